@@ -0,0 +1,377 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use sdkgen_core::{
+    Case, CasingOverrides, Diagnostic, PackageMetadata, PathFilter, Span, TypeMappings,
+};
+use sdkgen_emitter_python::PythonModelStyle;
+use sdkgen_emitter_rust::RustMapType;
+use sdkgen_emitter_typescript::{TypeScriptDateType, TypeScriptLongType};
+use serde::{Deserialize, Deserializer};
+
+use crate::emitters::{CasingOptions, EmitterOptions};
+
+/// The configuration file that is read when none is given, if it exists.
+pub const DEFAULT_CONFIG_FILE: &str = "sdkgen.toml";
+
+/// The configuration read from an `sdkgen.toml` file.
+///
+/// Relative paths are resolved against the directory containing the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    /// The API definitions to generate SDKs from, given as a single path or a list of paths.
+    #[serde(deserialize_with = "one_or_many")]
+    pub input: Vec<PathBuf>,
+    /// The directory to write the generated SDKs to, each in a directory named after its language.
+    pub out: Option<PathBuf>,
+    /// The languages to generate SDKs for. Defaults to all languages.
+    pub languages: Vec<String>,
+    pub package: PackageConfig,
+    pub filter: FilterConfig,
+    /// The settings for individual languages, keyed by language.
+    pub lang: IndexMap<String, LanguageConfig>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PackageConfig {
+    pub name: Option<String>,
+    pub version: Option<String>,
+}
+
+/// The patterns for the paths in the API definitions to generate routes for.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FilterConfig {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LanguageConfig {
+    /// The directory to write the SDK to, instead of a directory in the shared output directory.
+    pub out: Option<PathBuf>,
+    /// Overrides the shared package metadata.
+    pub package: PackageConfig,
+    /// The cases to use for each kind of identifier, instead of those of the language.
+    pub casing: CasingConfig,
+    /// The names to use for identifiers in the API definition, instead of applying the casing
+    /// rules.
+    pub rename: IndexMap<String, String>,
+    /// The existing types to use in place of the named types in the API definition.
+    pub types: IndexMap<String, String>,
+    /// The kind of classes emitted for records in the Python SDK.
+    pub model_style: Option<ModelStyleConfig>,
+    /// The type used for maps in the Rust SDK.
+    pub map_type: Option<MapTypeConfig>,
+    /// The type used for 64-bit integers in the TypeScript SDK, `bigint` by default.
+    pub long_type: Option<LongTypeConfig>,
+    /// The type used for date-times in the TypeScript SDK.
+    pub date_type: Option<DateTypeConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CasingConfig {
+    pub type_name: Option<CaseConfig>,
+    /// Not supported by TypeScript, whose records keep the names that their members are sent with.
+    pub record_member: Option<CaseConfig>,
+    pub function_name: Option<CaseConfig>,
+    pub parameter: Option<CaseConfig>,
+    pub enum_variant: Option<CaseConfig>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CaseConfig {
+    Preserve,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+}
+
+impl From<CaseConfig> for Case {
+    fn from(case: CaseConfig) -> Self {
+        match case {
+            CaseConfig::Preserve => Case::Preserve,
+            CaseConfig::Camel => Case::Camel,
+            CaseConfig::Pascal => Case::Pascal,
+            CaseConfig::Snake => Case::Snake,
+            CaseConfig::ScreamingSnake => Case::ScreamingSnake,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ModelStyleConfig {
+    Dataclasses,
+    Pydantic,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MapTypeConfig {
+    HashMap,
+    IndexMap,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LongTypeConfig {
+    Number,
+    Bigint,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DateTypeConfig {
+    String,
+    Date,
+}
+
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<PathBuf>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(PathBuf),
+        Many(Vec<PathBuf>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(path) => vec![path],
+        OneOrMany::Many(paths) => paths,
+    })
+}
+
+impl Config {
+    /// Parses the configuration, resolving relative paths against the given directory.
+    pub fn from_toml(source: &str, base_directory: &Path) -> Result<Self, Diagnostic> {
+        let mut config: Config = toml::from_str(source).map_err(|err| {
+            let diagnostic = Diagnostic::error(format!("Failed to parse configuration: {}", err));
+
+            match err.line_col() {
+                Some((line, column)) => {
+                    let offset = offset_at(source, line, column);
+
+                    diagnostic.with_span(Span {
+                        start: offset,
+                        end: offset,
+                    })
+                }
+                None => diagnostic,
+            }
+        })?;
+
+        for input in config.input.iter_mut() {
+            *input = base_directory.join(&input);
+        }
+
+        config.out = Some(base_directory.join(config.out.unwrap_or_else(default_out_dir)));
+
+        for language in config.lang.values_mut() {
+            if let Some(out) = language.out.as_mut() {
+                *out = base_directory.join(&out);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn path_filter(&self) -> PathFilter {
+        PathFilter {
+            include: self.filter.include.clone(),
+            exclude: self.filter.exclude.clone(),
+        }
+    }
+
+    /// Returns the directory that the SDK for the language is written to.
+    pub fn out_dir(&self, language: &str) -> PathBuf {
+        self.lang
+            .get(language)
+            .and_then(|language| language.out.clone())
+            .unwrap_or_else(|| {
+                self.out
+                    .clone()
+                    .unwrap_or_else(default_out_dir)
+                    .join(language)
+            })
+    }
+
+    pub fn emitter_options(&self, language: &str) -> EmitterOptions {
+        let default_config = LanguageConfig::default();
+        let language_config = self.lang.get(language).unwrap_or(&default_config);
+
+        let default_package = PackageMetadata::default();
+
+        let casing = &language_config.casing;
+
+        let mut casing_overrides = CasingOverrides::new();
+        for (identifier, name) in language_config.rename.iter() {
+            casing_overrides.insert(identifier.as_str(), name.as_str());
+        }
+
+        let mut type_mappings = TypeMappings::new();
+        for (name, target_type) in language_config.types.iter() {
+            type_mappings.insert(name.as_str(), target_type.as_str());
+        }
+
+        EmitterOptions {
+            package: PackageMetadata {
+                name: language_config
+                    .package
+                    .name
+                    .clone()
+                    .or_else(|| self.package.name.clone())
+                    .unwrap_or(default_package.name),
+                version: language_config
+                    .package
+                    .version
+                    .clone()
+                    .or_else(|| self.package.version.clone())
+                    .unwrap_or(default_package.version),
+            },
+            casing: CasingOptions {
+                type_name: casing.type_name.map(Case::from),
+                record_member: casing.record_member.map(Case::from),
+                function_name: casing.function_name.map(Case::from),
+                parameter: casing.parameter.map(Case::from),
+                enum_variant: casing.enum_variant.map(Case::from),
+                overrides: casing_overrides,
+            },
+            type_mappings,
+            python_model_style: match language_config.model_style {
+                Some(ModelStyleConfig::Pydantic) => PythonModelStyle::Pydantic,
+                Some(ModelStyleConfig::Dataclasses) | None => PythonModelStyle::Dataclasses,
+            },
+            rust_map_type: match language_config.map_type {
+                Some(MapTypeConfig::IndexMap) => RustMapType::IndexMap,
+                Some(MapTypeConfig::HashMap) | None => RustMapType::HashMap,
+            },
+            typescript_long_type: match language_config.long_type {
+                Some(LongTypeConfig::Bigint) | None => TypeScriptLongType::Bigint,
+                Some(LongTypeConfig::String) => TypeScriptLongType::String,
+                Some(LongTypeConfig::Number) => TypeScriptLongType::Number,
+            },
+            typescript_date_type: match language_config.date_type {
+                Some(DateTypeConfig::Date) => TypeScriptDateType::Date,
+                Some(DateTypeConfig::String) | None => TypeScriptDateType::String,
+            },
+        }
+    }
+}
+
+fn default_out_dir() -> PathBuf {
+    PathBuf::from("generated")
+}
+
+/// Returns the byte offset of the given zero-based line and column within the source.
+fn offset_at(source: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = source.split_inclusive('\n').take(line).map(str::len).sum();
+
+    (line_start + column).min(source.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(source: &str) -> Config {
+        Config::from_toml(source, Path::new("api")).unwrap()
+    }
+
+    #[test]
+    fn parses_the_configuration() {
+        let config = config(
+            r#"
+input = ["petstore.yaml", "owners.yaml"]
+out = "sdks"
+languages = ["python", "rust"]
+
+[package]
+name = "petstore"
+version = "1.2.3"
+
+[filter]
+exclude = ["/internal/**"]
+
+[lang.rust]
+map-type = "index-map"
+
+[lang.rust.casing]
+type-name = "screaming-snake"
+"#,
+        );
+
+        assert_eq!(
+            config.input,
+            vec![
+                PathBuf::from("api/petstore.yaml"),
+                PathBuf::from("api/owners.yaml")
+            ]
+        );
+        assert_eq!(config.out_dir("python"), PathBuf::from("api/sdks/python"));
+        assert_eq!(config.languages, vec!["python", "rust"]);
+        assert_eq!(config.path_filter().exclude, vec!["/internal/**"]);
+
+        let options = config.emitter_options("rust");
+
+        assert_eq!(options.package.name, "petstore");
+        assert_eq!(options.package.version, "1.2.3");
+        assert!(matches!(options.rust_map_type, RustMapType::IndexMap));
+        assert_eq!(options.casing.type_name, Some(Case::ScreamingSnake));
+    }
+
+    #[test]
+    fn input_can_be_a_single_path() {
+        assert_eq!(
+            config(r#"input = "petstore.yaml""#).input,
+            vec![PathBuf::from("api/petstore.yaml")]
+        );
+    }
+
+    #[test]
+    fn language_settings_take_precedence() {
+        let config = config(
+            r#"
+[package]
+name = "petstore"
+version = "1.2.3"
+
+[lang.csharp]
+out = "dotnet"
+
+[lang.csharp.package]
+name = "Petstore.Client"
+"#,
+        );
+
+        let options = config.emitter_options("csharp");
+
+        assert_eq!(options.package.name, "Petstore.Client");
+        assert_eq!(options.package.version, "1.2.3");
+        assert_eq!(config.out_dir("csharp"), PathBuf::from("api/dotnet"));
+        assert_eq!(config.emitter_options("go").package.name, "petstore");
+        assert_eq!(config.out_dir("go"), PathBuf::from("api/generated/go"));
+    }
+
+    #[test]
+    fn reports_unknown_keys() {
+        let diagnostic = Config::from_toml(
+            "[package]\nname = \"petstore\"\nlicense = \"MIT\"\n",
+            Path::new(""),
+        )
+        .expect_err("`license` is not a known key");
+
+        assert!(
+            diagnostic.message.contains("unknown field `license`"),
+            "{}",
+            diagnostic.message
+        );
+        assert!(diagnostic.span.is_some());
+    }
+}
@@ -0,0 +1,130 @@
+use sdkgen_core::{
+    Case, CasingOverrides, GenerateSdk, IdentifierCases, PackageMetadata, TypeMappings,
+};
+use sdkgen_emitter_csharp::{CsharpCasingRules, CsharpSdk};
+use sdkgen_emitter_go::{GoCasingRules, GoSdk};
+use sdkgen_emitter_python::{PythonCasingRules, PythonModelStyle, PythonSdk};
+use sdkgen_emitter_rust::{RustCasingRules, RustMapType, RustSdk};
+use sdkgen_emitter_typescript::{
+    TypeScriptCasingRules, TypeScriptDateType, TypeScriptLongType, TypeScriptSdk,
+};
+
+/// The options that emitters are created with.
+#[derive(Debug, Clone, Default)]
+pub struct EmitterOptions {
+    pub package: PackageMetadata,
+    pub casing: CasingOptions,
+    pub type_mappings: TypeMappings,
+    pub python_model_style: PythonModelStyle,
+    pub rust_map_type: RustMapType,
+    pub typescript_long_type: TypeScriptLongType,
+    pub typescript_date_type: TypeScriptDateType,
+}
+
+/// The casing settings that take precedence over those of the emitter.
+#[derive(Debug, Clone, Default)]
+pub struct CasingOptions {
+    pub type_name: Option<Case>,
+    pub record_member: Option<Case>,
+    pub function_name: Option<Case>,
+    pub parameter: Option<Case>,
+    pub enum_variant: Option<Case>,
+    pub overrides: CasingOverrides,
+}
+
+impl CasingOptions {
+    /// Returns the default cases of an emitter with the configured cases applied.
+    fn cases(&self, defaults: IdentifierCases) -> IdentifierCases {
+        IdentifierCases {
+            type_name: self.type_name.unwrap_or(defaults.type_name),
+            record_member: self.record_member.unwrap_or(defaults.record_member),
+            function_name: self.function_name.unwrap_or(defaults.function_name),
+            parameter: self.parameter.unwrap_or(defaults.parameter),
+            enum_variant: self.enum_variant.unwrap_or(defaults.enum_variant),
+        }
+    }
+}
+
+/// An emitter that SDKs can be generated with.
+pub struct Emitter {
+    /// The name used to select the emitter, which is also the directory that its SDK is written to.
+    pub name: &'static str,
+    pub create: fn(&EmitterOptions) -> Box<dyn GenerateSdk>,
+}
+
+/// All of the available emitters.
+pub const EMITTERS: &[Emitter] = &[
+    Emitter {
+        name: "csharp",
+        create: |options| {
+            Box::new(CsharpSdk {
+                package: options.package.clone(),
+                casing_rules: CsharpCasingRules {
+                    cases: options.casing.cases(CsharpCasingRules::DEFAULT_CASES),
+                    overrides: options.casing.overrides.clone(),
+                },
+                type_mappings: options.type_mappings.clone(),
+            })
+        },
+    },
+    Emitter {
+        name: "go",
+        create: |options| {
+            Box::new(GoSdk {
+                package: options.package.clone(),
+                casing_rules: GoCasingRules {
+                    cases: options.casing.cases(GoCasingRules::DEFAULT_CASES),
+                    overrides: options.casing.overrides.clone(),
+                },
+                type_mappings: options.type_mappings.clone(),
+            })
+        },
+    },
+    Emitter {
+        name: "python",
+        create: |options| {
+            Box::new(PythonSdk {
+                package: options.package.clone(),
+                model_style: options.python_model_style,
+                casing_rules: PythonCasingRules {
+                    cases: options.casing.cases(PythonCasingRules::DEFAULT_CASES),
+                    overrides: options.casing.overrides.clone(),
+                },
+                type_mappings: options.type_mappings.clone(),
+            })
+        },
+    },
+    Emitter {
+        name: "rust",
+        create: |options| {
+            Box::new(RustSdk {
+                package: options.package.clone(),
+                map_type: options.rust_map_type,
+                casing_rules: RustCasingRules {
+                    cases: options.casing.cases(RustCasingRules::DEFAULT_CASES),
+                    overrides: options.casing.overrides.clone(),
+                },
+                type_mappings: options.type_mappings.clone(),
+            })
+        },
+    },
+    Emitter {
+        name: "typescript",
+        create: |options| {
+            Box::new(TypeScriptSdk {
+                package: options.package.clone(),
+                long_type: options.typescript_long_type,
+                date_type: options.typescript_date_type,
+                casing_rules: TypeScriptCasingRules {
+                    cases: options.casing.cases(TypeScriptCasingRules::DEFAULT_CASES),
+                    overrides: options.casing.overrides.clone(),
+                },
+                type_mappings: options.type_mappings.clone(),
+            })
+        },
+    },
+];
+
+pub fn find_emitter(name: &str) -> Option<&'static Emitter> {
+    EMITTERS.iter().find(|emitter| emitter.name == name)
+}
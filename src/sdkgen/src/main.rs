@@ -1,11 +1,24 @@
-use std::path::PathBuf;
+mod config;
+mod emitters;
+mod report;
+
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
 
 use indexmap::IndexMap;
-use sdkgen_core::{GenerateSdk, Route, SdkResource, SdkVersion, TypeDeclarations};
-use sdkgen_emitter_csharp::CsharpSdk;
-use sdkgen_emitter_typescript::TypeScriptSdk;
+use sdkgen_core::{
+    Api, Diagnostic, Diagnostics, FileTree, PathFilter, Route, SdkResource, SdkVersion,
+    TypeDeclarations,
+};
 use structopt::StructOpt;
 
+use crate::config::{Config, ModelStyleConfig, DEFAULT_CONFIG_FILE};
+use crate::emitters::{find_emitter, Emitter, EMITTERS};
+use crate::report::{print_diagnostics, SourceFormat};
+
 fn versions_from_routes(routes: Vec<Route>) -> Vec<SdkVersion> {
     let mut versions = IndexMap::new();
 
@@ -35,55 +48,398 @@ fn versions_from_routes(routes: Vec<Route>) -> Vec<SdkVersion> {
 }
 
 #[derive(Debug, StructOpt)]
-struct Args {
-    #[structopt(name = "API_DEFINITION")]
-    api_definition: PathBuf,
+enum Command {
+    /// Generates SDKs from an API definition.
+    Generate(GenerateArgs),
 }
 
-fn main() -> std::io::Result<()> {
-    let args = Args::from_args();
+/// The arguments to the `generate` command, which take precedence over the configuration file.
+#[derive(Debug, StructOpt)]
+struct GenerateArgs {
+    /// The API definition to generate SDKs from, instead of the inputs in the configuration file.
+    #[structopt(name = "API_DEFINITION")]
+    api_definition: Option<PathBuf>,
+
+    /// The configuration file to read. Defaults to `sdkgen.toml`, if it exists.
+    #[structopt(long)]
+    config: Option<PathBuf>,
+
+    /// The languages to generate SDKs for, separated by commas. Defaults to all languages.
+    #[structopt(long, require_delimiter = true)]
+    lang: Vec<String>,
 
-    use std::ffi::OsStr;
-    use std::fs::File;
-    use std::io::prelude::*;
-    use std::io::BufReader;
+    /// The directory to write the generated SDKs to, each in a directory named after its language.
+    /// Defaults to `generated`.
+    #[structopt(long)]
+    out: Option<PathBuf>,
+
+    /// The name of the generated packages.
+    #[structopt(long)]
+    package_name: Option<String>,
+
+    /// The version of the generated packages.
+    #[structopt(long)]
+    package_version: Option<String>,
+
+    /// Emit Pydantic models instead of dataclasses in the Python SDK.
+    #[structopt(long)]
+    pydantic: bool,
+}
+
+fn main() {
+    match Command::from_args() {
+        Command::Generate(args) => run_generate(args),
+    }
+}
 
-    let api_definition = File::open(&args.api_definition)?;
-    let mut buf_reader = BufReader::new(api_definition);
-    let mut api_definition = String::new();
-    buf_reader.read_to_string(&mut api_definition)?;
+fn run_generate(args: GenerateArgs) {
+    let mut config = load_config(args.config.as_deref());
+    apply_args(&mut config, args);
 
-    let routes = match args.api_definition.extension().and_then(OsStr::to_str) {
-        Some("json") => sdkgen_adapter_openapi::from_json(&api_definition)
-            .expect("Failed to deserialize API data"),
-        Some("yaml") => sdkgen_adapter_openapi::from_yaml(&api_definition)
-            .expect("Failed to deserialize API data"),
-        Some(extension) => panic!("Invalid file extension: '{}'", extension),
-        None => panic!("Could not determine extension"),
+    let emitters = match select_emitters(&config) {
+        Ok(emitters) => emitters,
+        Err(diagnostics) => exit_with_diagnostics(None, None, diagnostics),
     };
 
+    if config.input.is_empty() {
+        exit_with_diagnostics(
+            None,
+            None,
+            Diagnostic::error(format!(
+                "No API definition given. Pass one as an argument or set `input` in {}.",
+                DEFAULT_CONFIG_FILE
+            ))
+            .into(),
+        );
+    }
+
+    let filter = config.path_filter();
+
+    let mut routes = Vec::new();
     let mut type_decls = TypeDeclarations::new();
 
-    for route in routes.iter() {
-        if let Some(payload_type) = route.payload_type.as_ref() {
-            type_decls.register(payload_type.to_owned());
+    for path in config.input.iter() {
+        let (api_definition, source_format) = match read_api_definition(path) {
+            Ok(api_definition) => api_definition,
+            Err(diagnostic) => exit_with_diagnostics(Some(path), None, diagnostic.into()),
+        };
+
+        match convert_api_definition(path, &api_definition, source_format, &filter) {
+            Ok((api, warnings)) => {
+                // Warnings do not stop the SDKs from being generated, but are still worth knowing.
+                print_diagnostics(
+                    Some(path),
+                    Some((&api_definition, source_format)),
+                    &warnings,
+                );
+
+                routes.extend(api.routes);
+
+                // A type declared by more than one input is declared as the first one does.
+                for (_, ty) in api.types {
+                    type_decls.register(ty);
+                }
+            }
+            Err(diagnostics) => exit_with_diagnostics(
+                Some(path),
+                Some((&api_definition, source_format)),
+                diagnostics,
+            ),
+        }
+    }
+
+    if let Err(diagnostics) = generate(routes, type_decls, &emitters, &config) {
+        exit_with_diagnostics(None, None, diagnostics);
+    }
+}
+
+/// Reads the configuration file, falling back to the default configuration when no file was given
+/// and `sdkgen.toml` does not exist.
+fn load_config(path: Option<&Path>) -> Config {
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None if Path::new(DEFAULT_CONFIG_FILE).exists() => PathBuf::from(DEFAULT_CONFIG_FILE),
+        None => return Config::default(),
+    };
+
+    let source = match read_file(&path) {
+        Ok(source) => source,
+        Err(diagnostic) => exit_with_diagnostics(Some(&path), None, diagnostic.into()),
+    };
+
+    let base_directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    match Config::from_toml(&source, base_directory) {
+        Ok(config) => config,
+        Err(diagnostic) => exit_with_diagnostics(
+            Some(&path),
+            Some((&source, SourceFormat::Toml)),
+            diagnostic.into(),
+        ),
+    }
+}
+
+/// Applies the command-line arguments to the configuration, overriding the values from the file.
+fn apply_args(config: &mut Config, args: GenerateArgs) {
+    if let Some(api_definition) = args.api_definition {
+        config.input = vec![api_definition];
+    }
+
+    if !args.lang.is_empty() {
+        config.languages = args.lang;
+    }
+
+    if let Some(out) = args.out {
+        config.out = Some(out);
+
+        for language in config.lang.values_mut() {
+            language.out = None;
+        }
+    }
+
+    if let Some(package_name) = args.package_name {
+        config.package.name = Some(package_name);
+
+        for language in config.lang.values_mut() {
+            language.package.name = None;
+        }
+    }
+
+    if let Some(package_version) = args.package_version {
+        config.package.version = Some(package_version);
+
+        for language in config.lang.values_mut() {
+            language.package.version = None;
+        }
+    }
+
+    if args.pydantic {
+        config.lang.entry("python".into()).or_default().model_style =
+            Some(ModelStyleConfig::Pydantic);
+    }
+}
+
+fn unknown_language(language: &str) -> Diagnostic {
+    Diagnostic::error(format!(
+        "Unknown language: '{}'. Expected one of: {}.",
+        language,
+        EMITTERS
+            .iter()
+            .map(|emitter| emitter.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+/// Returns the emitters for the configured languages, or all emitters if none are configured.
+fn select_emitters(config: &Config) -> Result<Vec<&'static Emitter>, Diagnostics> {
+    let mut diagnostics = Diagnostics::new();
+
+    for language in config.lang.keys() {
+        if find_emitter(language).is_none() {
+            diagnostics.push(unknown_language(language));
+        }
+    }
+
+    if config.languages.is_empty() {
+        return diagnostics.into_result(EMITTERS.iter().collect());
+    }
+
+    let mut emitters = Vec::new();
+
+    for language in config.languages.iter() {
+        match find_emitter(language) {
+            Some(emitter) => emitters.push(emitter),
+            None => diagnostics.push(unknown_language(language)),
+        }
+    }
+
+    diagnostics.into_result(emitters)
+}
+
+fn exit_with_diagnostics(
+    path: Option<&Path>,
+    source: Option<(&str, SourceFormat)>,
+    diagnostics: Diagnostics,
+) -> ! {
+    print_diagnostics(path, source, &diagnostics);
+
+    std::process::exit(1);
+}
+
+fn read_file(path: &Path) -> Result<String, Diagnostic> {
+    let read = || -> std::io::Result<String> {
+        let file = File::open(path)?;
+        let mut buf_reader = BufReader::new(file);
+        let mut contents = String::new();
+        buf_reader.read_to_string(&mut contents)?;
+
+        Ok(contents)
+    };
+
+    read().map_err(|err| Diagnostic::error(format!("Failed to read '{}': {}", path.display(), err)))
+}
+
+fn read_api_definition(path: &Path) -> Result<(String, SourceFormat), Diagnostic> {
+    let source_format = match path.extension().and_then(OsStr::to_str) {
+        Some("json") => SourceFormat::Json,
+        Some("yaml") | Some("yml") => SourceFormat::Yaml,
+        Some(extension) => {
+            return Err(Diagnostic::error(format!(
+                "Invalid file extension: '{}'.",
+                extension
+            )))
         }
+        None => return Err(Diagnostic::error("Could not determine file extension.")),
+    };
+
+    Ok((read_file(path)?, source_format))
+}
 
-        if let Some(return_type) = route.return_type.as_ref() {
-            type_decls.register(return_type.to_owned());
+fn convert_api_definition(
+    path: &Path,
+    api_definition: &str,
+    source_format: SourceFormat,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    match source_format {
+        SourceFormat::Json if sdkgen_adapter_swagger2::is_swagger2_json(api_definition) => {
+            sdkgen_adapter_swagger2::from_json(api_definition, filter)
+        }
+        SourceFormat::Yaml if sdkgen_adapter_swagger2::is_swagger2_yaml(api_definition) => {
+            sdkgen_adapter_swagger2::from_yaml(api_definition, filter)
         }
+        SourceFormat::Json => sdkgen_adapter_openapi::from_json(api_definition, path, filter),
+        SourceFormat::Yaml => sdkgen_adapter_openapi::from_yaml(api_definition, path, filter),
+        SourceFormat::Toml => unreachable!("API definitions are never TOML"),
     }
+}
 
+fn generate(
+    routes: Vec<Route>,
+    type_decls: TypeDeclarations,
+    emitters: &[&Emitter],
+    config: &Config,
+) -> Result<(), Diagnostics> {
     let versions = versions_from_routes(routes);
 
-    let csharp_output = CsharpSdk.generate_sdk(type_decls.clone(), versions.clone());
-    let typescript_output = TypeScriptSdk.generate_sdk(type_decls, versions);
+    let mut diagnostics = Diagnostics::new();
 
-    let mut csharp_file = File::create("generated/csharp.cs")?;
-    csharp_file.write_all(&csharp_output.as_bytes())?;
+    for emitter in emitters {
+        let sdk = (emitter.create)(&config.emitter_options(emitter.name));
 
-    let mut typescript_file = File::create("generated/typescript.ts")?;
-    typescript_file.write_all(&typescript_output.as_bytes())?;
+        match sdk.generate_sdk(type_decls.clone(), versions.clone()) {
+            Ok(files) => {
+                if let Err(diagnostic) = write_file_tree(&config.out_dir(emitter.name), files) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+            Err(errors) => diagnostics.extend(errors),
+        }
+    }
+
+    diagnostics.into_result(())
+}
+
+/// Writes the files to the directory, creating any missing directories along the way.
+fn write_file_tree(directory: &Path, files: FileTree) -> Result<(), Diagnostic> {
+    for (path, contents) in files {
+        let path = directory.join(path);
+
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&path, contents)
+        };
+
+        write().map_err(|err| {
+            Diagnostic::error(format!("Failed to write '{}': {}", path.display(), err))
+        })?;
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> GenerateArgs {
+        GenerateArgs::from_iter(std::iter::once("generate").chain(args.iter().copied()))
+    }
+
+    fn config() -> Config {
+        Config::from_toml(
+            r#"
+input = "petstore.yaml"
+languages = ["python"]
+
+[package]
+name = "petstore"
+version = "1.2.3"
+
+[lang.python]
+out = "python-sdk"
+
+[lang.python.package]
+name = "petstore-client"
+"#,
+            Path::new(""),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn configuration_is_used_without_arguments() {
+        let mut config = config();
+        apply_args(&mut config, args(&[]));
+
+        assert_eq!(config.input, vec![PathBuf::from("petstore.yaml")]);
+        assert_eq!(config.out_dir("python"), PathBuf::from("python-sdk"));
+        assert_eq!(
+            config.emitter_options("python").package.name,
+            "petstore-client"
+        );
+    }
+
+    #[test]
+    fn arguments_take_precedence_over_the_configuration() {
+        let mut config = config();
+        apply_args(
+            &mut config,
+            args(&[
+                "owners.yaml",
+                "--lang",
+                "go,rust",
+                "--out",
+                "sdks",
+                "--package-name",
+                "owners",
+                "--package-version",
+                "2.0.0",
+            ]),
+        );
+
+        assert_eq!(config.input, vec![PathBuf::from("owners.yaml")]);
+        assert_eq!(config.languages, vec!["go", "rust"]);
+        assert_eq!(config.out_dir("python"), PathBuf::from("sdks/python"));
+
+        let options = config.emitter_options("python");
+
+        assert_eq!(options.package.name, "owners");
+        assert_eq!(options.package.version, "2.0.0");
+    }
+
+    #[test]
+    fn pydantic_flag_selects_pydantic_models() {
+        let mut config = config();
+        apply_args(&mut config, args(&["--pydantic"]));
+
+        assert!(matches!(
+            config.emitter_options("python").python_model_style,
+            sdkgen_emitter_python::PythonModelStyle::Pydantic
+        ));
+    }
+}
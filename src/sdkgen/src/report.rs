@@ -0,0 +1,345 @@
+use std::path::Path;
+
+use sdkgen_core::{Diagnostic, Diagnostics, JsonPointer, Span};
+
+/// The format of a source file.
+#[derive(Debug, Clone, Copy)]
+pub enum SourceFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+/// Renders the diagnostics, along with an excerpt of the source they refer to.
+///
+/// Diagnostics that do not refer to a file, such as invalid arguments, are rendered without a path.
+pub fn render_diagnostics(
+    path: Option<&Path>,
+    source: Option<(&str, SourceFormat)>,
+    diagnostics: &Diagnostics,
+) -> String {
+    let mut buffer = String::new();
+
+    for diagnostic in diagnostics.iter() {
+        buffer += &render_diagnostic(path, source, diagnostic);
+        buffer += "\n";
+    }
+
+    buffer
+}
+
+/// Prints the diagnostics to standard error, along with an excerpt of the source they refer to.
+pub fn print_diagnostics(
+    path: Option<&Path>,
+    source: Option<(&str, SourceFormat)>,
+    diagnostics: &Diagnostics,
+) {
+    eprint!("{}", render_diagnostics(path, source, diagnostics));
+}
+
+fn render_diagnostic(
+    path: Option<&Path>,
+    source: Option<(&str, SourceFormat)>,
+    diagnostic: &Diagnostic,
+) -> String {
+    let mut buffer = format!("{}: {}\n", diagnostic.severity, diagnostic.message);
+
+    let path = match path {
+        Some(path) => path,
+        None => return buffer,
+    };
+
+    let excerpt = source.and_then(|(source, format)| {
+        let span = diagnostic.span.or_else(|| {
+            diagnostic
+                .pointer
+                .as_ref()
+                .and_then(|pointer| locate_pointer(source, format, pointer))
+        })?;
+
+        Some(render_excerpt(path, source, span))
+    });
+
+    match excerpt {
+        Some(excerpt) => buffer += &excerpt,
+        None => buffer += &format!("  --> {}\n", path.display()),
+    }
+
+    if let Some(pointer) = diagnostic.pointer.as_ref() {
+        buffer += &format!("   = at '{}'\n", pointer);
+    }
+
+    buffer
+}
+
+fn render_excerpt(path: &Path, source: &str, span: Span) -> String {
+    let start = span.start.min(source.len());
+
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |index| start + index);
+
+    let line_number = source[..start].matches('\n').count() + 1;
+    let column = source[line_start..start].chars().count();
+    let line = &source[line_start..line_end];
+
+    let underline_length = span.end.min(line_end).saturating_sub(start).max(1);
+
+    let gutter = " ".repeat(line_number.to_string().len());
+
+    format!(
+        "{gutter}--> {path}:{line_number}:{column}\n{gutter} |\n{line_number} | {line}\n{gutter} | {padding}{underline}\n",
+        gutter = gutter,
+        path = path.display(),
+        line_number = line_number,
+        column = column + 1,
+        line = line.trim_end_matches('\r'),
+        padding = " ".repeat(column),
+        underline = "^".repeat(underline_length)
+    )
+}
+
+/// Finds the span of the key or item that the pointer refers to within the source.
+fn locate_pointer(source: &str, format: SourceFormat, pointer: &JsonPointer) -> Option<Span> {
+    match format {
+        SourceFormat::Json => locate_in_json(source, pointer),
+        SourceFormat::Yaml => locate_in_yaml(source, pointer),
+        SourceFormat::Toml => None,
+    }
+}
+
+fn locate_in_json(source: &str, pointer: &JsonPointer) -> Option<Span> {
+    let bytes = source.as_bytes();
+    let mut position = skip_json_whitespace(bytes, 0);
+    let mut span = None;
+
+    for segment in pointer.segments() {
+        match bytes.get(position)? {
+            b'{' => {
+                position = skip_json_whitespace(bytes, position + 1);
+
+                loop {
+                    let key_start = position;
+                    let key_end = skip_json_value(bytes, key_start)?;
+                    let key: String = serde_json::from_str(&source[key_start..key_end]).ok()?;
+
+                    position = skip_json_whitespace(bytes, key_end);
+                    position = skip_json_whitespace(bytes, position + 1);
+
+                    if &key == segment {
+                        span = Some(Span {
+                            start: key_start,
+                            end: key_end,
+                        });
+                        break;
+                    }
+
+                    position = skip_json_whitespace(bytes, skip_json_value(bytes, position)?);
+
+                    match bytes.get(position)? {
+                        b',' => position = skip_json_whitespace(bytes, position + 1),
+                        _ => return None,
+                    }
+                }
+            }
+            b'[' => {
+                let index: usize = segment.parse().ok()?;
+
+                position = skip_json_whitespace(bytes, position + 1);
+
+                for _ in 0..index {
+                    position = skip_json_whitespace(bytes, skip_json_value(bytes, position)?);
+
+                    match bytes.get(position)? {
+                        b',' => position = skip_json_whitespace(bytes, position + 1),
+                        _ => return None,
+                    }
+                }
+
+                span = Some(Span {
+                    start: position,
+                    end: position + 1,
+                });
+            }
+            _ => return None,
+        }
+    }
+
+    span
+}
+
+fn skip_json_whitespace(bytes: &[u8], mut position: usize) -> usize {
+    while bytes
+        .get(position)
+        .is_some_and(|byte| byte.is_ascii_whitespace())
+    {
+        position += 1;
+    }
+
+    position
+}
+
+/// Returns the position just past the JSON value starting at `position`.
+fn skip_json_value(bytes: &[u8], mut position: usize) -> Option<usize> {
+    match bytes.get(position)? {
+        b'"' => {
+            position += 1;
+
+            loop {
+                match bytes.get(position)? {
+                    b'\\' => position += 2,
+                    b'"' => return Some(position + 1),
+                    _ => position += 1,
+                }
+            }
+        }
+        b'{' | b'[' => {
+            let mut depth = 0;
+
+            loop {
+                match bytes.get(position)? {
+                    b'"' => {
+                        position = skip_json_value(bytes, position)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some(position + 1);
+                        }
+                    }
+                    _ => (),
+                }
+
+                position += 1;
+            }
+        }
+        _ => {
+            while bytes
+                .get(position)
+                .is_some_and(|byte| !b",}] \t\r\n".contains(byte))
+            {
+                position += 1;
+            }
+
+            Some(position)
+        }
+    }
+}
+
+/// Finds the pointer within a YAML document by following the indentation of block mappings and
+/// sequences.
+///
+/// Flow-style collections are not supported.
+fn locate_in_yaml(source: &str, pointer: &JsonPointer) -> Option<Span> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        lines.push((offset, line.trim_end_matches(&['\r', '\n'][..])));
+        offset += line.len();
+    }
+
+    let mut from = 0;
+    let mut parent_indent: Option<usize> = None;
+    let mut is_within_sequence_item = false;
+    let mut span = None;
+
+    for segment in pointer.segments() {
+        let mut child_indent = None;
+        let mut sequence_index = 0;
+        let mut found = false;
+
+        for (index, (offset, line)) in lines.iter().enumerate().skip(from) {
+            let trimmed = line.trim_start();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let indent = line.len() - trimmed.len();
+            let is_first_line_of_item = is_within_sequence_item && index == from;
+
+            if !is_first_line_of_item && parent_indent.is_some_and(|parent| indent <= parent) {
+                let is_compact_sequence_item = trimmed.starts_with("- ")
+                    && parent_indent == Some(indent)
+                    && !is_within_sequence_item;
+
+                if !is_compact_sequence_item {
+                    break;
+                }
+            }
+
+            if !is_first_line_of_item && (trimmed.starts_with("- ") || trimmed == "-") {
+                if child_indent.is_none() {
+                    child_indent = Some(indent);
+                }
+
+                if child_indent != Some(indent) {
+                    continue;
+                }
+
+                if segment.parse() == Ok(sequence_index) {
+                    span = Some(Span {
+                        start: offset + indent,
+                        end: offset + indent + 1,
+                    });
+                    from = index;
+                    parent_indent = Some(indent);
+                    is_within_sequence_item = true;
+                    found = true;
+                    break;
+                }
+
+                sequence_index += 1;
+                continue;
+            }
+
+            let (indent, content) = if is_first_line_of_item {
+                let content = trimmed.trim_start_matches('-').trim_start();
+
+                (line.len() - content.len(), content)
+            } else {
+                (indent, trimmed)
+            };
+
+            if child_indent.is_none() {
+                child_indent = Some(indent);
+            }
+
+            if child_indent != Some(indent) {
+                continue;
+            }
+
+            let key_length = [
+                format!("{}:", segment),
+                format!("\"{}\":", segment),
+                format!("'{}':", segment),
+            ]
+            .iter()
+            .find(|key| content.starts_with(key.as_str()))
+            .map(|key| key.len() - 1);
+
+            if let Some(key_length) = key_length {
+                span = Some(Span {
+                    start: offset + indent,
+                    end: offset + indent + key_length,
+                });
+                from = index + 1;
+                parent_indent = Some(indent);
+                is_within_sequence_item = false;
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            return None;
+        }
+    }
+
+    span
+}
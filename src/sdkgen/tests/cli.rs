@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn warnings_are_printed() {
+    let temp_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli");
+    fs::create_dir_all(&temp_directory).unwrap();
+
+    let api_definition = temp_directory.join("digest.yaml");
+    fs::write(
+        &api_definition,
+        r#"openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      security:
+        - digestAuth: []
+      responses:
+        "204":
+          description: Nothing.
+components:
+  securitySchemes:
+    digestAuth:
+      type: http
+      scheme: digest
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sdkgen"))
+        .arg("generate")
+        .arg(&api_definition)
+        .arg("--lang")
+        .arg("python")
+        .arg("--out")
+        .arg(temp_directory.join("generated"))
+        .current_dir(&temp_directory)
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{}", stderr);
+    assert!(
+        stderr.contains(
+            "warning: Unsupported HTTP authentication scheme 'digest', so requests are not authorized with 'digestAuth'."
+        ),
+        "{}",
+        stderr
+    );
+    assert!(stderr.contains("digest.yaml:16:5"), "{}", stderr);
+}
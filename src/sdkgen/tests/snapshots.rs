@@ -0,0 +1,177 @@
+//! Compares the SDKs generated from the fixtures with the snapshots in `tests/snapshots`.
+//!
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to write the generated SDKs to the snapshots instead,
+//! then review the changes to them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Reads the files within the directory, by their path relative to it.
+fn read_files(directory: &Path) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    let mut directories = vec![directory.to_owned()];
+
+    while let Some(current) = directories.pop() {
+        for entry in fs::read_dir(&current).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.is_dir() {
+                directories.push(path);
+                continue;
+            }
+
+            let relative_path = path
+                .strip_prefix(directory)
+                .unwrap()
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            files.insert(relative_path, fs::read_to_string(&path).unwrap());
+        }
+    }
+
+    files
+}
+
+fn generate(fixture: &str, language: &str) -> PathBuf {
+    let manifest_directory = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let api_definition = manifest_directory
+        .join("../../fixtures")
+        .join(format!("{}.yaml", fixture));
+
+    let temp_directory = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let out = temp_directory.join("snapshots").join(fixture);
+    let _ = fs::remove_dir_all(out.join(language));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sdkgen"))
+        .arg("generate")
+        .arg(&api_definition)
+        .arg("--lang")
+        .arg(language)
+        .arg("--out")
+        .arg(&out)
+        .current_dir(temp_directory)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "Failed to generate the {} SDK for '{}':\n{}",
+        language,
+        fixture,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    out.join(language)
+}
+
+fn assert_snapshot(fixture: &str, language: &str) {
+    let generated = read_files(&generate(fixture, language));
+
+    let snapshot_directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(fixture)
+        .join(language);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        let _ = fs::remove_dir_all(&snapshot_directory);
+
+        for (path, contents) in generated {
+            let path = snapshot_directory.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        return;
+    }
+
+    let snapshot = read_files(&snapshot_directory);
+
+    assert_eq!(
+        generated.keys().collect::<Vec<_>>(),
+        snapshot.keys().collect::<Vec<_>>(),
+        "The files of the {} SDK for '{}' differ from the snapshot",
+        language,
+        fixture
+    );
+
+    for (path, contents) in generated.iter() {
+        let expected = &snapshot[path];
+
+        if contents == expected {
+            continue;
+        }
+
+        let (line_number, (line, expected_line)) = contents
+            .lines()
+            .chain(std::iter::repeat(""))
+            .zip(expected.lines().chain(std::iter::repeat("")))
+            .enumerate()
+            .find(|(_, (line, expected_line))| line != expected_line)
+            .unwrap();
+
+        panic!(
+            "'{}' of the {} SDK for '{}' differs from the snapshot at line {}:\n  generated: {}\n  snapshot:  {}\nRun the tests with UPDATE_SNAPSHOTS=1 to update the snapshots.",
+            path,
+            language,
+            fixture,
+            line_number + 1,
+            line,
+            expected_line
+        );
+    }
+}
+
+#[test]
+fn petstore_csharp() {
+    assert_snapshot("petstore", "csharp");
+}
+
+#[test]
+fn petstore_go() {
+    assert_snapshot("petstore", "go");
+}
+
+#[test]
+fn petstore_python() {
+    assert_snapshot("petstore", "python");
+}
+
+#[test]
+fn petstore_rust() {
+    assert_snapshot("petstore", "rust");
+}
+
+#[test]
+fn petstore_typescript() {
+    assert_snapshot("petstore", "typescript");
+}
+
+#[test]
+fn features_csharp() {
+    assert_snapshot("features", "csharp");
+}
+
+#[test]
+fn features_go() {
+    assert_snapshot("features", "go");
+}
+
+#[test]
+fn features_python() {
+    assert_snapshot("features", "python");
+}
+
+#[test]
+fn features_rust() {
+    assert_snapshot("features", "rust");
+}
+
+#[test]
+fn features_typescript() {
+    assert_snapshot("features", "typescript");
+}
@@ -0,0 +1,137 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Net.Http;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Sdk;
+
+/// <summary>
+/// Options for an <see cref="ApiClient"/>.
+/// </summary>
+public sealed class ApiClientOptions
+{
+    /// <summary>
+    /// The base URL of the API, or null to use one of the servers of the API.
+    /// </summary>
+    public Uri? BaseUrl { get; set; }
+
+    /// <summary>
+    /// The index in <see cref="Server.All"/> of the server that requests are sent to when no base
+    /// URL is given.
+    /// </summary>
+    public int ServerIndex { get; set; }
+
+    /// <summary>
+    /// The values of the variables in the URLs of the servers, which otherwise take their defaults.
+    /// </summary>
+    public IDictionary<string, string> ServerVariables { get; } = new Dictionary<string, string>();
+
+    /// <summary>
+    /// The HTTP client that sends requests, or null to create one for the <see cref="ApiClient"/>.
+    /// </summary>
+    public HttpClient? HttpClient { get; set; }
+
+    /// <summary>
+    /// Headers sent with every request, unless the request sets them itself.
+    /// </summary>
+    public IDictionary<string, string> DefaultHeaders { get; } = new Dictionary<string, string>();
+
+    /// <summary>
+    /// How long to wait for a response, or null to only use the timeout of the HTTP client.
+    /// </summary>
+    public TimeSpan? Timeout { get; set; }
+
+    /// <summary>
+    /// The provider of the credentials that requests are authorized with.
+    /// </summary>
+    public ICredentialsProvider? CredentialsProvider { get; set; }
+}
+
+/// <summary>
+/// A client for the API.
+/// </summary>
+/// <remarks>
+/// Relative base URLs are resolved against the base address of the HTTP client.
+/// </remarks>
+public sealed class ApiClient : IDisposable
+{
+    private readonly HttpClient httpClient;
+    private readonly bool ownsHttpClient;
+    private readonly string baseUrl;
+    private readonly Dictionary<string, string> serverVariables;
+    private readonly Dictionary<string, string> defaultHeaders;
+    private readonly TimeSpan? timeout;
+
+    public ApiClient(ApiClientOptions options)
+    {
+        if (options.BaseUrl != null)
+        {
+            baseUrl = options.BaseUrl.OriginalString.TrimEnd('/');
+        }
+        else if (options.ServerIndex >= 0 && options.ServerIndex < Server.All.Count)
+        {
+            baseUrl = Server.All[options.ServerIndex].GetUrl(options.ServerVariables);
+        }
+        else
+        {
+            throw new ArgumentException("The base URL of the API is required when the server index is not that of one of its servers.", nameof(options));
+        }
+
+        httpClient = options.HttpClient ?? new HttpClient();
+        ownsHttpClient = options.HttpClient == null;
+        serverVariables = new Dictionary<string, string>(options.ServerVariables);
+        defaultHeaders = new Dictionary<string, string>(options.DefaultHeaders);
+        timeout = options.Timeout;
+        CredentialsProvider = options.CredentialsProvider;
+        Pets = new V.PetsClient(this);
+        Owners = new V.OwnersClient(this);
+        Events = new V.EventsClient(this);
+    }
+
+    public V.PetsClient Pets { get; }
+
+    public V.OwnersClient Owners { get; }
+
+    public V.EventsClient Events { get; }
+
+    internal ICredentialsProvider? CredentialsProvider { get; }
+
+    /// <summary>
+    /// Disposes the HTTP client, unless it was given in the options.
+    /// </summary>
+    public void Dispose()
+    {
+        if (ownsHttpClient)
+        {
+            httpClient.Dispose();
+        }
+    }
+
+    internal Uri CreateUri(string path, Server? server = null)
+    {
+        return new Uri((server == null ? baseUrl : server.GetUrl(serverVariables)) + path, UriKind.RelativeOrAbsolute);
+    }
+
+    internal async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request)
+    {
+        foreach (var header in defaultHeaders)
+        {
+            if (!request.Headers.Contains(header.Key))
+            {
+                request.Headers.TryAddWithoutValidation(header.Key, header.Value);
+            }
+        }
+
+        if (timeout == null)
+        {
+            return await httpClient.SendAsync(request).ConfigureAwait(false);
+        }
+
+        using var cancellation = new CancellationTokenSource(timeout.Value);
+
+        return await httpClient.SendAsync(request, cancellation.Token).ConfigureAwait(false);
+    }
+}
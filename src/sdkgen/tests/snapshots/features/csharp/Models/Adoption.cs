@@ -0,0 +1,23 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Adoption
+{
+    [JsonProperty("type")]
+    public string Type { get; set; } = null!;
+
+    [JsonProperty("at")]
+    public DateTimeOffset At { get; set; }
+
+    [JsonProperty("owner")]
+    public Owner Owner { get; set; } = null!;
+}
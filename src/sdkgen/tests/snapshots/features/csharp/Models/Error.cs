@@ -0,0 +1,17 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Error
+{
+    [JsonProperty("message", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Message { get; set; }
+}
@@ -0,0 +1,70 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+[JsonConverter(typeof(EventConverter))]
+public abstract class Event
+{
+    private Event()
+    {
+    }
+
+    internal abstract object UntypedValue { get; }
+    
+    public sealed class AdoptionCase : Event
+    {
+        public AdoptionCase(Adoption value)
+        {
+            Value = value;
+        }
+
+        public Adoption Value { get; }
+
+        internal override object UntypedValue => Value;
+    }
+
+    public sealed class VisitCase : Event
+    {
+        public VisitCase(Visit value)
+        {
+            Value = value;
+        }
+
+        public Visit Value { get; }
+
+        internal override object UntypedValue => Value;
+    }
+}
+
+public class EventConverter : JsonConverter
+{
+    public override bool CanConvert(Type objectType) => typeof(Event).IsAssignableFrom(objectType);
+
+    public override object ReadJson(JsonReader reader, Type objectType, object? existingValue, JsonSerializer serializer)
+    {
+        var token = JToken.Load(reader);
+
+        switch ((string?)token["type"])
+        {
+            case "adoption":
+                return new Event.AdoptionCase(token.ToObject<Adoption>(serializer)!);
+            case "visit":
+                return new Event.VisitCase(token.ToObject<Visit>(serializer)!);
+            default:
+                throw new JsonSerializationException($"Unknown type '{token["type"]}' for Event.");
+        }
+    }
+
+    public override void WriteJson(JsonWriter writer, object? value, JsonSerializer serializer)
+    {
+        serializer.Serialize(writer, ((Event)value!).UntypedValue);
+    }
+}
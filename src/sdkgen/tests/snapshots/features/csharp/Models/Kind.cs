@@ -0,0 +1,22 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+[JsonConverter(typeof(StringEnumConverter))]
+public enum Kind
+{
+    [EnumMember(Value = "dog")]
+    Dog,
+    [EnumMember(Value = "cat")]
+    Cat,
+    [EnumMember(Value = "guinea-pig")]
+    GuineaPig,
+}
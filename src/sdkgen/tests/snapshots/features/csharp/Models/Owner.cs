@@ -0,0 +1,29 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Owner
+{
+    [JsonProperty("name")]
+    public string Name { get; set; } = null!;
+
+    [JsonProperty("email", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Email { get; set; }
+
+    [JsonProperty("website", NullValueHandling = NullValueHandling.Ignore)]
+    public Uri? Website { get; set; }
+
+    [JsonProperty("avatar", NullValueHandling = NullValueHandling.Ignore)]
+    public byte[]? Avatar { get; set; }
+
+    [JsonProperty("pets", NullValueHandling = NullValueHandling.Ignore)]
+    public List<Pet>? Pets { get; set; }
+}
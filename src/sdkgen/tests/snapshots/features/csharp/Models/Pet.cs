@@ -0,0 +1,68 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Pet
+{
+    [JsonProperty("id")]
+    public Guid Id { get; set; }
+
+    [JsonProperty("name")]
+    public string Name { get; set; } = null!;
+
+    [JsonProperty("kind")]
+    public Kind Kind { get; set; }
+
+    [JsonProperty("nickname")]
+    public string? Nickname { get; set; }
+
+    [JsonProperty("status")]
+    public Status Status { get; set; }
+
+    [JsonProperty("tags", NullValueHandling = NullValueHandling.Ignore)]
+    public List<string>? Tags { get; set; }
+
+    [JsonProperty("attributes", NullValueHandling = NullValueHandling.Ignore)]
+    public Dictionary<string, string>? Attributes { get; set; }
+
+    [JsonProperty("extra", NullValueHandling = NullValueHandling.Ignore)]
+    public object? Extra { get; set; }
+
+    [JsonProperty("born-at", NullValueHandling = NullValueHandling.Ignore)]
+    public DateTimeOffset? BornAt { get; set; }
+
+    [JsonProperty("weight", NullValueHandling = NullValueHandling.Ignore)]
+    public double? Weight { get; set; }
+
+    [JsonProperty("price", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Price { get; set; }
+
+    [JsonProperty("chipId", NullValueHandling = NullValueHandling.Ignore)]
+    public long? ChipId { get; set; }
+
+    [JsonProperty("position", NullValueHandling = NullValueHandling.Ignore)]
+    public List<float>? Position { get; set; }
+
+    [JsonProperty("parent", NullValueHandling = NullValueHandling.Ignore)]
+    public Pet? Parent { get; set; }
+
+    [JsonProperty("owner", NullValueHandling = NullValueHandling.Ignore)]
+    public Owner? Owner { get; set; }
+
+    [JsonProperty("type", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Type { get; set; }
+
+    [JsonProperty("class", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Class { get; set; }
+
+    [JsonProperty("default", NullValueHandling = NullValueHandling.Ignore)]
+    public bool? Default { get; set; }
+}
@@ -0,0 +1,20 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Problem
+{
+    [JsonProperty("title")]
+    public string Title { get; set; } = null!;
+
+    [JsonProperty("status", NullValueHandling = NullValueHandling.Ignore)]
+    public int? Status { get; set; }
+}
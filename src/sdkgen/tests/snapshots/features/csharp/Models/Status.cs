@@ -0,0 +1,18 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public enum Status
+{
+    Value1 = 1,
+    Value2 = 2,
+    Value3 = 3,
+}
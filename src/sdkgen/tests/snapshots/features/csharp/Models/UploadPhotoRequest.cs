@@ -0,0 +1,20 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class UploadPhotoRequest
+{
+    [JsonProperty("photo")]
+    public Stream Photo { get; set; } = null!;
+
+    [JsonProperty("caption", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Caption { get; set; }
+}
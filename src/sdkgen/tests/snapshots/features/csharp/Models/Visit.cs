@@ -0,0 +1,26 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Visit
+{
+    [JsonProperty("type")]
+    public string Type { get; set; } = null!;
+
+    [JsonProperty("at")]
+    public DateTimeOffset At { get; set; }
+
+    [JsonProperty("startsAt", NullValueHandling = NullValueHandling.Ignore)]
+    public TimeOnly? StartsAt { get; set; }
+
+    [JsonProperty("duration", NullValueHandling = NullValueHandling.Ignore)]
+    public VisitDuration? Duration { get; set; }
+}
@@ -0,0 +1,78 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+[JsonConverter(typeof(VisitDurationConverter))]
+public abstract class VisitDuration
+{
+    private VisitDuration()
+    {
+    }
+
+    internal abstract object UntypedValue { get; }
+    
+    public sealed class Case1Case : VisitDuration
+    {
+        public Case1Case(int value)
+        {
+            Value = value;
+        }
+
+        public int Value { get; }
+
+        internal override object UntypedValue => Value;
+    }
+
+    public sealed class Case2Case : VisitDuration
+    {
+        public Case2Case(string value)
+        {
+            Value = value;
+        }
+
+        public string Value { get; }
+
+        internal override object UntypedValue => Value;
+    }
+}
+
+public class VisitDurationConverter : JsonConverter
+{
+    public override bool CanConvert(Type objectType) => typeof(VisitDuration).IsAssignableFrom(objectType);
+
+    public override object ReadJson(JsonReader reader, Type objectType, object? existingValue, JsonSerializer serializer)
+    {
+        var token = JToken.Load(reader);
+
+        try
+        {
+            return new VisitDuration.Case1Case(token.ToObject<int>(serializer)!);
+        }
+        catch (JsonException)
+        {
+        }
+
+        try
+        {
+            return new VisitDuration.Case2Case(token.ToObject<string>(serializer)!);
+        }
+        catch (JsonException)
+        {
+        }
+
+        throw new JsonSerializationException("Value does not match any case of VisitDuration.");
+    }
+
+    public override void WriteJson(JsonWriter writer, object? value, JsonSerializer serializer)
+    {
+        serializer.Serialize(writer, ((VisitDuration)value!).UntypedValue);
+    }
+}
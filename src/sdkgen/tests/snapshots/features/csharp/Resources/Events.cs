@@ -0,0 +1,54 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class EventsClient
+    {
+        private readonly ApiClient client;
+
+        internal EventsClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        public async Task<List<Event>> ListEvents(DateOnly? since = null)
+        {
+            var queryParameters = new List<string>();
+            if (since != null)
+            {
+                queryParameters.Add("since=" + Uri.EscapeDataString(JsonConvert.SerializeObject(since).Trim('"')));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/events{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("openId", Array.Empty<string>()) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<List<Event>>(responseBody)!;
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class OwnersClient
+    {
+        private readonly ApiClient client;
+
+        internal OwnersClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        public async Task<Owner> UpdateOwner(UpdateOwnerRequest payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Put,
+                RequestUri = client.CreateUri($"/owners")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("apiKey", Array.Empty<string>()), ("session", Array.Empty<string>()) }).ConfigureAwait(false);
+            var fields = new List<KeyValuePair<string, string>>();
+            fields.Add(new KeyValuePair<string, string>("name", payload.Name));
+            if (payload.Email != null)
+            {
+                fields.Add(new KeyValuePair<string, string>("email", payload.Email));
+            }
+            request.Content = new FormUrlEncodedContent(fields);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Owner>(responseBody)!;
+        }
+    }
+}
@@ -0,0 +1,202 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class PetsClient
+    {
+        private readonly ApiClient client;
+
+        internal PetsClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        /// <summary>
+        /// Lists the pets. The pets are listed by name.
+        /// </summary>
+        public async Task<List<Pet>> ListPets(string? owner, int? limit = null, Guid? xRequestId = null, string? session = null)
+        {
+            var queryParameters = new List<string>();
+            if (owner != null)
+            {
+                queryParameters.Add("owner=" + Uri.EscapeDataString(owner));
+            }
+            if (limit != null)
+            {
+                queryParameters.Add("limit=" + Uri.EscapeDataString(JsonConvert.SerializeObject(limit).Trim('"')));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pets{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("bearer", Array.Empty<string>()) }).ConfigureAwait(false);
+            if (xRequestId != null)
+            {
+                request.Headers.TryAddWithoutValidation("X-Request-Id", JsonConvert.SerializeObject(xRequestId).Trim('"'));
+            }
+            var cookies = new List<string>();
+            if (session != null)
+            {
+                cookies.Add("session=" + Uri.EscapeDataString(session));
+            }
+            if (cookies.Count > 0)
+            {
+                request.Headers.TryAddWithoutValidation("Cookie", string.Join("; ", cookies));
+            }
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                if (statusCode / 100 == 4)
+                {
+                    throw new ApiException<Problem>(statusCode, responseBody, JsonConvert.DeserializeObject<Problem>(responseBody)!);
+                }
+
+                throw new ApiException<Error>(statusCode, responseBody, JsonConvert.DeserializeObject<Error>(responseBody)!);
+            }
+
+            return JsonConvert.DeserializeObject<List<Pet>>(responseBody)!;
+        }
+
+        public async Task<Pet> CreatePet(Pet payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/pets")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("oauth", new[] { "write" }) }, new[] { ("basic", Array.Empty<string>()) }).ConfigureAwait(false);
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                if (statusCode == 409)
+                {
+                    throw new ApiException<Problem>(statusCode, responseBody, JsonConvert.DeserializeObject<Problem>(responseBody)!);
+                }
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Pet>(responseBody)!;
+        }
+
+        public async Task DeletePet(string petId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Delete,
+                RequestUri = client.CreateUri($"/pets/{Uri.EscapeDataString(petId)}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("bearer", Array.Empty<string>()) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                if (statusCode == 404)
+                {
+                    throw new ApiException<Problem>(statusCode, responseBody, JsonConvert.DeserializeObject<Problem>(responseBody)!);
+                }
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        public async Task<Stream> DownloadPhoto(string petId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pets/{Uri.EscapeDataString(petId)}/photo", new Server("https://uploads.example.com", new Dictionary<string, string>()))
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("apiKey", Array.Empty<string>()) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return await response.Content.ReadAsStreamAsync().ConfigureAwait(false);
+        }
+
+        public async Task UploadPhoto(string petId, UploadPhotoRequest payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/pets/{Uri.EscapeDataString(petId)}/photo", new Server("https://uploads.example.com", new Dictionary<string, string>()))
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("bearer", Array.Empty<string>()) }).ConfigureAwait(false);
+            var content = new MultipartFormDataContent();
+            content.Add(new StreamContent(payload.Photo), "photo", "photo");
+            if (payload.Caption != null)
+            {
+                content.Add(new StringContent(payload.Caption), "caption");
+            }
+            request.Content = content;
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        public async Task<string> DescribePet(string petId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pets/{Uri.EscapeDataString(petId)}/description")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return responseBody;
+        }
+    }
+}
@@ -0,0 +1,518 @@
+package sdk
+
+import (
+	"bytes"
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"io"
+	"mime/multipart"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// Error is returned when the API responds with an unsuccessful status code.
+type Error struct {
+	StatusCode int
+	Body       []byte
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("unexpected status code %d: %s", e.StatusCode, e.Body)
+}
+
+func (c *Client) do(ctx context.Context, method string, baseURL string, path string, query url.Values, header http.Header, security [][]securityScheme, payload interface{}, result interface{}) error {
+	if len(security) > 0 {
+		if query == nil {
+			query = url.Values{}
+		}
+		if header == nil {
+			header = http.Header{}
+		}
+		if err := c.authorize(ctx, security, query, header); err != nil {
+			return err
+		}
+	}
+
+	endpoint := strings.TrimSuffix(baseURL, "/") + path
+	if len(query) > 0 {
+		endpoint += "?" + query.Encode()
+	}
+
+	var body io.Reader
+	contentType := "application/json"
+	if raw, ok := payload.(*rawBody); ok {
+		body = raw.data
+		contentType = raw.contentType
+	} else if payload != nil {
+		data, err := json.Marshal(payload)
+		if err != nil {
+			return err
+		}
+		body = bytes.NewReader(data)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, endpoint, body)
+	if err != nil {
+		return err
+	}
+	for key, values := range header {
+		req.Header[key] = values
+	}
+	if payload != nil {
+		req.Header.Set("Content-Type", contentType)
+	}
+
+	resp, err := c.httpClient.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	data, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return err
+	}
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return &Error{StatusCode: resp.StatusCode, Body: data}
+	}
+
+	if result == nil {
+		return nil
+	}
+	if text, ok := result.(*string); ok {
+		*text = string(data)
+		return nil
+	}
+	if raw, ok := result.(*rawBytes); ok {
+		*raw = data
+		return nil
+	}
+	return json.Unmarshal(data, result)
+}
+
+// CredentialsProvider supplies the credentials for the security schemes of the API.
+type CredentialsProvider interface {
+	// Credential returns the credential for the security scheme, or false if there is none.
+	//
+	// The credential is the API key for API key schemes, "username:password" for HTTP basic
+	// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+	Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+}
+
+// CredentialsProviderFunc adapts a function to a CredentialsProvider.
+type CredentialsProviderFunc func(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+
+// Credential calls f(ctx, scheme, scopes).
+func (f CredentialsProviderFunc) Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error) {
+	return f(ctx, scheme, scopes)
+}
+
+// securityScheme is a security scheme that a request must satisfy, along with the scopes it
+// requires.
+type securityScheme struct {
+	name   string
+	scopes []string
+}
+
+// authorize adds the credentials for the first of the security requirements that the provider
+// can satisfy to the query and header of a request.
+func (c *Client) authorize(ctx context.Context, requirements [][]securityScheme, query url.Values, header http.Header) error {
+	for _, requirement := range requirements {
+		credentials := make([]string, 0, len(requirement))
+		for _, scheme := range requirement {
+			if c.credentials == nil {
+				break
+			}
+			credential, ok, err := c.credentials.Credential(ctx, scheme.name, scheme.scopes)
+			if err != nil {
+				return err
+			}
+			if !ok {
+				break
+			}
+			credentials = append(credentials, credential)
+		}
+		if len(credentials) == len(requirement) {
+			for i, scheme := range requirement {
+				applyCredential(scheme.name, credentials[i], query, header)
+			}
+			return nil
+		}
+	}
+	return nil
+}
+
+// rawBody is a payload that is sent as it is, rather than as JSON.
+type rawBody struct {
+	contentType string
+	data        io.Reader
+}
+
+// rawBytes receives a response body as it is, rather than as JSON.
+type rawBytes []byte
+
+// multipartBody encodes the fields and files as a multipart/form-data payload. Writing to a
+// buffer cannot fail, so neither can encoding.
+func multipartBody(fields url.Values, files map[string][][]byte) *rawBody {
+	var buffer bytes.Buffer
+	writer := multipart.NewWriter(&buffer)
+	for name, values := range fields {
+		for _, value := range values {
+			_ = writer.WriteField(name, value)
+		}
+	}
+	for name, contents := range files {
+		for _, content := range contents {
+			part, _ := writer.CreateFormFile(name, name)
+			_, _ = part.Write(content)
+		}
+	}
+	_ = writer.Close()
+	return &rawBody{contentType: writer.FormDataContentType(), data: &buffer}
+}
+
+func decodeStrict(data []byte, value interface{}) error {
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	decoder.DisallowUnknownFields()
+	return decoder.Decode(value)
+}
+
+func toString(value interface{}) string {
+	if text, ok := value.(string); ok {
+		return text
+	}
+	data, _ := json.Marshal(value)
+	return strings.Trim(string(data), `"`)
+}
+
+type Pet struct {
+	ID         string            `json:"id"`
+	Name       string            `json:"name"`
+	Kind       Kind              `json:"kind"`
+	Nickname   *string           `json:"nickname"`
+	Status     Status            `json:"status"`
+	Tags       []string          `json:"tags,omitempty"`
+	Attributes map[string]string `json:"attributes,omitempty"`
+	Extra      interface{}       `json:"extra,omitempty"`
+	BornAt     *time.Time        `json:"born-at,omitempty"`
+	Weight     *float64          `json:"weight,omitempty"`
+	Price      *string           `json:"price,omitempty"`
+	ChipID     *int64            `json:"chipId,omitempty"`
+	Position   []float32         `json:"position,omitempty"`
+	Parent     *Pet              `json:"parent,omitempty"`
+	Owner      *Owner            `json:"owner,omitempty"`
+	Type       *string           `json:"type,omitempty"`
+	Class      *string           `json:"class,omitempty"`
+	Default    *bool             `json:"default,omitempty"`
+}
+
+type Kind string
+
+const (
+	KindDog       Kind = "dog"
+	KindCat       Kind = "cat"
+	KindGuineaPig Kind = "guinea-pig"
+)
+
+type Status int64
+
+const (
+	StatusValue1 Status = 1
+	StatusValue2 Status = 2
+	StatusValue3 Status = 3
+)
+
+type Owner struct {
+	Name    string  `json:"name"`
+	Email   *string `json:"email,omitempty"`
+	Website *string `json:"website,omitempty"`
+	Avatar  []byte  `json:"avatar,omitempty"`
+	Pets    []Pet   `json:"pets,omitempty"`
+}
+
+type Problem struct {
+	Title  string `json:"title"`
+	Status *int64 `json:"status,omitempty"`
+}
+
+type Error_ struct {
+	Message *string `json:"message,omitempty"`
+}
+
+type UploadPhotoRequest struct {
+	Photo   []byte  `json:"photo"`
+	Caption *string `json:"caption,omitempty"`
+}
+
+type UpdateOwnerRequest struct {
+	Name  string  `json:"name"`
+	Email *string `json:"email,omitempty"`
+}
+
+type Event struct {
+	Adoption *Adoption
+	Visit    *Visit
+}
+
+func (u Event) MarshalJSON() ([]byte, error) {
+	switch {
+	case u.Adoption != nil:
+		return json.Marshal(u.Adoption)
+	case u.Visit != nil:
+		return json.Marshal(u.Visit)
+	}
+
+	return []byte("null"), nil
+}
+
+func (u *Event) UnmarshalJSON(data []byte) error {
+	var tag struct {
+		Value string `json:"type"`
+	}
+	if err := json.Unmarshal(data, &tag); err != nil {
+		return err
+	}
+
+	switch tag.Value {
+	case "adoption":
+		return json.Unmarshal(data, &u.Adoption)
+	case "visit":
+		return json.Unmarshal(data, &u.Visit)
+	}
+
+	return fmt.Errorf("unknown type %q for Event", tag.Value)
+}
+
+type Adoption struct {
+	Type  string    `json:"type"`
+	At    time.Time `json:"at"`
+	Owner Owner     `json:"owner"`
+}
+
+type Visit struct {
+	Type     string         `json:"type"`
+	At       time.Time      `json:"at"`
+	StartsAt *string        `json:"startsAt,omitempty"`
+	Duration *VisitDuration `json:"duration,omitempty"`
+}
+
+type VisitDuration struct {
+	Case1 *int64
+	Case2 *string
+}
+
+func (u VisitDuration) MarshalJSON() ([]byte, error) {
+	switch {
+	case u.Case1 != nil:
+		return json.Marshal(u.Case1)
+	case u.Case2 != nil:
+		return json.Marshal(u.Case2)
+	}
+
+	return []byte("null"), nil
+}
+
+func (u *VisitDuration) UnmarshalJSON(data []byte) error {
+	{
+		var value int64
+		if err := decodeStrict(data, &value); err == nil {
+			u.Case1 = &value
+			return nil
+		}
+	}
+	{
+		var value string
+		if err := decodeStrict(data, &value); err == nil {
+			u.Case2 = &value
+			return nil
+		}
+	}
+
+	return fmt.Errorf("value does not match any case of VisitDuration")
+}
+
+// Client is a client for the API.
+type Client struct {
+	httpClient      *http.Client
+	baseURL         string
+	credentials     CredentialsProvider
+	serverVariables map[string]string
+
+	Pets   *PetsService
+	Owners *OwnersService
+	Events *EventsService
+}
+
+// NewClient returns a client that sends requests to the API at the given base URL, using
+// http.DefaultClient when httpClient is nil.
+func NewClient(httpClient *http.Client, baseURL string) *Client {
+	if httpClient == nil {
+		httpClient = http.DefaultClient
+	}
+
+	c := &Client{httpClient: httpClient, baseURL: baseURL}
+	c.Pets = &PetsService{client: c}
+	c.Owners = &OwnersService{client: c}
+	c.Events = &EventsService{client: c}
+
+	return c
+}
+
+// WithCredentials sets the provider of the credentials that requests are authorized with.
+func (c *Client) WithCredentials(provider CredentialsProvider) *Client {
+	c.credentials = provider
+
+	return c
+}
+
+// WithServerVariables sets the values of the variables in the URLs of the servers that some routes
+// are sent to instead of the base URL, which otherwise take their defaults.
+func (c *Client) WithServerVariables(variables map[string]string) *Client {
+	c.serverVariables = variables
+
+	return c
+}
+
+// applyCredential adds the credential for the security scheme to the query or header of a request.
+func applyCredential(scheme string, credential string, query url.Values, header http.Header) {
+	switch scheme {
+	case "bearer":
+		header.Set("Authorization", "Bearer "+credential)
+	case "oauth":
+		header.Set("Authorization", "Bearer "+credential)
+	case "basic":
+		header.Set("Authorization", "Basic "+base64.StdEncoding.EncodeToString([]byte(credential)))
+	case "apiKey":
+		query.Add("api_key", credential)
+	case "session":
+		header.Set("Cookie", strings.TrimPrefix(header.Get("Cookie")+"; session_id="+url.QueryEscape(credential), "; "))
+	case "openId":
+		header.Set("Authorization", "Bearer "+credential)
+	}
+}
+
+// Server is a server that hosts the API, whose URL may hold variables written as {name}.
+type Server struct {
+	URL string
+	// Variables are the default values of the variables.
+	Variables map[string]string
+}
+
+// URLWith returns the URL of the server, with its variables replaced by the given values or
+// otherwise their defaults.
+func (s Server) URLWith(variables map[string]string) string {
+	result := s.URL
+	for name, value := range s.Variables {
+		if given, ok := variables[name]; ok {
+			value = given
+		}
+		result = strings.ReplaceAll(result, "{"+name+"}", value)
+	}
+
+	return result
+}
+
+// Servers are the servers that host the API.
+var Servers = []Server{
+	{URL: "https://{region}.example.com/{basePath}", Variables: map[string]string{"region": "eu", "basePath": "v1"}},
+}
+
+type PetsService struct {
+	client *Client
+}
+
+// ListPets Lists the pets. The pets are listed by name.
+func (s *PetsService) ListPets(ctx context.Context, owner *string, limit *int64, xRequestID *string, session *string) ([]Pet, error) {
+	query := url.Values{}
+	if owner != nil {
+		query.Add("owner", toString(*owner))
+	}
+	if limit != nil {
+		query.Add("limit", toString(*limit))
+	}
+	header := http.Header{}
+	if xRequestID != nil {
+		header.Add("X-Request-Id", toString(*xRequestID))
+	}
+	var cookies []string
+	if session != nil {
+		cookies = append(cookies, "session="+url.QueryEscape(toString(*session)))
+	}
+	if len(cookies) > 0 {
+		header.Set("Cookie", strings.Join(cookies, "; "))
+	}
+	var result []Pet
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/pets", query, header, [][]securityScheme{{{"bearer", nil}}}, nil, &result)
+
+	return result, err
+}
+
+func (s *PetsService) CreatePet(ctx context.Context, payload Pet) (Pet, error) {
+	var result Pet
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, "/pets", nil, nil, [][]securityScheme{{{"oauth", []string{"write"}}}, {{"basic", nil}}}, payload, &result)
+
+	return result, err
+}
+
+func (s *PetsService) DeletePet(ctx context.Context, petID string) error {
+	return s.client.do(ctx, http.MethodDelete, s.client.baseURL, fmt.Sprintf("/pets/%s", url.PathEscape(toString(petID))), nil, nil, [][]securityScheme{{{"bearer", nil}}}, nil, nil)
+}
+
+func (s *PetsService) DownloadPhoto(ctx context.Context, petID string) ([]byte, error) {
+	var result []byte
+	err := s.client.do(ctx, http.MethodGet, Server{URL: "https://uploads.example.com"}.URLWith(s.client.serverVariables), fmt.Sprintf("/pets/%s/photo", url.PathEscape(toString(petID))), nil, nil, [][]securityScheme{{{"apiKey", nil}}}, nil, (*rawBytes)(&result))
+
+	return result, err
+}
+
+func (s *PetsService) UploadPhoto(ctx context.Context, petID string, payload UploadPhotoRequest) error {
+	form := url.Values{}
+	files := map[string][][]byte{}
+	files["photo"] = append(files["photo"], payload.Photo)
+	if payload.Caption != nil {
+		form.Add("caption", toString(*payload.Caption))
+	}
+	return s.client.do(ctx, http.MethodPost, Server{URL: "https://uploads.example.com"}.URLWith(s.client.serverVariables), fmt.Sprintf("/pets/%s/photo", url.PathEscape(toString(petID))), nil, nil, [][]securityScheme{{{"bearer", nil}}}, multipartBody(form, files), nil)
+}
+
+func (s *PetsService) DescribePet(ctx context.Context, petID string) (string, error) {
+	var result string
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, fmt.Sprintf("/pets/%s/description", url.PathEscape(toString(petID))), nil, nil, nil, nil, &result)
+
+	return result, err
+}
+
+type OwnersService struct {
+	client *Client
+}
+
+func (s *OwnersService) UpdateOwner(ctx context.Context, payload UpdateOwnerRequest) (Owner, error) {
+	form := url.Values{}
+	form.Add("name", toString(payload.Name))
+	if payload.Email != nil {
+		form.Add("email", toString(*payload.Email))
+	}
+	var result Owner
+	err := s.client.do(ctx, http.MethodPut, s.client.baseURL, "/owners", nil, nil, [][]securityScheme{{{"apiKey", nil}, {"session", nil}}}, &rawBody{contentType: "application/x-www-form-urlencoded", data: strings.NewReader(form.Encode())}, &result)
+
+	return result, err
+}
+
+type EventsService struct {
+	client *Client
+}
+
+func (s *EventsService) ListEvents(ctx context.Context, since *string) ([]Event, error) {
+	query := url.Values{}
+	if since != nil {
+		query.Add("since", toString(*since))
+	}
+	var result []Event
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/events", query, nil, [][]securityScheme{{{"openId", nil}}}, nil, &result)
+
+	return result, err
+}
@@ -0,0 +1,589 @@
+from __future__ import annotations
+
+import base64
+import dataclasses
+import datetime
+import decimal
+import json
+import uuid
+from dataclasses import dataclass, field
+from typing import Annotated, Any, Callable, Dict, ForwardRef, List, Literal, Optional, Tuple, Union, get_args, get_origin
+from urllib.parse import quote
+
+import httpx
+
+
+@dataclass(frozen=True)
+class _Tagged:
+    discriminator: str
+    cases: Dict[str, str]
+
+
+def _resolve(tp: Any) -> Any:
+    if isinstance(tp, ForwardRef):
+        tp = tp.__forward_arg__
+    if isinstance(tp, str):
+        return _TYPES[tp]
+    return tp
+
+
+_STRING_DECODERS = {
+    datetime.datetime: lambda data: datetime.datetime.fromisoformat(data.replace("Z", "+00:00")),
+    datetime.date: datetime.date.fromisoformat,
+    datetime.time: lambda data: datetime.time.fromisoformat(data.replace("Z", "+00:00")),
+    uuid.UUID: uuid.UUID,
+    bytes: base64.b64decode,
+}
+
+
+def _decode(tp: Any, data: Any) -> Any:
+    tp = _resolve(tp)
+    origin = get_origin(tp)
+    args = get_args(tp)
+
+    if data is None:
+        return None
+    if origin is Annotated:
+        tagged = tp.__metadata__[0]
+        return _decode(tagged.cases[data[tagged.discriminator]], data)
+    if origin is Union:
+        for case in args:
+            try:
+                return _decode(case, data)
+            except (TypeError, ValueError, KeyError):
+                continue
+        raise ValueError(f"{data!r} does not match any case of {tp}.")
+    if origin is list:
+        return [_decode(args[0], item) for item in data]
+    if origin is tuple:
+        return tuple(_decode(item_tp, item) for item_tp, item in zip(args, data))
+    if origin is dict:
+        return {key: _decode(args[1], value) for key, value in data.items()}
+    if origin is Literal:
+        if data not in args:
+            raise ValueError(f"{data!r} is not one of {args}.")
+        return data
+    if dataclasses.is_dataclass(tp):
+        if not isinstance(data, dict):
+            raise TypeError(f"Expected an object for {tp.__name__}.")
+        values = {}
+        for tp_field in dataclasses.fields(tp):
+            key = tp_field.metadata.get("json", tp_field.name)
+            if key in data:
+                values[tp_field.name] = _decode(tp_field.type, data[key])
+        return tp(**values)
+    if tp in _STRING_DECODERS:
+        if not isinstance(data, str):
+            raise TypeError(f"Expected a string for {tp.__name__}.")
+        return _STRING_DECODERS[tp](data)
+    if tp is decimal.Decimal and isinstance(data, (int, float, str)) and not isinstance(data, bool):
+        return decimal.Decimal(str(data))
+    if tp is float and isinstance(data, int):
+        return float(data)
+    if isinstance(tp, type) and not isinstance(data, tp):
+        raise TypeError(f"Expected {tp.__name__}, got {type(data).__name__}.")
+    return data
+
+
+def _encode(value: Any) -> Any:
+    if dataclasses.is_dataclass(value):
+        return {
+            tp_field.metadata.get("json", tp_field.name): _encode(getattr(value, tp_field.name))
+            for tp_field in dataclasses.fields(value)
+            if getattr(value, tp_field.name) is not None or tp_field.metadata.get("nullable")
+        }
+    if isinstance(value, (list, tuple)):
+        return [_encode(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _encode(item) for key, item in value.items()}
+    if isinstance(value, (datetime.datetime, datetime.date, datetime.time)):
+        return value.isoformat()
+    if isinstance(value, decimal.Decimal):
+        return str(value)
+    if isinstance(value, uuid.UUID):
+        return str(value)
+    if isinstance(value, bytes):
+        return base64.b64encode(value).decode("ascii")
+    return value
+
+
+def _to_string(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    return json.dumps(_encode(value)).strip('"')
+
+
+@dataclass
+class Pet:
+    id: uuid.UUID
+    name: str
+    kind: Kind
+    nickname: Optional[str] = field(metadata={"nullable": True})
+    status: Status
+    tags: Optional[List[str]] = None
+    attributes: Optional[Dict[str, str]] = None
+    extra: Optional[Any] = None
+    born_at: Optional[datetime.datetime] = field(default=None, metadata={"json": "born-at"})
+    weight: Optional[float] = None
+    price: Optional[str] = None
+    chip_id: Optional[int] = field(default=None, metadata={"json": "chipId"})
+    position: Optional[Tuple[float, float]] = None
+    parent: Optional[Pet] = None
+    owner: Optional[Owner] = None
+    type: Optional[str] = None
+    class_: Optional[str] = field(default=None, metadata={"json": "class"})
+    default: Optional[bool] = None
+
+
+Kind = Literal["dog", "cat", "guinea-pig"]
+
+
+Status = Literal[1, 2, 3]
+
+
+@dataclass
+class Owner:
+    name: str
+    email: Optional[str] = None
+    website: Optional[str] = None
+    avatar: Optional[bytes] = None
+    pets: Optional[List[Pet]] = None
+
+
+@dataclass
+class Problem:
+    title: str
+    status: Optional[int] = None
+
+
+@dataclass
+class Error:
+    message: Optional[str] = None
+
+
+@dataclass
+class UploadPhotoRequest:
+    photo: bytes
+    caption: Optional[str] = None
+
+
+@dataclass
+class UpdateOwnerRequest:
+    name: str
+    email: Optional[str] = None
+
+
+Event = Annotated[Union["Adoption", "Visit"], _Tagged("type", {"adoption": "Adoption", "visit": "Visit"})]
+
+
+@dataclass
+class Adoption:
+    type: str
+    at: datetime.datetime
+    owner: Owner
+
+
+@dataclass
+class Visit:
+    type: str
+    at: datetime.datetime
+    starts_at: Optional[datetime.time] = field(default=None, metadata={"json": "startsAt"})
+    duration: Optional[VisitDuration] = None
+
+
+VisitDuration = Union["int", "str"]
+
+
+_TYPES: Dict[str, Any] = {
+    "uuid.UUID": uuid.UUID,
+    "str": str,
+    "Kind": Kind,
+    "Optional[str]": Optional[str],
+    "Status": Status,
+    "Optional[List[str]]": Optional[List[str]],
+    "Optional[Dict[str, str]]": Optional[Dict[str, str]],
+    "Optional[Any]": Optional[Any],
+    "Optional[datetime.datetime]": Optional[datetime.datetime],
+    "Optional[float]": Optional[float],
+    "Optional[int]": Optional[int],
+    "Optional[Tuple[float, float]]": Optional[Tuple[float, float]],
+    "Optional[Pet]": Optional[Pet],
+    "Optional[Owner]": Optional[Owner],
+    "Optional[bool]": Optional[bool],
+    "Optional[bytes]": Optional[bytes],
+    "Optional[List[Pet]]": Optional[List[Pet]],
+    "bytes": bytes,
+    "Adoption": Adoption,
+    "Visit": Visit,
+    "datetime.datetime": datetime.datetime,
+    "Owner": Owner,
+    "Optional[datetime.time]": Optional[datetime.time],
+    "Optional[VisitDuration]": Optional[VisitDuration],
+    "int": int,
+}
+
+
+CredentialsProvider = Callable[[str, List[str]], Optional[str]]
+"""Supplies the credential for a security scheme of the API, or `None` if there is none.
+
+The credential is the API key for API key schemes, `username:password` for HTTP basic
+authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+"""
+
+
+def _authorize(
+    credentials: Optional[CredentialsProvider],
+    requirements: List[List[Tuple[str, List[str]]]],
+    headers: Dict[str, str],
+    params: List[Tuple[str, str]],
+) -> None:
+    for requirement in requirements:
+        values: List[str] = []
+        for scheme, scopes in requirement:
+            value = credentials(scheme, scopes) if credentials else None
+            if value is None:
+                break
+            values.append(value)
+        else:
+            for (scheme, _), value in zip(requirement, values):
+                _apply_credential(scheme, value, headers, params)
+            return
+
+
+def _apply_credential(
+    scheme: str, credential: str, headers: Dict[str, str], params: List[Tuple[str, str]]
+) -> None:
+    if scheme == "bearer":
+        headers["Authorization"] = "Bearer " + credential
+    elif scheme == "oauth":
+        headers["Authorization"] = "Bearer " + credential
+    elif scheme == "basic":
+        headers["Authorization"] = "Basic " + base64.b64encode(credential.encode()).decode("ascii")
+    elif scheme == "apiKey":
+        params.append(("api_key", credential))
+    elif scheme == "session":
+        headers["Cookie"] = "; ".join(filter(None, [headers.get("Cookie"), "session_id=" + quote(credential)]))
+    elif scheme == "openId":
+        headers["Authorization"] = "Bearer " + credential
+
+
+class Server:
+    """A server that hosts the API, whose URL may hold variables written as `{name}`."""
+
+    def __init__(self, url: str, variables: Optional[Dict[str, str]] = None) -> None:
+        self.url = url
+        self.variables = variables or {}
+        """The default values of the variables."""
+
+    def url_with(self, variables: Optional[Dict[str, str]] = None) -> str:
+        """Returns the URL of the server, with its variables replaced by the given values or
+        otherwise their defaults.
+        """
+        url = self.url
+        for name, value in self.variables.items():
+            url = url.replace("{" + name + "}", (variables or {}).get(name, value))
+        return url
+
+
+SERVERS: List[Server] = [
+    Server("https://{region}.example.com/{basePath}", {"region": "eu", "basePath": "v1"}),
+]
+"""The servers that host the API, the first of which is preferred."""
+
+
+class PetsResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None, server_variables: Optional[Dict[str, str]] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+        self._server_variables = server_variables
+
+    def list_pets(self, owner: Optional[str], limit: Optional[int] = None, x_request_id: Optional[uuid.UUID] = None, session: Optional[str] = None) -> List[Pet]:
+        """Lists the pets. The pets are listed by name."""
+        params: List[Tuple[str, str]] = []
+        if owner is not None:
+            params.append(("owner", _to_string(owner)))
+        if limit is not None:
+            params.append(("limit", _to_string(limit)))
+        headers: Dict[str, str] = {}
+        if x_request_id is not None:
+            headers["X-Request-Id"] = _to_string(x_request_id)
+        cookies: List[str] = []
+        if session is not None:
+            cookies.append("session=" + quote(_to_string(session)))
+        if cookies:
+            headers["Cookie"] = "; ".join(cookies)
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/pets",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    def create_pet(self, payload: Pet) -> Pet:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("oauth", ["write"])], [("basic", [])]], headers, params)
+        response = self._client.request(
+            "POST",
+            f"/pets",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    def delete_pet(self, pet_id: str) -> None:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        response = self._client.request(
+            "DELETE",
+            f"/pets/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    def download_photo(self, pet_id: str) -> bytes:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("apiKey", [])]], headers, params)
+        base_url = Server("https://uploads.example.com").url_with(self._server_variables)
+        response = self._client.request(
+            "GET",
+            f"{base_url}/pets/{quote(pet_id, safe='')}/photo",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return response.content
+
+    def upload_photo(self, pet_id: str, payload: UploadPhotoRequest) -> None:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        files: List[Tuple[str, Any]] = []
+        files.append(("photo", ("photo", payload.photo)))
+        if payload.caption is not None:
+            files.append(("caption", (None, _to_string(payload.caption))))
+        base_url = Server("https://uploads.example.com").url_with(self._server_variables)
+        response = self._client.request(
+            "POST",
+            f"{base_url}/pets/{quote(pet_id, safe='')}/photo",
+            params=params,
+            headers=headers,
+            files=files,
+        )
+        response.raise_for_status()
+
+    def describe_pet(self, pet_id: str) -> str:
+        response = self._client.request(
+            "GET",
+            f"/pets/{quote(pet_id, safe='')}/description",
+        )
+        response.raise_for_status()
+
+        return response.text
+
+
+class AsyncPetsResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None, server_variables: Optional[Dict[str, str]] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+        self._server_variables = server_variables
+
+    async def list_pets(self, owner: Optional[str], limit: Optional[int] = None, x_request_id: Optional[uuid.UUID] = None, session: Optional[str] = None) -> List[Pet]:
+        """Lists the pets. The pets are listed by name."""
+        params: List[Tuple[str, str]] = []
+        if owner is not None:
+            params.append(("owner", _to_string(owner)))
+        if limit is not None:
+            params.append(("limit", _to_string(limit)))
+        headers: Dict[str, str] = {}
+        if x_request_id is not None:
+            headers["X-Request-Id"] = _to_string(x_request_id)
+        cookies: List[str] = []
+        if session is not None:
+            cookies.append("session=" + quote(_to_string(session)))
+        if cookies:
+            headers["Cookie"] = "; ".join(cookies)
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/pets",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    async def create_pet(self, payload: Pet) -> Pet:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("oauth", ["write"])], [("basic", [])]], headers, params)
+        response = await self._client.request(
+            "POST",
+            f"/pets",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    async def delete_pet(self, pet_id: str) -> None:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        response = await self._client.request(
+            "DELETE",
+            f"/pets/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    async def download_photo(self, pet_id: str) -> bytes:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("apiKey", [])]], headers, params)
+        base_url = Server("https://uploads.example.com").url_with(self._server_variables)
+        response = await self._client.request(
+            "GET",
+            f"{base_url}/pets/{quote(pet_id, safe='')}/photo",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return response.content
+
+    async def upload_photo(self, pet_id: str, payload: UploadPhotoRequest) -> None:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("bearer", [])]], headers, params)
+        files: List[Tuple[str, Any]] = []
+        files.append(("photo", ("photo", payload.photo)))
+        if payload.caption is not None:
+            files.append(("caption", (None, _to_string(payload.caption))))
+        base_url = Server("https://uploads.example.com").url_with(self._server_variables)
+        response = await self._client.request(
+            "POST",
+            f"{base_url}/pets/{quote(pet_id, safe='')}/photo",
+            params=params,
+            headers=headers,
+            files=files,
+        )
+        response.raise_for_status()
+
+    async def describe_pet(self, pet_id: str) -> str:
+        response = await self._client.request(
+            "GET",
+            f"/pets/{quote(pet_id, safe='')}/description",
+        )
+        response.raise_for_status()
+
+        return response.text
+
+
+class OwnersResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    def update_owner(self, payload: UpdateOwnerRequest) -> Owner:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("apiKey", []), ("session", [])]], headers, params)
+        form: Dict[str, List[str]] = {}
+        form.setdefault("name", []).append(_to_string(payload.name))
+        if payload.email is not None:
+            form.setdefault("email", []).append(_to_string(payload.email))
+        response = self._client.request(
+            "PUT",
+            f"/owners",
+            params=params,
+            headers=headers,
+            data=form,
+        )
+        response.raise_for_status()
+
+        return _decode(Owner, response.json())
+
+
+class AsyncOwnersResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    async def update_owner(self, payload: UpdateOwnerRequest) -> Owner:
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("apiKey", []), ("session", [])]], headers, params)
+        form: Dict[str, List[str]] = {}
+        form.setdefault("name", []).append(_to_string(payload.name))
+        if payload.email is not None:
+            form.setdefault("email", []).append(_to_string(payload.email))
+        response = await self._client.request(
+            "PUT",
+            f"/owners",
+            params=params,
+            headers=headers,
+            data=form,
+        )
+        response.raise_for_status()
+
+        return _decode(Owner, response.json())
+
+
+class EventsResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    def list_events(self, since: Optional[datetime.date] = None) -> List[Event]:
+        params: List[Tuple[str, str]] = []
+        if since is not None:
+            params.append(("since", _to_string(since)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("openId", [])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/events",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Event], response.json())
+
+
+class AsyncEventsResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    async def list_events(self, since: Optional[datetime.date] = None) -> List[Event]:
+        params: List[Tuple[str, str]] = []
+        if since is not None:
+            params.append(("since", _to_string(since)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("openId", [])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/events",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Event], response.json())
@@ -0,0 +1,450 @@
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the [`Client`].
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    Request(reqwest::Error),
+    /// The API responded with an unsuccessful status code.
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// The response body could not be deserialized.
+    Deserialize(serde_json::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request failed: {}", err),
+            Error::Status { status, body } => write!(f, "unexpected status code {}: {}", status, body),
+            Error::Deserialize(err) => write!(f, "invalid response body: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Deserialize(err)
+    }
+}
+
+/// Supplies the credentials for the security schemes of the API.
+///
+/// The credential is the API key for API key schemes, `username:password` for HTTP basic
+/// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+pub trait CredentialsProvider: std::fmt::Debug + Send + Sync {
+    /// Returns the credential for the security scheme, if there is one.
+    fn credential(&self, scheme: &str, scopes: &[&str]) -> Option<String>;
+}
+
+/// A client for the API.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    credentials: Option<std::sync::Arc<dyn CredentialsProvider>>,
+    server_variables: Vec<(String, String)>,
+}
+
+impl Client {
+    /// Returns a client that sends requests to the API at the given base URL.
+    pub fn new(http_client: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            http_client,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            credentials: None,
+            server_variables: Vec::new(),
+        }
+    }
+
+    /// Authorizes requests with the credentials of the given provider.
+    pub fn with_credentials(mut self, provider: impl CredentialsProvider + 'static) -> Self {
+        self.credentials = Some(std::sync::Arc::new(provider));
+        self
+    }
+
+    /// Sets the values of the variables in the URLs of the servers that some routes are sent to
+    /// instead of the base URL, which otherwise take their defaults.
+    pub fn with_server_variables(mut self, variables: &[(&str, &str)]) -> Self {
+        self.server_variables = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        self
+    }
+}
+
+impl Client {
+    /// Adds the credentials for the first of the security requirements that the provider can
+    /// satisfy to the request.
+    #[allow(dead_code)]
+    fn authorize(
+        &self,
+        mut request: reqwest::RequestBuilder,
+        requirements: &[&[(&str, &[&str])]],
+    ) -> reqwest::RequestBuilder {
+        let provider = match &self.credentials {
+            Some(provider) => provider,
+            None => return request,
+        };
+
+        for requirement in requirements {
+            let credentials = requirement
+                .iter()
+                .map(|(scheme, scopes)| provider.credential(scheme, scopes))
+                .collect::<Option<Vec<_>>>();
+
+            if let Some(credentials) = credentials {
+                for ((scheme, _), credential) in requirement.iter().zip(credentials) {
+                    request = apply_credential(request, scheme, credential);
+                }
+                break;
+            }
+        }
+
+        request
+    }
+
+    /// Sends the request, returning the body of a successful response.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(body)
+    }
+
+    /// Sends the request, returning the body of a successful response as it is.
+    #[allow(dead_code)]
+    async fn send_bytes(&self, request: reqwest::RequestBuilder) -> Result<Vec<u8>, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+#[allow(dead_code)]
+fn to_string<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+#[allow(dead_code)]
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Adds the credential for the security scheme to the request.
+#[allow(dead_code)]
+fn apply_credential(
+    request: reqwest::RequestBuilder,
+    scheme: &str,
+    credential: String,
+) -> reqwest::RequestBuilder {
+    match scheme {
+        "bearer" => request.bearer_auth(credential),
+        "oauth" => request.bearer_auth(credential),
+        "basic" => {
+            let mut parts = credential.splitn(2, ':');
+            request.basic_auth(parts.next().unwrap_or_default(), parts.next())
+        }
+        "apiKey" => request.query(&[("api_key", credential)]),
+        "session" => request.header("Cookie", format!("session_id={}", encode_component(&credential))),
+        "openId" => request.bearer_auth(credential),
+        _ => request,
+    }
+}
+
+/// A server that hosts the API, whose URL may hold variables written as `{name}`.
+#[derive(Debug, Clone, Copy)]
+pub struct Server {
+    pub url: &'static str,
+    /// The default values of the variables.
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+impl Server {
+    /// Returns the URL of the server, with its variables replaced by the given values or
+    /// otherwise their defaults.
+    pub fn url_with<S: AsRef<str>>(&self, variables: &[(S, S)]) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.to_owned(), |url, (name, default)| {
+                let value = variables
+                    .iter()
+                    .find(|(given, _)| given.as_ref() == *name)
+                    .map_or(*default, |(_, value)| value.as_ref());
+
+                url.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+
+/// The servers that host the API.
+pub const SERVERS: &[Server] = &[
+    Server { url: "https://{region}.example.com/{basePath}", variables: &[("region", "eu"), ("basePath", "v1")] },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pet {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub kind: Kind,
+    pub nickname: Option<String>,
+    pub status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<std::collections::HashMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<serde_json::Value>,
+    #[serde(rename = "born-at", default, skip_serializing_if = "Option::is_none")]
+    pub born_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(rename = "chipId", default, skip_serializing_if = "Option::is_none")]
+    pub chip_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<(f32, f32)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Pet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Owner>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Kind {
+    #[serde(rename = "dog")]
+    Dog,
+    #[serde(rename = "cat")]
+    Cat,
+    #[serde(rename = "guinea-pig")]
+    GuineaPig,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[repr(i64)]
+pub enum Status {
+    Value1 = 1,
+    Value2 = 2,
+    Value3 = 3,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Owner {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pets: Option<Vec<Pet>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Problem {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Error_ {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadPhotoRequest {
+    pub photo: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateOwnerRequest {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Event {
+    Adoption(Adoption),
+    Visit(Visit),
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let tag = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_owned);
+
+        let case = match tag.as_deref() {
+            Some("adoption") => serde_json::from_value(value).map(Event::Adoption).map_err(|err| err.to_string()),
+            Some("visit") => serde_json::from_value(value).map(Event::Visit).map_err(|err| err.to_string()),
+            tag => Err(format!("unknown type {:?} for Event", tag)),
+        };
+
+        case.map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Adoption {
+    pub r#type: String,
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+    pub owner: Owner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Visit {
+    pub r#type: String,
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+    #[serde(rename = "startsAt", default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<chrono::NaiveTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<VisitDuration>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VisitDuration {
+    Case1(i64),
+    Case2(String),
+}
+
+impl Client {
+    /// Lists the pets. The pets are listed by name.
+    pub async fn list_pets(&self, owner: Option<String>, limit: Option<i64>, x_request_id: Option<uuid::Uuid>, session: Option<String>) -> Result<Vec<Pet>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/pets", self.base_url));
+        if let Some(owner) = owner.as_ref() {
+            request = request.query(&[("owner", to_string(owner))]);
+        }
+        if let Some(limit) = limit.as_ref() {
+            request = request.query(&[("limit", to_string(limit))]);
+        }
+        if let Some(x_request_id) = x_request_id.as_ref() {
+            request = request.header("X-Request-Id", to_string(x_request_id));
+        }
+        let mut cookies = Vec::new();
+        if let Some(session) = session.as_ref() {
+            cookies.push(format!("session={}", encode_component(&to_string(session))));
+        }
+        if !cookies.is_empty() {
+            request = request.header("Cookie", cookies.join("; "));
+        }
+        request = self.authorize(request, &[&[("bearer", &[])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    pub async fn create_pet(&self, payload: Pet) -> Result<Pet, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/pets", self.base_url));
+        request = request.json(&payload);
+        request = self.authorize(request, &[&[("oauth", &["write"])], &[("basic", &[])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    pub async fn delete_pet(&self, pet_id: String) -> Result<(), Error> {
+        let mut request = self.http_client.request(reqwest::Method::DELETE, format!("{}/pets/{}", self.base_url, encode_component(&to_string(&pet_id))));
+        request = self.authorize(request, &[&[("bearer", &[])]]);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    pub async fn download_photo(&self, pet_id: String) -> Result<Vec<u8>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/pets/{}/photo", Server { url: "https://uploads.example.com", variables: &[] }.url_with(&self.server_variables), encode_component(&to_string(&pet_id))));
+        request = self.authorize(request, &[&[("apiKey", &[])]]);
+
+        self.send_bytes(request).await
+    }
+
+    pub async fn upload_photo(&self, pet_id: String, payload: UploadPhotoRequest) -> Result<(), Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/pets/{}/photo", Server { url: "https://uploads.example.com", variables: &[] }.url_with(&self.server_variables), encode_component(&to_string(&pet_id))));
+        let mut form = reqwest::multipart::Form::new();
+        form = form.part("photo", reqwest::multipart::Part::bytes(payload.photo).file_name("photo"));
+        if let Some(value) = payload.caption {
+            form = form.text("caption", to_string(&value));
+        }
+        request = request.multipart(form);
+        request = self.authorize(request, &[&[("bearer", &[])]]);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    pub async fn describe_pet(&self, pet_id: String) -> Result<String, Error> {
+        let request = self.http_client.request(reqwest::Method::GET, format!("{}/pets/{}/description", self.base_url, encode_component(&to_string(&pet_id))));
+
+        self.send(request).await
+    }
+
+    pub async fn update_owner(&self, payload: UpdateOwnerRequest) -> Result<Owner, Error> {
+        let mut request = self.http_client.request(reqwest::Method::PUT, format!("{}/owners", self.base_url));
+        let mut form = Vec::new();
+        form.push(("name", to_string(&payload.name)));
+        if let Some(value) = payload.email {
+            form.push(("email", to_string(&value)));
+        }
+        request = request.form(&form);
+        request = self.authorize(request, &[&[("apiKey", &[]), ("session", &[])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    pub async fn list_events(&self, since: Option<chrono::NaiveDate>) -> Result<Vec<Event>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/events", self.base_url));
+        if let Some(since) = since.as_ref() {
+            request = request.query(&[("since", to_string(since))]);
+        }
+        request = self.authorize(request, &[&[("openId", &[])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+}
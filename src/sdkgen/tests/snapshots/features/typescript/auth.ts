@@ -0,0 +1,76 @@
+/**
+ * The names of the security schemes of the API.
+ */
+export type SecuritySchemeName = 'bearer' | 'oauth' | 'basic' | 'apiKey' | 'session' | 'openId';
+
+/**
+ * Supplies the credential for a security scheme of the API, or `undefined` if there is none.
+ *
+ * The credential is the API key for API key schemes, `username:password` for HTTP basic
+ * authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+ */
+export type CredentialsProvider = (
+    scheme: SecuritySchemeName,
+    scopes: string[],
+) => string | undefined | Promise<string | undefined>;
+
+/**
+ * The security schemes that must all be satisfied to authorize a request, along with the scopes
+ * that each requires.
+ */
+export type SecurityRequirement = [SecuritySchemeName, string[]][];
+
+/**
+ * Adds the credentials for the first of the security requirements that the provider can satisfy
+ * to the headers and query parameters of a request.
+ *
+ * @internal
+ */
+export async function authorize(
+    credentialsProvider: CredentialsProvider | undefined,
+    requirements: SecurityRequirement[],
+    headers: Record<string, string>,
+    params: URLSearchParams,
+): Promise<void> {
+    for (const requirement of requirements) {
+        const credentials = await Promise.all(
+            requirement.map(([scheme, scopes]) => credentialsProvider?.(scheme, scopes)),
+        );
+
+        if (credentials.every(credential => credential !== undefined)) {
+            requirement.forEach(([scheme], index) => {
+                applyCredential(scheme, credentials[index] as string, headers, params);
+            });
+
+            return;
+        }
+    }
+}
+
+function applyCredential(
+    scheme: SecuritySchemeName,
+    credential: string,
+    headers: Record<string, string>,
+    params: URLSearchParams,
+): void {
+    switch (scheme) {
+        case 'bearer':
+            headers['Authorization'] = `Bearer ${credential}`;
+            break;
+        case 'oauth':
+            headers['Authorization'] = `Bearer ${credential}`;
+            break;
+        case 'basic':
+            headers['Authorization'] = `Basic ${btoa(String.fromCharCode(...new TextEncoder().encode(credential)))}`;
+            break;
+        case 'apiKey':
+            params.append('api_key', credential);
+            break;
+        case 'session':
+            headers['Cookie'] = [headers['Cookie'], `session_id=${encodeURIComponent(credential)}`].filter(Boolean).join('; ');
+            break;
+        case 'openId':
+            headers['Authorization'] = `Bearer ${credential}`;
+            break;
+    }
+}
@@ -0,0 +1,79 @@
+import axios, { AxiosInstance, AxiosRequestConfig, AxiosResponse } from 'axios';
+import { CredentialsProvider } from './auth';
+import { serverUrl, servers } from './servers';
+import { PetsClient } from './resources/pets';
+import { OwnersClient } from './resources/owners';
+import { EventsClient } from './resources/events';
+
+/**
+ * Options for an `ApiClient`.
+ */
+export interface ApiClientOptions {
+    /**
+     * The base URL of the API, or `undefined` to use one of the servers of the API.
+     */
+    baseUrl?: string;
+    /**
+     * The index in `servers` of the server that requests are sent to when no base URL is given,
+     * or `undefined` for the first one.
+     */
+    serverIndex?: number;
+    /**
+     * The values of the variables in the URLs of the servers, which otherwise take their
+     * defaults.
+     */
+    serverVariables?: Record<string, string>;
+    /**
+     * The axios instance that sends requests, or `undefined` to use the default instance.
+     */
+    axios?: AxiosInstance;
+    /**
+     * Headers sent with every request, unless the request sets them itself.
+     */
+    defaultHeaders?: Record<string, string>;
+    /**
+     * How long to wait for a response in milliseconds, or `undefined` to use the timeout of the
+     * axios instance.
+     */
+    timeout?: number;
+    /**
+     * The provider of the credentials that requests are authorized with.
+     */
+    credentialsProvider?: CredentialsProvider;
+}
+
+/**
+ * A client for the API.
+ */
+export class ApiClient {
+    readonly pets: PetsClient;
+    readonly owners: OwnersClient;
+    readonly events: EventsClient;
+    /**
+     * The base URL that requests are sent to.
+     */
+    readonly baseUrl: string;
+
+    constructor(readonly options: ApiClientOptions) {
+        this.baseUrl = options.baseUrl ?? serverUrl(servers[options.serverIndex ?? 0], options.serverVariables);
+        this.pets = new PetsClient(this);
+        this.owners = new OwnersClient(this);
+        this.events = new EventsClient(this);
+    }
+
+    /**
+     * Sends a request to the API with the options of the client.
+     *
+     * @internal
+     */
+    request(config: AxiosRequestConfig): Promise<AxiosResponse> {
+        const { axios: instance = axios, defaultHeaders, timeout } = this.options;
+
+        return instance.request({
+            ...config,
+            baseURL: this.baseUrl,
+            headers: { ...defaultHeaders, ...config.headers },
+            ...(timeout === undefined ? {} : { timeout }),
+        });
+    }
+}
@@ -0,0 +1,42 @@
+/**
+ * The status code and body of an unsuccessful response.
+ */
+export interface ErrorResponse {
+    status: number;
+    body: unknown;
+}
+
+/**
+ * Thrown when the API responds with an unsuccessful status code.
+ *
+ * For routes that document their error responses, the response is one of them, discriminated by
+ * its status code.
+ */
+export class ApiError<TResponse extends ErrorResponse = ErrorResponse> extends Error {
+    constructor(readonly response: TResponse) {
+        super(`The API responded with status code ${response.status}.`);
+        this.name = 'ApiError';
+    }
+
+    get status(): TResponse['status'] {
+        return this.response.status;
+    }
+
+    get body(): TResponse['body'] {
+        return this.response.body;
+    }
+}
+
+/**
+ * Reads the body of an unsuccessful response that was received as text or binary data, parsing
+ * it as JSON when it is.
+ */
+export function decodeErrorBody(data: ArrayBuffer | string): unknown {
+    const text = typeof data === 'string' ? data : new TextDecoder().decode(data);
+
+    try {
+        return JSON.parse(text);
+    } catch {
+        return text;
+    }
+}
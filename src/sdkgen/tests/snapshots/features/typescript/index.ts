@@ -0,0 +1,19 @@
+export * from './models/Pet';
+export * from './models/Kind';
+export * from './models/Status';
+export * from './models/Owner';
+export * from './models/Problem';
+export * from './models/Error';
+export * from './models/UploadPhotoRequest';
+export * from './models/UpdateOwnerRequest';
+export * from './models/Event';
+export * from './models/Adoption';
+export * from './models/Visit';
+export * from './models/VisitDuration';
+export * from './resources/pets';
+export * from './resources/owners';
+export * from './resources/events';
+export * from './client';
+export * from './servers';
+export * from './errors';
+export * from './auth';
@@ -0,0 +1,14 @@
+import { Owner, decodeOwner } from './Owner';
+
+export interface Adoption {
+    type: string;
+    at: string;
+    owner: Owner;
+}
+
+export function decodeAdoption(value: any): Adoption {
+    return {
+        ...value,
+        owner: decodeOwner(value.owner),
+    };
+}
@@ -0,0 +1,13 @@
+import { Adoption, decodeAdoption } from './Adoption';
+import { Visit } from './Visit';
+
+export type Event = ({ type: 'adoption' } & Adoption) | ({ type: 'visit' } & Visit);
+
+export function decodeEvent(value: any): Event {
+    switch (value.type) {
+        case 'adoption':
+            return decodeAdoption(value) as Event;
+        default:
+            return value;
+    }
+}
@@ -0,0 +1,8 @@
+
+export type Kind = 'dog' | 'cat' | 'guinea-pig';
+
+export const Kind = {
+    Dog: 'dog',
+    Cat: 'cat',
+    GuineaPig: 'guinea-pig',
+} as const;
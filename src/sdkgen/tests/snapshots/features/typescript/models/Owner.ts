@@ -0,0 +1,16 @@
+import { Pet, decodePet } from './Pet';
+
+export interface Owner {
+    name: string;
+    email?: string;
+    website?: string;
+    avatar?: string;
+    pets?: Pet[];
+}
+
+export function decodeOwner(value: any): Owner {
+    return {
+        ...value,
+        pets: value.pets == null ? value.pets : value.pets.map((item: any) => decodePet(item)),
+    };
+}
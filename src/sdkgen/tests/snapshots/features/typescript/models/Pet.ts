@@ -0,0 +1,33 @@
+import { Kind } from './Kind';
+import { Status } from './Status';
+import { Owner, decodeOwner } from './Owner';
+
+export interface Pet {
+    id: string;
+    name: string;
+    kind: Kind;
+    nickname: string | null;
+    status: Status;
+    tags?: string[];
+    attributes?: Record<string, string>;
+    extra?: unknown;
+    'born-at'?: string;
+    weight?: number;
+    price?: string;
+    chipId?: bigint;
+    position?: [number, number];
+    parent?: Pet;
+    owner?: Owner | null;
+    type?: string;
+    class?: string;
+    default?: boolean;
+}
+
+export function decodePet(value: any): Pet {
+    return {
+        ...value,
+        chipId: value.chipId == null ? value.chipId : BigInt(value.chipId),
+        parent: value.parent == null ? value.parent : decodePet(value.parent),
+        owner: value.owner == null ? value.owner : decodeOwner(value.owner),
+    };
+}
@@ -0,0 +1,8 @@
+
+export type Status = 1 | 2 | 3;
+
+export const Status = {
+    Value1: 1,
+    Value2: 2,
+    Value3: 3,
+} as const;
@@ -0,0 +1,5 @@
+
+export interface UpdateOwnerRequest {
+    name: string;
+    email?: string;
+}
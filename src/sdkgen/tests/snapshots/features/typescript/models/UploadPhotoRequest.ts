@@ -0,0 +1,5 @@
+
+export interface UploadPhotoRequest {
+    photo: Blob;
+    caption?: string;
+}
@@ -0,0 +1,8 @@
+import { VisitDuration } from './VisitDuration';
+
+export interface Visit {
+    type: string;
+    at: string;
+    startsAt?: string;
+    duration?: VisitDuration;
+}
@@ -0,0 +1,2 @@
+
+export type VisitDuration = number | string;
@@ -0,0 +1,41 @@
+import type { ApiClient } from '../client';
+import { authorize } from '../auth';
+import { ApiError } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { Event, decodeEvent } from '../models/Event';
+
+/**
+ * The routes of the `events` resource.
+ */
+export class EventsClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async listEvents(since?: string): Promise<Event[]> {
+        const params = new URLSearchParams();
+        if (since != null) {
+            params.append('since', String(since));
+        }
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['openId', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/events`,
+            params,
+            headers,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return data.map((item: any) => decodeEvent(item));
+    }
+}
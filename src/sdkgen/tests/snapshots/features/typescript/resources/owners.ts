@@ -0,0 +1,45 @@
+import type { ApiClient } from '../client';
+import { authorize } from '../auth';
+import { ApiError } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { UpdateOwnerRequest } from '../models/UpdateOwnerRequest';
+import { Owner, decodeOwner } from '../models/Owner';
+
+/**
+ * The routes of the `owners` resource.
+ */
+export class OwnersClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async updateOwner(payload: UpdateOwnerRequest): Promise<Owner> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['apiKey', []], ['session', []]]], headers, params);
+        const form = new URLSearchParams();
+        form.append('name', String(payload.name));
+        if (payload.email != null) {
+            form.append('email', String(payload.email));
+        }
+        const response = await this.client.request({
+            method: 'put',
+            url: `/owners`,
+            params,
+            headers,
+            data: form,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeOwner(data);
+    }
+}
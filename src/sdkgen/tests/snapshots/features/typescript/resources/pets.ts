@@ -0,0 +1,186 @@
+import type { ApiClient } from '../client';
+import { authorize } from '../auth';
+import { ApiError, decodeErrorBody } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { serverUrl } from '../servers';
+import { Pet, decodePet } from '../models/Pet';
+import { Problem } from '../models/Problem';
+import { Error } from '../models/Error';
+import { UploadPhotoRequest } from '../models/UploadPhotoRequest';
+
+export type ListPetsErrorResponse = { status: number; body: Problem } | { status: number; body: Error };
+
+export type CreatePetErrorResponse = { status: 409; body: Problem };
+
+export type DeletePetErrorResponse = { status: 404; body: Problem };
+
+/**
+ * The routes of the `pets` resource.
+ */
+export class PetsClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * Lists the pets. The pets are listed by name.
+     *
+     * @throws {ApiError<ListPetsErrorResponse>} If the API responds with an unsuccessful status code.
+     */
+    async listPets(owner: string | null, limit?: number, xRequestId?: string, session?: string): Promise<Pet[]> {
+        const params = new URLSearchParams();
+        if (owner != null) {
+            params.append('owner', String(owner));
+        }
+        if (limit != null) {
+            params.append('limit', String(limit));
+        }
+        const headers: Record<string, string> = {};
+        if (xRequestId != null) {
+            headers['X-Request-Id'] = String(xRequestId);
+        }
+        const cookies: string[] = [];
+        if (session != null) {
+            cookies.push(`session=${encodeURIComponent(String(session))}`);
+        }
+        if (cookies.length > 0) {
+            headers['Cookie'] = cookies.join('; ');
+        }
+        await authorize(this.client.options.credentialsProvider, [[['bearer', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/pets`,
+            params,
+            headers,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data } as ListPetsErrorResponse);
+        }
+
+        return data.map((item: any) => decodePet(item));
+    }
+
+    /**
+     * @throws {ApiError<CreatePetErrorResponse>} If the API responds with an unsuccessful status code.
+     */
+    async createPet(payload: Pet): Promise<Pet> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        await authorize(this.client.options.credentialsProvider, [[['oauth', ['write']]], [['basic', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'post',
+            url: `/pets`,
+            params,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data } as CreatePetErrorResponse);
+        }
+
+        return decodePet(data);
+    }
+
+    /**
+     * @throws {ApiError<DeletePetErrorResponse>} If the API responds with an unsuccessful status code.
+     */
+    async deletePet(petId: string): Promise<void> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['bearer', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'delete',
+            url: `/pets/${encodeURIComponent(petId)}`,
+            params,
+            headers,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data } as DeletePetErrorResponse);
+        }
+    }
+
+    /**
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async downloadPhoto(petId: string): Promise<Blob> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['apiKey', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `${serverUrl({ url: 'https://uploads.example.com', variables: {} }, this.client.options.serverVariables)}/pets/${encodeURIComponent(petId)}/photo`,
+            params,
+            headers,
+            responseType: 'arraybuffer',
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            const errorBody = decodeErrorBody(response.data);
+
+            throw new ApiError({ status: response.status, body: errorBody });
+        }
+
+        return new Blob([response.data], { type: response.headers['content-type'] });
+    }
+
+    /**
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async uploadPhoto(petId: string, payload: UploadPhotoRequest): Promise<void> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['bearer', []]]], headers, params);
+        const form = new FormData();
+        form.append('photo', payload.photo);
+        if (payload.caption != null) {
+            form.append('caption', String(payload.caption));
+        }
+        const response = await this.client.request({
+            method: 'post',
+            url: `${serverUrl({ url: 'https://uploads.example.com', variables: {} }, this.client.options.serverVariables)}/pets/${encodeURIComponent(petId)}/photo`,
+            params,
+            headers,
+            data: form,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+
+    /**
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async describePet(petId: string): Promise<string> {
+        const response = await this.client.request({
+            method: 'get',
+            url: `/pets/${encodeURIComponent(petId)}/description`,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            const errorBody = decodeErrorBody(response.data);
+
+            throw new ApiError({ status: response.status, body: errorBody });
+        }
+
+        return response.data;
+    }
+}
@@ -0,0 +1,43 @@
+/**
+ * Parses JSON, keeping the integers that a `number` cannot represent exactly as strings, so that
+ * no digits are lost.
+ *
+ * Like axios, text that is not JSON is returned as it is.
+ */
+export function parseJson(text: string): any {
+    if (text === '') {
+        return undefined;
+    }
+
+    try {
+        return JSON.parse(
+                text.replace(/"(?:[^"\\]|\\.)*"|(?<![\d.eE+-])-?\d{16,}(?![\d.eE])/g, token =>
+                token.startsWith('"') || Number.isSafeInteger(Number(token)) ? token : `"${token}"`
+            )
+        );
+    } catch {
+        return text;
+    }
+}
+
+/**
+ * Serializes a value as JSON, writing `bigint`s as integers.
+ */
+export function stringifyJson(value: unknown): string {
+    if (typeof value === 'bigint') {
+        return value.toString();
+    }
+
+    if (Array.isArray(value)) {
+        return `[${value.map(item => stringifyJson(item === undefined ? null : item)).join(',')}]`;
+    }
+
+    if (value !== null && typeof value === 'object' && !(value instanceof Date)) {
+        return `{${Object.entries(value)
+            .filter(([, item]) => item !== undefined)
+            .map(([key, item]) => `${JSON.stringify(key)}:${stringifyJson(item)}`)
+            .join(',')}}`;
+    }
+
+    return JSON.stringify(value);
+}
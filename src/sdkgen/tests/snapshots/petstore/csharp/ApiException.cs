@@ -0,0 +1,36 @@
+#nullable enable
+
+using System;
+
+namespace Sdk;
+
+/// <summary>
+/// Thrown when the API responds with an unsuccessful status code.
+/// </summary>
+public class ApiException : Exception
+{
+    public ApiException(int statusCode, string responseBody)
+        : base($"The API responded with status code {statusCode}.")
+    {
+        StatusCode = statusCode;
+        ResponseBody = responseBody;
+    }
+
+    public int StatusCode { get; }
+
+    public string ResponseBody { get; }
+}
+
+/// <summary>
+/// Thrown when the API responds with an unsuccessful status code that it documents a response body for.
+/// </summary>
+public class ApiException<TError> : ApiException
+{
+    public ApiException(int statusCode, string responseBody, TError error)
+        : base(statusCode, responseBody)
+    {
+        Error = error;
+    }
+
+    public TError Error { get; }
+}
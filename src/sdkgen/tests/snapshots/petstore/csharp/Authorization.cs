@@ -0,0 +1,77 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+
+namespace Sdk;
+
+/// <summary>
+/// Supplies the credentials for the security schemes of the API.
+/// </summary>
+public interface ICredentialsProvider
+{
+    /// <summary>
+    /// Returns the credential for the security scheme, or null if there is none.
+    /// </summary>
+    /// <remarks>
+    /// The credential is the API key for API key schemes, <c>username:password</c> for HTTP basic
+    /// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+    /// </remarks>
+    Task<string?> GetCredentialAsync(string scheme, IReadOnlyList<string> scopes);
+}
+
+internal static class Authorization
+{
+    /// <summary>
+    /// Adds the credentials for the first of the security requirements that the provider can satisfy to the request.
+    /// </summary>
+    public static async Task AuthorizeAsync(HttpRequestMessage request, ICredentialsProvider? provider, params (string Scheme, string[] Scopes)[][] requirements)
+    {
+        foreach (var requirement in requirements)
+        {
+            var credentials = new List<string>();
+
+            foreach (var (scheme, scopes) in requirement)
+            {
+                var credential = provider == null ? null : await provider.GetCredentialAsync(scheme, scopes).ConfigureAwait(false);
+                if (credential == null)
+                {
+                    break;
+                }
+
+                credentials.Add(credential);
+            }
+
+            if (credentials.Count == requirement.Length)
+            {
+                for (var i = 0; i < requirement.Length; i++)
+                {
+                    ApplyCredential(request, requirement[i].Scheme, credentials[i]);
+                }
+
+                return;
+            }
+        }
+    }
+
+    private static void ApplyCredential(HttpRequestMessage request, string scheme, string credential)
+    {
+        switch (scheme)
+        {
+            case "petstore_auth":
+            {
+                request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", credential);
+                break;
+            }
+            case "api_key":
+            {
+                request.Headers.TryAddWithoutValidation("api_key", credential);
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class ApiResponse
+{
+    [JsonProperty("code", NullValueHandling = NullValueHandling.Ignore)]
+    public int? Code { get; set; }
+
+    [JsonProperty("type", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Type { get; set; }
+
+    [JsonProperty("message", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Message { get; set; }
+}
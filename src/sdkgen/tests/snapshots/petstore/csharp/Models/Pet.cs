@@ -0,0 +1,32 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class Pet
+{
+    [JsonProperty("id", NullValueHandling = NullValueHandling.Ignore)]
+    public long? Id { get; set; }
+
+    [JsonProperty("name")]
+    public string Name { get; set; } = null!;
+
+    [JsonProperty("category", NullValueHandling = NullValueHandling.Ignore)]
+    public Category? Category { get; set; }
+
+    [JsonProperty("photoUrls")]
+    public List<string> PhotoUrls { get; set; } = null!;
+
+    [JsonProperty("tags", NullValueHandling = NullValueHandling.Ignore)]
+    public List<Tag>? Tags { get; set; }
+
+    [JsonProperty("status", NullValueHandling = NullValueHandling.Ignore)]
+    public PetStatus? Status { get; set; }
+}
@@ -0,0 +1,22 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+[JsonConverter(typeof(StringEnumConverter))]
+public enum PetStatus
+{
+    [EnumMember(Value = "available")]
+    Available,
+    [EnumMember(Value = "pending")]
+    Pending,
+    [EnumMember(Value = "sold")]
+    Sold,
+}
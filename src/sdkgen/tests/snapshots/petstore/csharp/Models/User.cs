@@ -0,0 +1,38 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+
+namespace Sdk.Models;
+
+public class User
+{
+    [JsonProperty("id", NullValueHandling = NullValueHandling.Ignore)]
+    public long? Id { get; set; }
+
+    [JsonProperty("username", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Username { get; set; }
+
+    [JsonProperty("firstName", NullValueHandling = NullValueHandling.Ignore)]
+    public string? FirstName { get; set; }
+
+    [JsonProperty("lastName", NullValueHandling = NullValueHandling.Ignore)]
+    public string? LastName { get; set; }
+
+    [JsonProperty("email", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Email { get; set; }
+
+    [JsonProperty("password", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Password { get; set; }
+
+    [JsonProperty("phone", NullValueHandling = NullValueHandling.Ignore)]
+    public string? Phone { get; set; }
+
+    [JsonProperty("userStatus", NullValueHandling = NullValueHandling.Ignore)]
+    public int? UserStatus { get; set; }
+}
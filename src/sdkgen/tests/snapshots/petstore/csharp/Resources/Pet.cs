@@ -0,0 +1,264 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class PetClient
+    {
+        private readonly ApiClient client;
+
+        internal PetClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        /// <summary>
+        /// Add a new pet to the store. Add a new pet to the store
+        /// </summary>
+        public async Task<Pet> AddPet(Pet payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/pet")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Pet>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Update an existing pet. Update an existing pet by Id
+        /// </summary>
+        public async Task<Pet> UpdatePet(Pet payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Put,
+                RequestUri = client.CreateUri($"/pet")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Pet>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Finds Pets by status. Multiple status values can be provided with comma separated strings
+        /// </summary>
+        public async Task<List<Pet>> FindPetsByStatus(FindPetsByStatusStatus? status = null)
+        {
+            var queryParameters = new List<string>();
+            if (status != null)
+            {
+                queryParameters.Add("status=" + Uri.EscapeDataString(JsonConvert.SerializeObject(status).Trim('"')));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pet/findByStatus{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<List<Pet>>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing.
+        /// </summary>
+        public async Task<List<Pet>> FindPetsByTags(List<string>? tags = null)
+        {
+            var queryParameters = new List<string>();
+            if (tags != null)
+            {
+                foreach (var item in tags)
+            {
+                queryParameters.Add("tags=" + Uri.EscapeDataString(item));
+            }
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pet/findByTags{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<List<Pet>>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Find pet by ID. Returns a single pet
+        /// </summary>
+        public async Task<Pet> GetPetById(string petId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/pet/{Uri.EscapeDataString(petId)}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("api_key", Array.Empty<string>()) }, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Pet>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Updates a pet in the store with form data
+        /// </summary>
+        public async Task UpdatePetWithForm(string petId, string? name = null, string? status = null)
+        {
+            var queryParameters = new List<string>();
+            if (name != null)
+            {
+                queryParameters.Add("name=" + Uri.EscapeDataString(name));
+            }
+            if (status != null)
+            {
+                queryParameters.Add("status=" + Uri.EscapeDataString(status));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/pet/{Uri.EscapeDataString(petId)}{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        /// <summary>
+        /// Deletes a pet
+        /// </summary>
+        public async Task DeletePet(string petId, string? apiKey = null)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Delete,
+                RequestUri = client.CreateUri($"/pet/{Uri.EscapeDataString(petId)}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+            if (apiKey != null)
+            {
+                request.Headers.TryAddWithoutValidation("api_key", apiKey);
+            }
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        /// <summary>
+        /// uploads an image
+        /// </summary>
+        public async Task<ApiResponse> UploadFile(string petId, Stream payload, string? additionalMetadata = null)
+        {
+            var queryParameters = new List<string>();
+            if (additionalMetadata != null)
+            {
+                queryParameters.Add("additionalMetadata=" + Uri.EscapeDataString(additionalMetadata));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/pet/{Uri.EscapeDataString(petId)}/uploadImage{query}")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("petstore_auth", new[] { "write:pets", "read:pets" }) }).ConfigureAwait(false);
+            request.Content = new StreamContent(payload);
+            request.Content.Headers.ContentType = new MediaTypeHeaderValue("application/octet-stream");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<ApiResponse>(responseBody)!;
+        }
+    }
+}
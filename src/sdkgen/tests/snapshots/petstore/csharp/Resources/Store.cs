@@ -0,0 +1,121 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class StoreClient
+    {
+        private readonly ApiClient client;
+
+        internal StoreClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        /// <summary>
+        /// Returns pet inventories by status. Returns a map of status codes to quantities
+        /// </summary>
+        public async Task<Dictionary<string, int>> GetInventory()
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/store/inventory")
+            };
+            await Authorization.AuthorizeAsync(request, client.CredentialsProvider, new[] { ("api_key", Array.Empty<string>()) }).ConfigureAwait(false);
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Dictionary<string, int>>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Place an order for a pet. Place a new order in the store
+        /// </summary>
+        public async Task<Order> PlaceOrder(Order payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/store/order")
+            };
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Order>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions
+        /// </summary>
+        public async Task<Order> GetOrderById(string orderId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/store/order/{Uri.EscapeDataString(orderId)}")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<Order>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors
+        /// </summary>
+        public async Task DeleteOrder(string orderId)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Delete,
+                RequestUri = client.CreateUri($"/store/order/{Uri.EscapeDataString(orderId)}")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+    }
+}
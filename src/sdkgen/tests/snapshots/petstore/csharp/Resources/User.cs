@@ -0,0 +1,201 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using Sdk.Models;
+
+namespace Sdk.V
+{
+    public sealed class UserClient
+    {
+        private readonly ApiClient client;
+
+        internal UserClient(ApiClient client)
+        {
+            this.client = client;
+        }
+
+        /// <summary>
+        /// Create user. This can only be done by the logged in user.
+        /// </summary>
+        public async Task<User> CreateUser(User payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/user")
+            };
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<User>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Creates list of users with given input array. Creates list of users with given input array
+        /// </summary>
+        public async Task<User> CreateUsersWithListInput(List<User> payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Post,
+                RequestUri = client.CreateUri($"/user/createWithList")
+            };
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<User>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Logs user into the system
+        /// </summary>
+        public async Task<string> LoginUser(string? username = null, string? password = null)
+        {
+            var queryParameters = new List<string>();
+            if (username != null)
+            {
+                queryParameters.Add("username=" + Uri.EscapeDataString(username));
+            }
+            if (password != null)
+            {
+                queryParameters.Add("password=" + Uri.EscapeDataString(password));
+            }
+            var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/user/login{query}")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<string>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Logs out current logged in user session
+        /// </summary>
+        public async Task LogoutUser()
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/user/logout")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        /// <summary>
+        /// Get user by user name
+        /// </summary>
+        public async Task<User> GetUserByName(string username)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Get,
+                RequestUri = client.CreateUri($"/user/{Uri.EscapeDataString(username)}")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+            var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+
+                throw new ApiException(statusCode, responseBody);
+            }
+
+            return JsonConvert.DeserializeObject<User>(responseBody)!;
+        }
+
+        /// <summary>
+        /// Update user. This can only be done by the logged in user.
+        /// </summary>
+        public async Task UpdateUser(string username, User payload)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Put,
+                RequestUri = client.CreateUri($"/user/{Uri.EscapeDataString(username)}")
+            };
+            request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+
+        /// <summary>
+        /// Delete user. This can only be done by the logged in user.
+        /// </summary>
+        public async Task DeleteUser(string username)
+        {
+            var request = new HttpRequestMessage
+            {
+                Method = HttpMethod.Delete,
+                RequestUri = client.CreateUri($"/user/{Uri.EscapeDataString(username)}")
+            };
+
+            var response = await client.SendAsync(request).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var statusCode = (int)response.StatusCode;
+                var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+
+                throw new ApiException(statusCode, responseBody);
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+
+namespace Sdk;
+
+/// <summary>
+/// A server that hosts the API, whose URL may hold variables written as <c>{name}</c>.
+/// </summary>
+public sealed class Server
+{
+    public Server(string url, IReadOnlyDictionary<string, string> variables)
+    {
+        Url = url;
+        Variables = variables;
+    }
+
+    /// <summary>
+    /// The servers that host the API.
+    /// </summary>
+    public static IReadOnlyList<Server> All { get; } = new[]
+    {
+        new Server("/v3", new Dictionary<string, string>()),
+    };
+
+    public string Url { get; }
+
+    /// <summary>
+    /// The default values of the variables.
+    /// </summary>
+    public IReadOnlyDictionary<string, string> Variables { get; }
+
+    /// <summary>
+    /// Returns the URL of the server, with its variables replaced by the given values or otherwise
+    /// their defaults.
+    /// </summary>
+    public string GetUrl(IDictionary<string, string>? variables = null)
+    {
+        var url = Url;
+
+        foreach (var variable in Variables)
+        {
+            var value = variables != null && variables.TryGetValue(variable.Key, out var given) ? given : variable.Value;
+            url = url.Replace("{" + variable.Key + "}", value);
+        }
+
+        return url;
+    }
+}
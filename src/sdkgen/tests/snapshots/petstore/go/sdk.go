@@ -0,0 +1,503 @@
+package sdk
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"mime/multipart"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// Error is returned when the API responds with an unsuccessful status code.
+type Error struct {
+	StatusCode int
+	Body       []byte
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("unexpected status code %d: %s", e.StatusCode, e.Body)
+}
+
+func (c *Client) do(ctx context.Context, method string, baseURL string, path string, query url.Values, header http.Header, security [][]securityScheme, payload interface{}, result interface{}) error {
+	if len(security) > 0 {
+		if query == nil {
+			query = url.Values{}
+		}
+		if header == nil {
+			header = http.Header{}
+		}
+		if err := c.authorize(ctx, security, query, header); err != nil {
+			return err
+		}
+	}
+
+	endpoint := strings.TrimSuffix(baseURL, "/") + path
+	if len(query) > 0 {
+		endpoint += "?" + query.Encode()
+	}
+
+	var body io.Reader
+	contentType := "application/json"
+	if raw, ok := payload.(*rawBody); ok {
+		body = raw.data
+		contentType = raw.contentType
+	} else if payload != nil {
+		data, err := json.Marshal(payload)
+		if err != nil {
+			return err
+		}
+		body = bytes.NewReader(data)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, endpoint, body)
+	if err != nil {
+		return err
+	}
+	for key, values := range header {
+		req.Header[key] = values
+	}
+	if payload != nil {
+		req.Header.Set("Content-Type", contentType)
+	}
+
+	resp, err := c.httpClient.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	data, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return err
+	}
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return &Error{StatusCode: resp.StatusCode, Body: data}
+	}
+
+	if result == nil {
+		return nil
+	}
+	if text, ok := result.(*string); ok {
+		*text = string(data)
+		return nil
+	}
+	if raw, ok := result.(*rawBytes); ok {
+		*raw = data
+		return nil
+	}
+	return json.Unmarshal(data, result)
+}
+
+// CredentialsProvider supplies the credentials for the security schemes of the API.
+type CredentialsProvider interface {
+	// Credential returns the credential for the security scheme, or false if there is none.
+	//
+	// The credential is the API key for API key schemes, "username:password" for HTTP basic
+	// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+	Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+}
+
+// CredentialsProviderFunc adapts a function to a CredentialsProvider.
+type CredentialsProviderFunc func(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+
+// Credential calls f(ctx, scheme, scopes).
+func (f CredentialsProviderFunc) Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error) {
+	return f(ctx, scheme, scopes)
+}
+
+// securityScheme is a security scheme that a request must satisfy, along with the scopes it
+// requires.
+type securityScheme struct {
+	name   string
+	scopes []string
+}
+
+// authorize adds the credentials for the first of the security requirements that the provider
+// can satisfy to the query and header of a request.
+func (c *Client) authorize(ctx context.Context, requirements [][]securityScheme, query url.Values, header http.Header) error {
+	for _, requirement := range requirements {
+		credentials := make([]string, 0, len(requirement))
+		for _, scheme := range requirement {
+			if c.credentials == nil {
+				break
+			}
+			credential, ok, err := c.credentials.Credential(ctx, scheme.name, scheme.scopes)
+			if err != nil {
+				return err
+			}
+			if !ok {
+				break
+			}
+			credentials = append(credentials, credential)
+		}
+		if len(credentials) == len(requirement) {
+			for i, scheme := range requirement {
+				applyCredential(scheme.name, credentials[i], query, header)
+			}
+			return nil
+		}
+	}
+	return nil
+}
+
+// rawBody is a payload that is sent as it is, rather than as JSON.
+type rawBody struct {
+	contentType string
+	data        io.Reader
+}
+
+// rawBytes receives a response body as it is, rather than as JSON.
+type rawBytes []byte
+
+// multipartBody encodes the fields and files as a multipart/form-data payload. Writing to a
+// buffer cannot fail, so neither can encoding.
+func multipartBody(fields url.Values, files map[string][][]byte) *rawBody {
+	var buffer bytes.Buffer
+	writer := multipart.NewWriter(&buffer)
+	for name, values := range fields {
+		for _, value := range values {
+			_ = writer.WriteField(name, value)
+		}
+	}
+	for name, contents := range files {
+		for _, content := range contents {
+			part, _ := writer.CreateFormFile(name, name)
+			_, _ = part.Write(content)
+		}
+	}
+	_ = writer.Close()
+	return &rawBody{contentType: writer.FormDataContentType(), data: &buffer}
+}
+
+func decodeStrict(data []byte, value interface{}) error {
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	decoder.DisallowUnknownFields()
+	return decoder.Decode(value)
+}
+
+func toString(value interface{}) string {
+	if text, ok := value.(string); ok {
+		return text
+	}
+	data, _ := json.Marshal(value)
+	return strings.Trim(string(data), `"`)
+}
+
+type Pet struct {
+	ID        *int64     `json:"id,omitempty"`
+	Name      string     `json:"name"`
+	Category  *Category  `json:"category,omitempty"`
+	PhotoUrls []string   `json:"photoUrls"`
+	Tags      []Tag      `json:"tags,omitempty"`
+	Status    *PetStatus `json:"status,omitempty"`
+}
+
+type PetStatus string
+
+const (
+	PetStatusAvailable PetStatus = "available"
+	PetStatusPending   PetStatus = "pending"
+	PetStatusSold      PetStatus = "sold"
+)
+
+type Category struct {
+	ID   *int64  `json:"id,omitempty"`
+	Name *string `json:"name,omitempty"`
+}
+
+type Tag struct {
+	ID   *int64  `json:"id,omitempty"`
+	Name *string `json:"name,omitempty"`
+}
+
+type FindPetsByStatusStatus string
+
+const (
+	FindPetsByStatusStatusAvailable FindPetsByStatusStatus = "available"
+	FindPetsByStatusStatusPending   FindPetsByStatusStatus = "pending"
+	FindPetsByStatusStatusSold      FindPetsByStatusStatus = "sold"
+)
+
+type APIResponse struct {
+	Code    *int64  `json:"code,omitempty"`
+	Type    *string `json:"type,omitempty"`
+	Message *string `json:"message,omitempty"`
+}
+
+type Order struct {
+	ID       *int64       `json:"id,omitempty"`
+	PetID    *int64       `json:"petId,omitempty"`
+	Quantity *int64       `json:"quantity,omitempty"`
+	ShipDate *time.Time   `json:"shipDate,omitempty"`
+	Status   *OrderStatus `json:"status,omitempty"`
+	Complete *bool        `json:"complete,omitempty"`
+}
+
+type OrderStatus string
+
+const (
+	OrderStatusPlaced    OrderStatus = "placed"
+	OrderStatusApproved  OrderStatus = "approved"
+	OrderStatusDelivered OrderStatus = "delivered"
+)
+
+type User struct {
+	ID         *int64  `json:"id,omitempty"`
+	Username   *string `json:"username,omitempty"`
+	FirstName  *string `json:"firstName,omitempty"`
+	LastName   *string `json:"lastName,omitempty"`
+	Email      *string `json:"email,omitempty"`
+	Password   *string `json:"password,omitempty"`
+	Phone      *string `json:"phone,omitempty"`
+	UserStatus *int64  `json:"userStatus,omitempty"`
+}
+
+// Client is a client for the API.
+type Client struct {
+	httpClient  *http.Client
+	baseURL     string
+	credentials CredentialsProvider
+
+	Pet   *PetService
+	Store *StoreService
+	User  *UserService
+}
+
+// NewClient returns a client that sends requests to the API at the given base URL, using
+// http.DefaultClient when httpClient is nil.
+func NewClient(httpClient *http.Client, baseURL string) *Client {
+	if httpClient == nil {
+		httpClient = http.DefaultClient
+	}
+
+	c := &Client{httpClient: httpClient, baseURL: baseURL}
+	c.Pet = &PetService{client: c}
+	c.Store = &StoreService{client: c}
+	c.User = &UserService{client: c}
+
+	return c
+}
+
+// WithCredentials sets the provider of the credentials that requests are authorized with.
+func (c *Client) WithCredentials(provider CredentialsProvider) *Client {
+	c.credentials = provider
+
+	return c
+}
+
+// applyCredential adds the credential for the security scheme to the query or header of a request.
+func applyCredential(scheme string, credential string, query url.Values, header http.Header) {
+	switch scheme {
+	case "petstore_auth":
+		header.Set("Authorization", "Bearer "+credential)
+	case "api_key":
+		header.Set("api_key", credential)
+	}
+}
+
+// Server is a server that hosts the API, whose URL may hold variables written as {name}.
+type Server struct {
+	URL string
+	// Variables are the default values of the variables.
+	Variables map[string]string
+}
+
+// URLWith returns the URL of the server, with its variables replaced by the given values or
+// otherwise their defaults.
+func (s Server) URLWith(variables map[string]string) string {
+	result := s.URL
+	for name, value := range s.Variables {
+		if given, ok := variables[name]; ok {
+			value = given
+		}
+		result = strings.ReplaceAll(result, "{"+name+"}", value)
+	}
+
+	return result
+}
+
+// Servers are the servers that host the API.
+var Servers = []Server{
+	{URL: "/v3"},
+}
+
+type PetService struct {
+	client *Client
+}
+
+// AddPet Add a new pet to the store. Add a new pet to the store
+func (s *PetService) AddPet(ctx context.Context, payload Pet) (Pet, error) {
+	var result Pet
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, "/pet", nil, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, payload, &result)
+
+	return result, err
+}
+
+// UpdatePet Update an existing pet. Update an existing pet by Id
+func (s *PetService) UpdatePet(ctx context.Context, payload Pet) (Pet, error) {
+	var result Pet
+	err := s.client.do(ctx, http.MethodPut, s.client.baseURL, "/pet", nil, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, payload, &result)
+
+	return result, err
+}
+
+// FindPetsByStatus Finds Pets by status. Multiple status values can be provided with comma separated strings
+func (s *PetService) FindPetsByStatus(ctx context.Context, status *FindPetsByStatusStatus) ([]Pet, error) {
+	query := url.Values{}
+	if status != nil {
+		query.Add("status", toString(*status))
+	}
+	var result []Pet
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/pet/findByStatus", query, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, nil, &result)
+
+	return result, err
+}
+
+// FindPetsByTags Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing.
+func (s *PetService) FindPetsByTags(ctx context.Context, tags []string) ([]Pet, error) {
+	query := url.Values{}
+	for _, item := range tags {
+		query.Add("tags", toString(item))
+	}
+	var result []Pet
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/pet/findByTags", query, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, nil, &result)
+
+	return result, err
+}
+
+// GetPetByID Find pet by ID. Returns a single pet
+func (s *PetService) GetPetByID(ctx context.Context, petID string) (Pet, error) {
+	var result Pet
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, fmt.Sprintf("/pet/%s", url.PathEscape(toString(petID))), nil, nil, [][]securityScheme{{{"api_key", nil}}, {{"petstore_auth", []string{"write:pets", "read:pets"}}}}, nil, &result)
+
+	return result, err
+}
+
+// UpdatePetWithForm Updates a pet in the store with form data
+func (s *PetService) UpdatePetWithForm(ctx context.Context, petID string, name *string, status *string) error {
+	query := url.Values{}
+	if name != nil {
+		query.Add("name", toString(*name))
+	}
+	if status != nil {
+		query.Add("status", toString(*status))
+	}
+	return s.client.do(ctx, http.MethodPost, s.client.baseURL, fmt.Sprintf("/pet/%s", url.PathEscape(toString(petID))), query, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, nil, nil)
+}
+
+// DeletePet Deletes a pet
+func (s *PetService) DeletePet(ctx context.Context, petID string, apiKey *string) error {
+	header := http.Header{}
+	if apiKey != nil {
+		header.Add("api_key", toString(*apiKey))
+	}
+	return s.client.do(ctx, http.MethodDelete, s.client.baseURL, fmt.Sprintf("/pet/%s", url.PathEscape(toString(petID))), nil, header, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, nil, nil)
+}
+
+// UploadFile uploads an image
+func (s *PetService) UploadFile(ctx context.Context, petID string, payload []byte, additionalMetadata *string) (APIResponse, error) {
+	query := url.Values{}
+	if additionalMetadata != nil {
+		query.Add("additionalMetadata", toString(*additionalMetadata))
+	}
+	var result APIResponse
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, fmt.Sprintf("/pet/%s/uploadImage", url.PathEscape(toString(petID))), query, nil, [][]securityScheme{{{"petstore_auth", []string{"write:pets", "read:pets"}}}}, &rawBody{contentType: "application/octet-stream", data: bytes.NewReader(payload)}, &result)
+
+	return result, err
+}
+
+type StoreService struct {
+	client *Client
+}
+
+// GetInventory Returns pet inventories by status. Returns a map of status codes to quantities
+func (s *StoreService) GetInventory(ctx context.Context) (map[string]int64, error) {
+	var result map[string]int64
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/store/inventory", nil, nil, [][]securityScheme{{{"api_key", nil}}}, nil, &result)
+
+	return result, err
+}
+
+// PlaceOrder Place an order for a pet. Place a new order in the store
+func (s *StoreService) PlaceOrder(ctx context.Context, payload Order) (Order, error) {
+	var result Order
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, "/store/order", nil, nil, nil, payload, &result)
+
+	return result, err
+}
+
+// GetOrderByID Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions
+func (s *StoreService) GetOrderByID(ctx context.Context, orderID string) (Order, error) {
+	var result Order
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, fmt.Sprintf("/store/order/%s", url.PathEscape(toString(orderID))), nil, nil, nil, nil, &result)
+
+	return result, err
+}
+
+// DeleteOrder Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors
+func (s *StoreService) DeleteOrder(ctx context.Context, orderID string) error {
+	return s.client.do(ctx, http.MethodDelete, s.client.baseURL, fmt.Sprintf("/store/order/%s", url.PathEscape(toString(orderID))), nil, nil, nil, nil, nil)
+}
+
+type UserService struct {
+	client *Client
+}
+
+// CreateUser Create user. This can only be done by the logged in user.
+func (s *UserService) CreateUser(ctx context.Context, payload User) (User, error) {
+	var result User
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, "/user", nil, nil, nil, payload, &result)
+
+	return result, err
+}
+
+// CreateUsersWithListInput Creates list of users with given input array. Creates list of users with given input array
+func (s *UserService) CreateUsersWithListInput(ctx context.Context, payload []User) (User, error) {
+	var result User
+	err := s.client.do(ctx, http.MethodPost, s.client.baseURL, "/user/createWithList", nil, nil, nil, payload, &result)
+
+	return result, err
+}
+
+// LoginUser Logs user into the system
+func (s *UserService) LoginUser(ctx context.Context, username *string, password *string) (string, error) {
+	query := url.Values{}
+	if username != nil {
+		query.Add("username", toString(*username))
+	}
+	if password != nil {
+		query.Add("password", toString(*password))
+	}
+	var result string
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, "/user/login", query, nil, nil, nil, &result)
+
+	return result, err
+}
+
+// LogoutUser Logs out current logged in user session
+func (s *UserService) LogoutUser(ctx context.Context) error {
+	return s.client.do(ctx, http.MethodGet, s.client.baseURL, "/user/logout", nil, nil, nil, nil, nil)
+}
+
+// GetUserByName Get user by user name
+func (s *UserService) GetUserByName(ctx context.Context, username string) (User, error) {
+	var result User
+	err := s.client.do(ctx, http.MethodGet, s.client.baseURL, fmt.Sprintf("/user/%s", url.PathEscape(toString(username))), nil, nil, nil, nil, &result)
+
+	return result, err
+}
+
+// UpdateUser Update user. This can only be done by the logged in user.
+func (s *UserService) UpdateUser(ctx context.Context, username string, payload User) error {
+	return s.client.do(ctx, http.MethodPut, s.client.baseURL, fmt.Sprintf("/user/%s", url.PathEscape(toString(username))), nil, nil, nil, payload, nil)
+}
+
+// DeleteUser Delete user. This can only be done by the logged in user.
+func (s *UserService) DeleteUser(ctx context.Context, username string) error {
+	return s.client.do(ctx, http.MethodDelete, s.client.baseURL, fmt.Sprintf("/user/%s", url.PathEscape(toString(username))), nil, nil, nil, nil, nil)
+}
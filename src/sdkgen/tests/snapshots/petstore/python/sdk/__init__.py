@@ -0,0 +1,783 @@
+from __future__ import annotations
+
+import base64
+import dataclasses
+import datetime
+import decimal
+import json
+import uuid
+from dataclasses import dataclass, field
+from typing import Annotated, Any, Callable, Dict, ForwardRef, List, Literal, Optional, Tuple, Union, get_args, get_origin
+from urllib.parse import quote
+
+import httpx
+
+
+@dataclass(frozen=True)
+class _Tagged:
+    discriminator: str
+    cases: Dict[str, str]
+
+
+def _resolve(tp: Any) -> Any:
+    if isinstance(tp, ForwardRef):
+        tp = tp.__forward_arg__
+    if isinstance(tp, str):
+        return _TYPES[tp]
+    return tp
+
+
+_STRING_DECODERS = {
+    datetime.datetime: lambda data: datetime.datetime.fromisoformat(data.replace("Z", "+00:00")),
+    datetime.date: datetime.date.fromisoformat,
+    datetime.time: lambda data: datetime.time.fromisoformat(data.replace("Z", "+00:00")),
+    uuid.UUID: uuid.UUID,
+    bytes: base64.b64decode,
+}
+
+
+def _decode(tp: Any, data: Any) -> Any:
+    tp = _resolve(tp)
+    origin = get_origin(tp)
+    args = get_args(tp)
+
+    if data is None:
+        return None
+    if origin is Annotated:
+        tagged = tp.__metadata__[0]
+        return _decode(tagged.cases[data[tagged.discriminator]], data)
+    if origin is Union:
+        for case in args:
+            try:
+                return _decode(case, data)
+            except (TypeError, ValueError, KeyError):
+                continue
+        raise ValueError(f"{data!r} does not match any case of {tp}.")
+    if origin is list:
+        return [_decode(args[0], item) for item in data]
+    if origin is tuple:
+        return tuple(_decode(item_tp, item) for item_tp, item in zip(args, data))
+    if origin is dict:
+        return {key: _decode(args[1], value) for key, value in data.items()}
+    if origin is Literal:
+        if data not in args:
+            raise ValueError(f"{data!r} is not one of {args}.")
+        return data
+    if dataclasses.is_dataclass(tp):
+        if not isinstance(data, dict):
+            raise TypeError(f"Expected an object for {tp.__name__}.")
+        values = {}
+        for tp_field in dataclasses.fields(tp):
+            key = tp_field.metadata.get("json", tp_field.name)
+            if key in data:
+                values[tp_field.name] = _decode(tp_field.type, data[key])
+        return tp(**values)
+    if tp in _STRING_DECODERS:
+        if not isinstance(data, str):
+            raise TypeError(f"Expected a string for {tp.__name__}.")
+        return _STRING_DECODERS[tp](data)
+    if tp is decimal.Decimal and isinstance(data, (int, float, str)) and not isinstance(data, bool):
+        return decimal.Decimal(str(data))
+    if tp is float and isinstance(data, int):
+        return float(data)
+    if isinstance(tp, type) and not isinstance(data, tp):
+        raise TypeError(f"Expected {tp.__name__}, got {type(data).__name__}.")
+    return data
+
+
+def _encode(value: Any) -> Any:
+    if dataclasses.is_dataclass(value):
+        return {
+            tp_field.metadata.get("json", tp_field.name): _encode(getattr(value, tp_field.name))
+            for tp_field in dataclasses.fields(value)
+            if getattr(value, tp_field.name) is not None or tp_field.metadata.get("nullable")
+        }
+    if isinstance(value, (list, tuple)):
+        return [_encode(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _encode(item) for key, item in value.items()}
+    if isinstance(value, (datetime.datetime, datetime.date, datetime.time)):
+        return value.isoformat()
+    if isinstance(value, decimal.Decimal):
+        return str(value)
+    if isinstance(value, uuid.UUID):
+        return str(value)
+    if isinstance(value, bytes):
+        return base64.b64encode(value).decode("ascii")
+    return value
+
+
+def _to_string(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    return json.dumps(_encode(value)).strip('"')
+
+
+@dataclass
+class Pet:
+    name: str
+    photo_urls: List[str] = field(metadata={"json": "photoUrls"})
+    id: Optional[int] = None
+    category: Optional[Category] = None
+    tags: Optional[List[Tag]] = None
+    status: Optional[PetStatus] = None
+
+
+PetStatus = Literal["available", "pending", "sold"]
+
+
+@dataclass
+class Category:
+    id: Optional[int] = None
+    name: Optional[str] = None
+
+
+@dataclass
+class Tag:
+    id: Optional[int] = None
+    name: Optional[str] = None
+
+
+FindPetsByStatusStatus = Literal["available", "pending", "sold"]
+
+
+@dataclass
+class ApiResponse:
+    code: Optional[int] = None
+    type: Optional[str] = None
+    message: Optional[str] = None
+
+
+@dataclass
+class Order:
+    id: Optional[int] = None
+    pet_id: Optional[int] = field(default=None, metadata={"json": "petId"})
+    quantity: Optional[int] = None
+    ship_date: Optional[datetime.datetime] = field(default=None, metadata={"json": "shipDate"})
+    status: Optional[OrderStatus] = None
+    complete: Optional[bool] = None
+
+
+OrderStatus = Literal["placed", "approved", "delivered"]
+
+
+@dataclass
+class User:
+    id: Optional[int] = None
+    username: Optional[str] = None
+    first_name: Optional[str] = field(default=None, metadata={"json": "firstName"})
+    last_name: Optional[str] = field(default=None, metadata={"json": "lastName"})
+    email: Optional[str] = None
+    password: Optional[str] = None
+    phone: Optional[str] = None
+    user_status: Optional[int] = field(default=None, metadata={"json": "userStatus"})
+
+
+_TYPES: Dict[str, Any] = {
+    "Optional[int]": Optional[int],
+    "str": str,
+    "Optional[Category]": Optional[Category],
+    "List[str]": List[str],
+    "Optional[List[Tag]]": Optional[List[Tag]],
+    "Optional[PetStatus]": Optional[PetStatus],
+    "Optional[str]": Optional[str],
+    "Optional[datetime.datetime]": Optional[datetime.datetime],
+    "Optional[OrderStatus]": Optional[OrderStatus],
+    "Optional[bool]": Optional[bool],
+}
+
+
+CredentialsProvider = Callable[[str, List[str]], Optional[str]]
+"""Supplies the credential for a security scheme of the API, or `None` if there is none.
+
+The credential is the API key for API key schemes, `username:password` for HTTP basic
+authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+"""
+
+
+def _authorize(
+    credentials: Optional[CredentialsProvider],
+    requirements: List[List[Tuple[str, List[str]]]],
+    headers: Dict[str, str],
+    params: List[Tuple[str, str]],
+) -> None:
+    for requirement in requirements:
+        values: List[str] = []
+        for scheme, scopes in requirement:
+            value = credentials(scheme, scopes) if credentials else None
+            if value is None:
+                break
+            values.append(value)
+        else:
+            for (scheme, _), value in zip(requirement, values):
+                _apply_credential(scheme, value, headers, params)
+            return
+
+
+def _apply_credential(
+    scheme: str, credential: str, headers: Dict[str, str], params: List[Tuple[str, str]]
+) -> None:
+    if scheme == "petstore_auth":
+        headers["Authorization"] = "Bearer " + credential
+    elif scheme == "api_key":
+        headers["api_key"] = credential
+
+
+class Server:
+    """A server that hosts the API, whose URL may hold variables written as `{name}`."""
+
+    def __init__(self, url: str, variables: Optional[Dict[str, str]] = None) -> None:
+        self.url = url
+        self.variables = variables or {}
+        """The default values of the variables."""
+
+    def url_with(self, variables: Optional[Dict[str, str]] = None) -> str:
+        """Returns the URL of the server, with its variables replaced by the given values or
+        otherwise their defaults.
+        """
+        url = self.url
+        for name, value in self.variables.items():
+            url = url.replace("{" + name + "}", (variables or {}).get(name, value))
+        return url
+
+
+SERVERS: List[Server] = [
+    Server("/v3"),
+]
+"""The servers that host the API, the first of which is preferred."""
+
+
+class PetResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    def add_pet(self, payload: Pet) -> Pet:
+        """Add a new pet to the store. Add a new pet to the store"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "POST",
+            f"/pet",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    def update_pet(self, payload: Pet) -> Pet:
+        """Update an existing pet. Update an existing pet by Id"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "PUT",
+            f"/pet",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    def find_pets_by_status(self, status: Optional[FindPetsByStatusStatus] = None) -> List[Pet]:
+        """Finds Pets by status. Multiple status values can be provided with comma separated strings"""
+        params: List[Tuple[str, str]] = []
+        if status is not None:
+            params.append(("status", _to_string(status)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/pet/findByStatus",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    def find_pets_by_tags(self, tags: Optional[List[str]] = None) -> List[Pet]:
+        """Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing."""
+        params: List[Tuple[str, str]] = []
+        if tags is not None:
+            for item in tags:
+                params.append(("tags", _to_string(item)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/pet/findByTags",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    def get_pet_by_id(self, pet_id: str) -> Pet:
+        """Find pet by ID. Returns a single pet"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("api_key", [])], [("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    def update_pet_with_form(self, pet_id: str, name: Optional[str] = None, status: Optional[str] = None) -> None:
+        """Updates a pet in the store with form data"""
+        params: List[Tuple[str, str]] = []
+        if name is not None:
+            params.append(("name", _to_string(name)))
+        if status is not None:
+            params.append(("status", _to_string(status)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "POST",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    def delete_pet(self, pet_id: str, api_key: Optional[str] = None) -> None:
+        """Deletes a pet"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        if api_key is not None:
+            headers["api_key"] = _to_string(api_key)
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "DELETE",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    def upload_file(self, pet_id: str, payload: bytes, additional_metadata: Optional[str] = None) -> ApiResponse:
+        """uploads an image"""
+        params: List[Tuple[str, str]] = []
+        if additional_metadata is not None:
+            params.append(("additionalMetadata", _to_string(additional_metadata)))
+        headers: Dict[str, str] = {}
+        headers["Content-Type"] = "application/octet-stream"
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = self._client.request(
+            "POST",
+            f"/pet/{quote(pet_id, safe='')}/uploadImage",
+            params=params,
+            headers=headers,
+            content=payload,
+        )
+        response.raise_for_status()
+
+        return _decode(ApiResponse, response.json())
+
+
+class AsyncPetResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    async def add_pet(self, payload: Pet) -> Pet:
+        """Add a new pet to the store. Add a new pet to the store"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "POST",
+            f"/pet",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    async def update_pet(self, payload: Pet) -> Pet:
+        """Update an existing pet. Update an existing pet by Id"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "PUT",
+            f"/pet",
+            params=params,
+            headers=headers,
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    async def find_pets_by_status(self, status: Optional[FindPetsByStatusStatus] = None) -> List[Pet]:
+        """Finds Pets by status. Multiple status values can be provided with comma separated strings"""
+        params: List[Tuple[str, str]] = []
+        if status is not None:
+            params.append(("status", _to_string(status)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/pet/findByStatus",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    async def find_pets_by_tags(self, tags: Optional[List[str]] = None) -> List[Pet]:
+        """Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing."""
+        params: List[Tuple[str, str]] = []
+        if tags is not None:
+            for item in tags:
+                params.append(("tags", _to_string(item)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/pet/findByTags",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(List[Pet], response.json())
+
+    async def get_pet_by_id(self, pet_id: str) -> Pet:
+        """Find pet by ID. Returns a single pet"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("api_key", [])], [("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(Pet, response.json())
+
+    async def update_pet_with_form(self, pet_id: str, name: Optional[str] = None, status: Optional[str] = None) -> None:
+        """Updates a pet in the store with form data"""
+        params: List[Tuple[str, str]] = []
+        if name is not None:
+            params.append(("name", _to_string(name)))
+        if status is not None:
+            params.append(("status", _to_string(status)))
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "POST",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    async def delete_pet(self, pet_id: str, api_key: Optional[str] = None) -> None:
+        """Deletes a pet"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        if api_key is not None:
+            headers["api_key"] = _to_string(api_key)
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "DELETE",
+            f"/pet/{quote(pet_id, safe='')}",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+    async def upload_file(self, pet_id: str, payload: bytes, additional_metadata: Optional[str] = None) -> ApiResponse:
+        """uploads an image"""
+        params: List[Tuple[str, str]] = []
+        if additional_metadata is not None:
+            params.append(("additionalMetadata", _to_string(additional_metadata)))
+        headers: Dict[str, str] = {}
+        headers["Content-Type"] = "application/octet-stream"
+        _authorize(self._credentials, [[("petstore_auth", ["write:pets", "read:pets"])]], headers, params)
+        response = await self._client.request(
+            "POST",
+            f"/pet/{quote(pet_id, safe='')}/uploadImage",
+            params=params,
+            headers=headers,
+            content=payload,
+        )
+        response.raise_for_status()
+
+        return _decode(ApiResponse, response.json())
+
+
+class StoreResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    def get_inventory(self) -> Dict[str, int]:
+        """Returns pet inventories by status. Returns a map of status codes to quantities"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("api_key", [])]], headers, params)
+        response = self._client.request(
+            "GET",
+            f"/store/inventory",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(Dict[str, int], response.json())
+
+    def place_order(self, payload: Order) -> Order:
+        """Place an order for a pet. Place a new order in the store"""
+        response = self._client.request(
+            "POST",
+            f"/store/order",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Order, response.json())
+
+    def get_order_by_id(self, order_id: str) -> Order:
+        """Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions"""
+        response = self._client.request(
+            "GET",
+            f"/store/order/{quote(order_id, safe='')}",
+        )
+        response.raise_for_status()
+
+        return _decode(Order, response.json())
+
+    def delete_order(self, order_id: str) -> None:
+        """Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors"""
+        response = self._client.request(
+            "DELETE",
+            f"/store/order/{quote(order_id, safe='')}",
+        )
+        response.raise_for_status()
+
+
+class AsyncStoreResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    async def get_inventory(self) -> Dict[str, int]:
+        """Returns pet inventories by status. Returns a map of status codes to quantities"""
+        params: List[Tuple[str, str]] = []
+        headers: Dict[str, str] = {}
+        _authorize(self._credentials, [[("api_key", [])]], headers, params)
+        response = await self._client.request(
+            "GET",
+            f"/store/inventory",
+            params=params,
+            headers=headers,
+        )
+        response.raise_for_status()
+
+        return _decode(Dict[str, int], response.json())
+
+    async def place_order(self, payload: Order) -> Order:
+        """Place an order for a pet. Place a new order in the store"""
+        response = await self._client.request(
+            "POST",
+            f"/store/order",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(Order, response.json())
+
+    async def get_order_by_id(self, order_id: str) -> Order:
+        """Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions"""
+        response = await self._client.request(
+            "GET",
+            f"/store/order/{quote(order_id, safe='')}",
+        )
+        response.raise_for_status()
+
+        return _decode(Order, response.json())
+
+    async def delete_order(self, order_id: str) -> None:
+        """Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors"""
+        response = await self._client.request(
+            "DELETE",
+            f"/store/order/{quote(order_id, safe='')}",
+        )
+        response.raise_for_status()
+
+
+class UserResource:
+    def __init__(self, client: httpx.Client, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    def create_user(self, payload: User) -> User:
+        """Create user. This can only be done by the logged in user."""
+        response = self._client.request(
+            "POST",
+            f"/user",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    def create_users_with_list_input(self, payload: List[User]) -> User:
+        """Creates list of users with given input array. Creates list of users with given input array"""
+        response = self._client.request(
+            "POST",
+            f"/user/createWithList",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    def login_user(self, username: Optional[str] = None, password: Optional[str] = None) -> str:
+        """Logs user into the system"""
+        params: List[Tuple[str, str]] = []
+        if username is not None:
+            params.append(("username", _to_string(username)))
+        if password is not None:
+            params.append(("password", _to_string(password)))
+        response = self._client.request(
+            "GET",
+            f"/user/login",
+            params=params,
+        )
+        response.raise_for_status()
+
+        return _decode(str, response.json())
+
+    def logout_user(self) -> None:
+        """Logs out current logged in user session"""
+        response = self._client.request(
+            "GET",
+            f"/user/logout",
+        )
+        response.raise_for_status()
+
+    def get_user_by_name(self, username: str) -> User:
+        """Get user by user name"""
+        response = self._client.request(
+            "GET",
+            f"/user/{quote(username, safe='')}",
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    def update_user(self, username: str, payload: User) -> None:
+        """Update user. This can only be done by the logged in user."""
+        response = self._client.request(
+            "PUT",
+            f"/user/{quote(username, safe='')}",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+    def delete_user(self, username: str) -> None:
+        """Delete user. This can only be done by the logged in user."""
+        response = self._client.request(
+            "DELETE",
+            f"/user/{quote(username, safe='')}",
+        )
+        response.raise_for_status()
+
+
+class AsyncUserResource:
+    def __init__(self, client: httpx.AsyncClient, credentials: Optional[CredentialsProvider] = None) -> None:
+        self._client = client
+        self._credentials = credentials
+
+    async def create_user(self, payload: User) -> User:
+        """Create user. This can only be done by the logged in user."""
+        response = await self._client.request(
+            "POST",
+            f"/user",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    async def create_users_with_list_input(self, payload: List[User]) -> User:
+        """Creates list of users with given input array. Creates list of users with given input array"""
+        response = await self._client.request(
+            "POST",
+            f"/user/createWithList",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    async def login_user(self, username: Optional[str] = None, password: Optional[str] = None) -> str:
+        """Logs user into the system"""
+        params: List[Tuple[str, str]] = []
+        if username is not None:
+            params.append(("username", _to_string(username)))
+        if password is not None:
+            params.append(("password", _to_string(password)))
+        response = await self._client.request(
+            "GET",
+            f"/user/login",
+            params=params,
+        )
+        response.raise_for_status()
+
+        return _decode(str, response.json())
+
+    async def logout_user(self) -> None:
+        """Logs out current logged in user session"""
+        response = await self._client.request(
+            "GET",
+            f"/user/logout",
+        )
+        response.raise_for_status()
+
+    async def get_user_by_name(self, username: str) -> User:
+        """Get user by user name"""
+        response = await self._client.request(
+            "GET",
+            f"/user/{quote(username, safe='')}",
+        )
+        response.raise_for_status()
+
+        return _decode(User, response.json())
+
+    async def update_user(self, username: str, payload: User) -> None:
+        """Update user. This can only be done by the logged in user."""
+        response = await self._client.request(
+            "PUT",
+            f"/user/{quote(username, safe='')}",
+            json=_encode(payload),
+        )
+        response.raise_for_status()
+
+    async def delete_user(self, username: str) -> None:
+        """Delete user. This can only be done by the logged in user."""
+        response = await self._client.request(
+            "DELETE",
+            f"/user/{quote(username, safe='')}",
+        )
+        response.raise_for_status()
@@ -0,0 +1,480 @@
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the [`Client`].
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    Request(reqwest::Error),
+    /// The API responded with an unsuccessful status code.
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// The response body could not be deserialized.
+    Deserialize(serde_json::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request failed: {}", err),
+            Error::Status { status, body } => write!(f, "unexpected status code {}: {}", status, body),
+            Error::Deserialize(err) => write!(f, "invalid response body: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Deserialize(err)
+    }
+}
+
+/// Supplies the credentials for the security schemes of the API.
+///
+/// The credential is the API key for API key schemes, `username:password` for HTTP basic
+/// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+pub trait CredentialsProvider: std::fmt::Debug + Send + Sync {
+    /// Returns the credential for the security scheme, if there is one.
+    fn credential(&self, scheme: &str, scopes: &[&str]) -> Option<String>;
+}
+
+/// A client for the API.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    credentials: Option<std::sync::Arc<dyn CredentialsProvider>>,
+}
+
+impl Client {
+    /// Returns a client that sends requests to the API at the given base URL.
+    pub fn new(http_client: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            http_client,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            credentials: None,
+        }
+    }
+
+    /// Authorizes requests with the credentials of the given provider.
+    pub fn with_credentials(mut self, provider: impl CredentialsProvider + 'static) -> Self {
+        self.credentials = Some(std::sync::Arc::new(provider));
+        self
+    }
+}
+
+impl Client {
+    /// Adds the credentials for the first of the security requirements that the provider can
+    /// satisfy to the request.
+    #[allow(dead_code)]
+    fn authorize(
+        &self,
+        mut request: reqwest::RequestBuilder,
+        requirements: &[&[(&str, &[&str])]],
+    ) -> reqwest::RequestBuilder {
+        let provider = match &self.credentials {
+            Some(provider) => provider,
+            None => return request,
+        };
+
+        for requirement in requirements {
+            let credentials = requirement
+                .iter()
+                .map(|(scheme, scopes)| provider.credential(scheme, scopes))
+                .collect::<Option<Vec<_>>>();
+
+            if let Some(credentials) = credentials {
+                for ((scheme, _), credential) in requirement.iter().zip(credentials) {
+                    request = apply_credential(request, scheme, credential);
+                }
+                break;
+            }
+        }
+
+        request
+    }
+
+    /// Sends the request, returning the body of a successful response.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(body)
+    }
+
+    /// Sends the request, returning the body of a successful response as it is.
+    #[allow(dead_code)]
+    async fn send_bytes(&self, request: reqwest::RequestBuilder) -> Result<Vec<u8>, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+#[allow(dead_code)]
+fn to_string<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+#[allow(dead_code)]
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Adds the credential for the security scheme to the request.
+#[allow(dead_code)]
+fn apply_credential(
+    request: reqwest::RequestBuilder,
+    scheme: &str,
+    credential: String,
+) -> reqwest::RequestBuilder {
+    match scheme {
+        "petstore_auth" => request.bearer_auth(credential),
+        "api_key" => request.header("api_key", credential),
+        _ => request,
+    }
+}
+
+/// A server that hosts the API, whose URL may hold variables written as `{name}`.
+#[derive(Debug, Clone, Copy)]
+pub struct Server {
+    pub url: &'static str,
+    /// The default values of the variables.
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+impl Server {
+    /// Returns the URL of the server, with its variables replaced by the given values or
+    /// otherwise their defaults.
+    pub fn url_with<S: AsRef<str>>(&self, variables: &[(S, S)]) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.to_owned(), |url, (name, default)| {
+                let value = variables
+                    .iter()
+                    .find(|(given, _)| given.as_ref() == *name)
+                    .map_or(*default, |(_, value)| value.as_ref());
+
+                url.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+
+/// The servers that host the API.
+pub const SERVERS: &[Server] = &[
+    Server { url: "/v3", variables: &[] },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pet {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+    #[serde(rename = "photoUrls")]
+    pub photo_urls: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PetStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PetStatus {
+    #[serde(rename = "available")]
+    Available,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "sold")]
+    Sold,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Category {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FindPetsByStatusStatus {
+    #[serde(rename = "available")]
+    Available,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "sold")]
+    Sold,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(rename = "petId", default, skip_serializing_if = "Option::is_none")]
+    pub pet_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+    #[serde(rename = "shipDate", default, skip_serializing_if = "Option::is_none")]
+    pub ship_date: Option<chrono::DateTime<chrono::FixedOffset>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderStatus {
+    #[serde(rename = "placed")]
+    Placed,
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "delivered")]
+    Delivered,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(rename = "firstName", default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(rename = "lastName", default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(rename = "userStatus", default, skip_serializing_if = "Option::is_none")]
+    pub user_status: Option<i64>,
+}
+
+impl Client {
+    /// Add a new pet to the store. Add a new pet to the store
+    pub async fn add_pet(&self, payload: Pet) -> Result<Pet, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/pet", self.base_url));
+        request = request.json(&payload);
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Update an existing pet. Update an existing pet by Id
+    pub async fn update_pet(&self, payload: Pet) -> Result<Pet, Error> {
+        let mut request = self.http_client.request(reqwest::Method::PUT, format!("{}/pet", self.base_url));
+        request = request.json(&payload);
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Finds Pets by status. Multiple status values can be provided with comma separated strings
+    pub async fn find_pets_by_status(&self, status: Option<FindPetsByStatusStatus>) -> Result<Vec<Pet>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/pet/findByStatus", self.base_url));
+        if let Some(status) = status.as_ref() {
+            request = request.query(&[("status", to_string(status))]);
+        }
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing.
+    pub async fn find_pets_by_tags(&self, tags: Option<Vec<String>>) -> Result<Vec<Pet>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/pet/findByTags", self.base_url));
+        for item in tags.iter().flatten() {
+            request = request.query(&[("tags", to_string(item))]);
+        }
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Find pet by ID. Returns a single pet
+    pub async fn get_pet_by_id(&self, pet_id: String) -> Result<Pet, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/pet/{}", self.base_url, encode_component(&to_string(&pet_id))));
+        request = self.authorize(request, &[&[("api_key", &[])], &[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Updates a pet in the store with form data
+    pub async fn update_pet_with_form(&self, pet_id: String, name: Option<String>, status: Option<String>) -> Result<(), Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/pet/{}", self.base_url, encode_component(&to_string(&pet_id))));
+        if let Some(name) = name.as_ref() {
+            request = request.query(&[("name", to_string(name))]);
+        }
+        if let Some(status) = status.as_ref() {
+            request = request.query(&[("status", to_string(status))]);
+        }
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Deletes a pet
+    pub async fn delete_pet(&self, pet_id: String, api_key: Option<String>) -> Result<(), Error> {
+        let mut request = self.http_client.request(reqwest::Method::DELETE, format!("{}/pet/{}", self.base_url, encode_component(&to_string(&pet_id))));
+        if let Some(api_key) = api_key.as_ref() {
+            request = request.header("api_key", to_string(api_key));
+        }
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// uploads an image
+    pub async fn upload_file(&self, pet_id: String, payload: Vec<u8>, additional_metadata: Option<String>) -> Result<ApiResponse, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/pet/{}/uploadImage", self.base_url, encode_component(&to_string(&pet_id))));
+        if let Some(additional_metadata) = additional_metadata.as_ref() {
+            request = request.query(&[("additionalMetadata", to_string(additional_metadata))]);
+        }
+        request = request.header("Content-Type", "application/octet-stream").body(payload);
+        request = self.authorize(request, &[&[("petstore_auth", &["write:pets", "read:pets"])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Returns pet inventories by status. Returns a map of status codes to quantities
+    pub async fn get_inventory(&self) -> Result<std::collections::HashMap<String, i64>, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/store/inventory", self.base_url));
+        request = self.authorize(request, &[&[("api_key", &[])]]);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Place an order for a pet. Place a new order in the store
+    pub async fn place_order(&self, payload: Order) -> Result<Order, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/store/order", self.base_url));
+        request = request.json(&payload);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions
+    pub async fn get_order_by_id(&self, order_id: String) -> Result<Order, Error> {
+        let request = self.http_client.request(reqwest::Method::GET, format!("{}/store/order/{}", self.base_url, encode_component(&to_string(&order_id))));
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors
+    pub async fn delete_order(&self, order_id: String) -> Result<(), Error> {
+        let request = self.http_client.request(reqwest::Method::DELETE, format!("{}/store/order/{}", self.base_url, encode_component(&to_string(&order_id))));
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Create user. This can only be done by the logged in user.
+    pub async fn create_user(&self, payload: User) -> Result<User, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/user", self.base_url));
+        request = request.json(&payload);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Creates list of users with given input array. Creates list of users with given input array
+    pub async fn create_users_with_list_input(&self, payload: Vec<User>) -> Result<User, Error> {
+        let mut request = self.http_client.request(reqwest::Method::POST, format!("{}/user/createWithList", self.base_url));
+        request = request.json(&payload);
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Logs user into the system
+    pub async fn login_user(&self, username: Option<String>, password: Option<String>) -> Result<String, Error> {
+        let mut request = self.http_client.request(reqwest::Method::GET, format!("{}/user/login", self.base_url));
+        if let Some(username) = username.as_ref() {
+            request = request.query(&[("username", to_string(username))]);
+        }
+        if let Some(password) = password.as_ref() {
+            request = request.query(&[("password", to_string(password))]);
+        }
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Logs out current logged in user session
+    pub async fn logout_user(&self) -> Result<(), Error> {
+        let request = self.http_client.request(reqwest::Method::GET, format!("{}/user/logout", self.base_url));
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Get user by user name
+    pub async fn get_user_by_name(&self, username: String) -> Result<User, Error> {
+        let request = self.http_client.request(reqwest::Method::GET, format!("{}/user/{}", self.base_url, encode_component(&to_string(&username))));
+
+        Ok(serde_json::from_str(&self.send(request).await?)?)
+    }
+
+    /// Update user. This can only be done by the logged in user.
+    pub async fn update_user(&self, username: String, payload: User) -> Result<(), Error> {
+        let mut request = self.http_client.request(reqwest::Method::PUT, format!("{}/user/{}", self.base_url, encode_component(&to_string(&username))));
+        request = request.json(&payload);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Delete user. This can only be done by the logged in user.
+    pub async fn delete_user(&self, username: String) -> Result<(), Error> {
+        let request = self.http_client.request(reqwest::Method::DELETE, format!("{}/user/{}", self.base_url, encode_component(&to_string(&username))));
+
+        self.send(request).await.map(|_| ())
+    }
+}
@@ -0,0 +1,16 @@
+export * from './models/Pet';
+export * from './models/PetStatus';
+export * from './models/Category';
+export * from './models/Tag';
+export * from './models/FindPetsByStatusStatus';
+export * from './models/ApiResponse';
+export * from './models/Order';
+export * from './models/OrderStatus';
+export * from './models/User';
+export * from './resources/pet';
+export * from './resources/store';
+export * from './resources/user';
+export * from './client';
+export * from './servers';
+export * from './errors';
+export * from './auth';
@@ -0,0 +1,6 @@
+
+export interface ApiResponse {
+    code?: number;
+    type?: string;
+    message?: string;
+}
@@ -0,0 +1,12 @@
+
+export interface Category {
+    id?: bigint;
+    name?: string;
+}
+
+export function decodeCategory(value: any): Category {
+    return {
+        ...value,
+        id: value.id == null ? value.id : BigInt(value.id),
+    };
+}
@@ -0,0 +1,8 @@
+
+export type FindPetsByStatusStatus = 'available' | 'pending' | 'sold';
+
+export const FindPetsByStatusStatus = {
+    Available: 'available',
+    Pending: 'pending',
+    Sold: 'sold',
+} as const;
@@ -0,0 +1,18 @@
+import { OrderStatus } from './OrderStatus';
+
+export interface Order {
+    id?: bigint;
+    petId?: bigint;
+    quantity?: number;
+    shipDate?: string;
+    status?: OrderStatus;
+    complete?: boolean;
+}
+
+export function decodeOrder(value: any): Order {
+    return {
+        ...value,
+        id: value.id == null ? value.id : BigInt(value.id),
+        petId: value.petId == null ? value.petId : BigInt(value.petId),
+    };
+}
@@ -0,0 +1,8 @@
+
+export type OrderStatus = 'placed' | 'approved' | 'delivered';
+
+export const OrderStatus = {
+    Placed: 'placed',
+    Approved: 'approved',
+    Delivered: 'delivered',
+} as const;
@@ -0,0 +1,21 @@
+import { Category, decodeCategory } from './Category';
+import { Tag, decodeTag } from './Tag';
+import { PetStatus } from './PetStatus';
+
+export interface Pet {
+    id?: bigint;
+    name: string;
+    category?: Category;
+    photoUrls: string[];
+    tags?: Tag[];
+    status?: PetStatus;
+}
+
+export function decodePet(value: any): Pet {
+    return {
+        ...value,
+        id: value.id == null ? value.id : BigInt(value.id),
+        category: value.category == null ? value.category : decodeCategory(value.category),
+        tags: value.tags == null ? value.tags : value.tags.map((item: any) => decodeTag(item)),
+    };
+}
@@ -0,0 +1,8 @@
+
+export type PetStatus = 'available' | 'pending' | 'sold';
+
+export const PetStatus = {
+    Available: 'available',
+    Pending: 'pending',
+    Sold: 'sold',
+} as const;
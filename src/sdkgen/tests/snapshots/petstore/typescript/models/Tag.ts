@@ -0,0 +1,12 @@
+
+export interface Tag {
+    id?: bigint;
+    name?: string;
+}
+
+export function decodeTag(value: any): Tag {
+    return {
+        ...value,
+        id: value.id == null ? value.id : BigInt(value.id),
+    };
+}
@@ -0,0 +1,18 @@
+
+export interface User {
+    id?: bigint;
+    username?: string;
+    firstName?: string;
+    lastName?: string;
+    email?: string;
+    password?: string;
+    phone?: string;
+    userStatus?: number;
+}
+
+export function decodeUser(value: any): User {
+    return {
+        ...value,
+        id: value.id == null ? value.id : BigInt(value.id),
+    };
+}
@@ -0,0 +1,244 @@
+import type { ApiClient } from '../client';
+import { authorize } from '../auth';
+import { ApiError } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { Pet, decodePet } from '../models/Pet';
+import { FindPetsByStatusStatus } from '../models/FindPetsByStatusStatus';
+import { ApiResponse } from '../models/ApiResponse';
+
+/**
+ * The routes of the `pet` resource.
+ */
+export class PetClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * Add a new pet to the store. Add a new pet to the store
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async addPet(payload: Pet): Promise<Pet> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'post',
+            url: `/pet`,
+            params,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodePet(data);
+    }
+
+    /**
+     * Update an existing pet. Update an existing pet by Id
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async updatePet(payload: Pet): Promise<Pet> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'put',
+            url: `/pet`,
+            params,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodePet(data);
+    }
+
+    /**
+     * Finds Pets by status. Multiple status values can be provided with comma separated strings
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async findPetsByStatus(status?: FindPetsByStatusStatus): Promise<Pet[]> {
+        const params = new URLSearchParams();
+        if (status != null) {
+            params.append('status', String(status));
+        }
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/pet/findByStatus`,
+            params,
+            headers,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return data.map((item: any) => decodePet(item));
+    }
+
+    /**
+     * Finds Pets by tags. Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing.
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async findPetsByTags(tags?: string[]): Promise<Pet[]> {
+        const params = new URLSearchParams();
+        tags?.forEach(item => { params.append('tags', String(item)); });
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/pet/findByTags`,
+            params,
+            headers,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return data.map((item: any) => decodePet(item));
+    }
+
+    /**
+     * Find pet by ID. Returns a single pet
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async getPetById(petId: string): Promise<Pet> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['api_key', []]], [['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/pet/${encodeURIComponent(petId)}`,
+            params,
+            headers,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodePet(data);
+    }
+
+    /**
+     * Updates a pet in the store with form data
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async updatePetWithForm(petId: string, name?: string, status?: string): Promise<void> {
+        const params = new URLSearchParams();
+        if (name != null) {
+            params.append('name', String(name));
+        }
+        if (status != null) {
+            params.append('status', String(status));
+        }
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'post',
+            url: `/pet/${encodeURIComponent(petId)}`,
+            params,
+            headers,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+
+    /**
+     * Deletes a pet
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async deletePet(petId: string, apiKey?: string): Promise<void> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        if (apiKey != null) {
+            headers['api_key'] = String(apiKey);
+        }
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'delete',
+            url: `/pet/${encodeURIComponent(petId)}`,
+            params,
+            headers,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+
+    /**
+     * uploads an image
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async uploadFile(petId: string, payload: Blob, additionalMetadata?: string): Promise<ApiResponse> {
+        const params = new URLSearchParams();
+        if (additionalMetadata != null) {
+            params.append('additionalMetadata', String(additionalMetadata));
+        }
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/octet-stream';
+        await authorize(this.client.options.credentialsProvider, [[['petstore_auth', ['write:pets', 'read:pets']]]], headers, params);
+        const response = await this.client.request({
+            method: 'post',
+            url: `/pet/${encodeURIComponent(petId)}/uploadImage`,
+            params,
+            headers,
+            data: payload,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+
+        return response.data;
+    }
+}
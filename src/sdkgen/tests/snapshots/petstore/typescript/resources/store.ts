@@ -0,0 +1,103 @@
+import type { ApiClient } from '../client';
+import { authorize } from '../auth';
+import { ApiError } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { Order, decodeOrder } from '../models/Order';
+
+/**
+ * The routes of the `store` resource.
+ */
+export class StoreClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * Returns pet inventories by status. Returns a map of status codes to quantities
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async getInventory(): Promise<Record<string, number>> {
+        const params = new URLSearchParams();
+        const headers: Record<string, string> = {};
+        await authorize(this.client.options.credentialsProvider, [[['api_key', []]]], headers, params);
+        const response = await this.client.request({
+            method: 'get',
+            url: `/store/inventory`,
+            params,
+            headers,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+
+        return response.data;
+    }
+
+    /**
+     * Place an order for a pet. Place a new order in the store
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async placeOrder(payload: Order): Promise<Order> {
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        const response = await this.client.request({
+            method: 'post',
+            url: `/store/order`,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeOrder(data);
+    }
+
+    /**
+     * Find purchase order by ID. For valid response try integer IDs with value <= 5 or > 10. Other values will generated exceptions
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async getOrderById(orderId: string): Promise<Order> {
+        const response = await this.client.request({
+            method: 'get',
+            url: `/store/order/${encodeURIComponent(orderId)}`,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeOrder(data);
+    }
+
+    /**
+     * Delete purchase order by ID. For valid response try integer IDs with value < 1000. Anything above 1000 or nonintegers will generate API errors
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async deleteOrder(orderId: string): Promise<void> {
+        const response = await this.client.request({
+            method: 'delete',
+            url: `/store/order/${encodeURIComponent(orderId)}`,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+}
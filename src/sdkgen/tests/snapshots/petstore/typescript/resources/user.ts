@@ -0,0 +1,170 @@
+import type { ApiClient } from '../client';
+import { ApiError } from '../errors';
+import { parseJson, stringifyJson } from '../runtime';
+import { User, decodeUser } from '../models/User';
+
+/**
+ * The routes of the `user` resource.
+ */
+export class UserClient {
+    constructor(private readonly client: ApiClient) {}
+
+    /**
+     * Create user. This can only be done by the logged in user.
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async createUser(payload: User): Promise<User> {
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        const response = await this.client.request({
+            method: 'post',
+            url: `/user`,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeUser(data);
+    }
+
+    /**
+     * Creates list of users with given input array. Creates list of users with given input array
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async createUsersWithListInput(payload: User[]): Promise<User> {
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        const response = await this.client.request({
+            method: 'post',
+            url: `/user/createWithList`,
+            headers,
+            data: stringifyJson(payload),
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeUser(data);
+    }
+
+    /**
+     * Logs user into the system
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async loginUser(username?: string, password?: string): Promise<string> {
+        const params = new URLSearchParams();
+        if (username != null) {
+            params.append('username', String(username));
+        }
+        if (password != null) {
+            params.append('password', String(password));
+        }
+        const response = await this.client.request({
+            method: 'get',
+            url: `/user/login`,
+            params,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+
+        return response.data;
+    }
+
+    /**
+     * Logs out current logged in user session
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async logoutUser(): Promise<void> {
+        const response = await this.client.request({
+            method: 'get',
+            url: `/user/logout`,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+
+    /**
+     * Get user by user name
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async getUserByName(username: string): Promise<User> {
+        const response = await this.client.request({
+            method: 'get',
+            url: `/user/${encodeURIComponent(username)}`,
+            responseType: 'text',
+            transformResponse: (data: string) => data,
+            validateStatus: () => true,
+        });
+
+        const data = parseJson(response.data);
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: data });
+        }
+
+        return decodeUser(data);
+    }
+
+    /**
+     * Update user. This can only be done by the logged in user.
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async updateUser(username: string, payload: User): Promise<void> {
+        const headers: Record<string, string> = {};
+        headers['Content-Type'] = 'application/json';
+        const response = await this.client.request({
+            method: 'put',
+            url: `/user/${encodeURIComponent(username)}`,
+            headers,
+            data: stringifyJson(payload),
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+
+    /**
+     * Delete user. This can only be done by the logged in user.
+     *
+     * @throws {ApiError} If the API responds with an unsuccessful status code.
+     */
+    async deleteUser(username: string): Promise<void> {
+        const response = await this.client.request({
+            method: 'delete',
+            url: `/user/${encodeURIComponent(username)}`,
+            validateStatus: () => true,
+        });
+
+        if (response.status < 200 || response.status >= 300) {
+            throw new ApiError({ status: response.status, body: response.data });
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/**
+ * A server that hosts the API, whose URL may hold variables written as `{name}`.
+ */
+export interface Server {
+    url: string;
+    /**
+     * The default values of the variables.
+     */
+    variables: Record<string, string>;
+}
+
+/**
+ * The servers that host the API.
+ */
+export const servers: Server[] = [
+    { url: '/v3', variables: {} },
+];
+
+/**
+ * Returns the URL of the server, with its variables replaced by the given values or otherwise
+ * their defaults.
+ */
+export function serverUrl(server: Server, variables: Record<string, string> = {}): string {
+    return server.url.replace(
+        /{([^}]+)}/g,
+        (match: string, name: string) => variables[name] ?? server.variables[name] ?? match,
+    );
+}
@@ -0,0 +1,113 @@
+use indexmap::IndexMap;
+use openapiv3::{Components, OpenAPI as OpenApi, Parameter, ReferenceOr, Response, SecurityScheme};
+use sdkgen_core::JsonPointer;
+
+/// Returns the name of the component that a reference such as `#/components/schemas/Pet` refers
+/// to within the given section of the components.
+fn component_name(reference: &str, section: &str, kind: &str) -> Result<String, String> {
+    let pointer = JsonPointer::parse(reference);
+
+    match pointer.segments() {
+        [components, components_section, name]
+            if reference.starts_with("#/")
+                && components == "components"
+                && components_section == section =>
+        {
+            Ok(name.clone())
+        }
+        _ => Err(format!("Not a {} reference: '{}'.", kind, reference)),
+    }
+}
+
+/// Resolves a reference to a component, following any references between components, and returns
+/// it along with its name.
+pub(crate) fn resolve_component<T: Clone>(
+    api: &OpenApi,
+    section: &str,
+    kind: &str,
+    components: fn(&Components) -> &IndexMap<String, ReferenceOr<T>>,
+    reference: &str,
+) -> Result<(String, T), String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut reference = reference.to_owned();
+
+    loop {
+        let name = component_name(&reference, section, kind)?;
+
+        if names.contains(&name) {
+            names.push(name);
+
+            return Err(format!(
+                "The {} reference '{}' refers to itself: {}.",
+                kind,
+                reference,
+                names.join(" -> ")
+            ));
+        }
+
+        names.push(name.clone());
+
+        match api
+            .components
+            .as_ref()
+            .map(components)
+            .and_then(|section| section.get(&name))
+        {
+            Some(ReferenceOr::Item(component)) => return Ok((name, component.clone())),
+            Some(ReferenceOr::Reference { reference: next }) => reference = next.clone(),
+            None => {
+                return Err(format!(
+                    "Could not resolve {} reference: '{}'.",
+                    kind, reference
+                ))
+            }
+        }
+    }
+}
+
+pub(crate) fn resolve_parameter(
+    api: &OpenApi,
+    parameter: ReferenceOr<Parameter>,
+) -> Result<Parameter, String> {
+    match parameter {
+        ReferenceOr::Item(parameter) => Ok(parameter),
+        ReferenceOr::Reference { reference } => resolve_component(
+            api,
+            "parameters",
+            "parameter",
+            |components| &components.parameters,
+            &reference,
+        )
+        .map(|(_, parameter)| parameter),
+    }
+}
+
+pub(crate) fn resolve_response(
+    api: &OpenApi,
+    response: ReferenceOr<Response>,
+) -> Result<Response, String> {
+    match response {
+        ReferenceOr::Item(response) => Ok(response),
+        ReferenceOr::Reference { reference } => resolve_component(
+            api,
+            "responses",
+            "response",
+            |components| &components.responses,
+            &reference,
+        )
+        .map(|(_, response)| response),
+    }
+}
+
+/// Resolves the security scheme with the given name, which security requirements refer to without
+/// a reference.
+pub(crate) fn resolve_security_scheme(api: &OpenApi, name: &str) -> Result<SecurityScheme, String> {
+    resolve_component(
+        api,
+        "securitySchemes",
+        "security scheme",
+        |components| &components.security_schemes,
+        &format!("#/components/securitySchemes/{}", name),
+    )
+    .map(|(_, scheme)| scheme)
+}
@@ -1,74 +1,365 @@
+mod component;
+mod openapi31;
+mod request_body;
+mod resolver;
 mod schema;
 
 use std::convert::TryFrom;
+use std::path::Path;
 
+use indexmap::IndexMap;
 use openapiv3::{
-    ArrayType, ObjectType, OpenAPI as OpenApi, Operation, Parameter, ParameterData, PathItem,
-    ReferenceOr, Response, Schema, SchemaKind, StatusCode, Type as OpenApiType,
+    APIKeyLocation, AdditionalProperties, ArrayType, Content, Discriminator, IntegerFormat,
+    IntegerType, NumberFormat, NumberType, OAuth2Flow as OpenApiOAuth2Flow, ObjectType,
+    OpenAPI as OpenApi, Operation, Parameter as OpenApiParameter, ParameterData,
+    ParameterSchemaOrContent, PathItem, ReferenceOr, Schema, SchemaKind,
+    SecurityRequirement as OpenApiSecurityRequirement, SecurityScheme as OpenApiSecurityScheme,
+    Server as OpenApiServer, StatusCode as OpenApiStatusCode, StringFormat, StringType,
+    Type as OpenApiType, VariantOrUnknownOrEmpty,
 };
-use sdkgen_core::{HttpMethod, Member, NonEmptyString, Primitive, Route, Type, UrlParameter};
+use sdkgen_core::{
+    Api, ApiKeyLocation, Diagnostic, Diagnostics, EnumValue, EnumVariant, HttpMethod, JsonPointer,
+    MediaType, Member, NonEmptyString, OAuth2Flow, OAuth2FlowKind, Parameter, PathFilter,
+    Primitive, Route, SecurityRequirement, SecurityScheme, SecuritySchemeKind, Server,
+    ServerVariable, Span, StatusCode, Type, TypeDeclarations, UnionCase, UrlParameter,
+};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
 
+use crate::component::{resolve_parameter, resolve_response, resolve_security_scheme};
+use crate::openapi31::{downgrade, is_openapi31, tuple_items};
+use crate::request_body::resolve_request_body;
+use crate::resolver::bundle;
 use crate::schema::resolve_schema;
 
-pub fn from_json(openapi_json: &str) -> serde_json::Result<Vec<Route>> {
-    let openapi: OpenApi = serde_json::from_str(openapi_json)?;
+/// The field that distinguishes OpenAPI 3.1 documents from OpenAPI 3.0 ones.
+#[derive(Deserialize)]
+struct VersionFields {
+    openapi: Option<String>,
+}
+
+/// Converts the operations of the API definition read from `path` to routes and the types they
+/// refer to, returned along with any warnings.
+///
+/// References to other documents are resolved relative to `path`.
+pub fn from_json(
+    openapi_json: &str,
+    path: &Path,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let version: VersionFields = parse_json(openapi_json)?;
+
+    // OpenAPI 3.0 documents are parsed as such first, so that problems are reported where they are
+    // found in the source.
+    let document = if version.openapi.as_deref().is_some_and(is_openapi31) {
+        parse_json(openapi_json)?
+    } else {
+        to_document(parse_json::<OpenApi>(openapi_json)?)
+    };
+
+    from_document(document, path, filter)
+}
+
+/// Converts the operations of the API definition read from `path` to routes and the types they
+/// refer to, returned along with any warnings.
+///
+/// References to other documents are resolved relative to `path`.
+pub fn from_yaml(
+    openapi_yaml: &str,
+    path: &Path,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let version: VersionFields = parse_yaml(openapi_yaml)?;
+
+    let document = if version.openapi.as_deref().is_some_and(is_openapi31) {
+        parse_yaml(openapi_yaml)?
+    } else {
+        to_document(parse_yaml::<OpenApi>(openapi_yaml)?)
+    };
+
+    from_document(document, path, filter)
+}
 
-    Ok(from_openapi(openapi))
+/// Converts the API definition into a JSON document.
+///
+/// serde_json is built with `preserve_order`, so that members, paths and responses keep the order
+/// of the source.
+fn to_document(openapi: OpenApi) -> Value {
+    serde_json::to_value(openapi).expect("API definitions are always valid JSON")
 }
 
-pub fn from_yaml(openapi_yaml: &str) -> serde_yaml::Result<Vec<Route>> {
-    let openapi: OpenApi = serde_yaml::from_str(openapi_yaml)?;
+fn parse_json<T: DeserializeOwned>(source: &str) -> Result<T, Diagnostic> {
+    serde_json::from_str(source).map_err(|err| {
+        let offset = offset_at(source, err.line(), err.column());
 
-    Ok(from_openapi(openapi))
+        Diagnostic::error(format!("Failed to parse API definition: {}", err)).with_span(Span {
+            start: offset,
+            end: offset,
+        })
+    })
 }
 
-fn from_openapi(openapi: OpenApi) -> Vec<Route> {
+fn parse_yaml<T: DeserializeOwned>(source: &str) -> Result<T, Diagnostic> {
+    serde_yaml::from_str(source).map_err(|err| {
+        let diagnostic = Diagnostic::error(format!("Failed to parse API definition: {}", err));
+
+        match err.location() {
+            Some(location) => diagnostic.with_span(Span {
+                start: location.index(),
+                end: location.index(),
+            }),
+            None => diagnostic,
+        }
+    })
+}
+
+/// Returns the byte offset of the given one-based line and column within the source.
+fn offset_at(source: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+
+    (line_start + column.saturating_sub(1)).min(source.len())
+}
+
+/// Converts the operations of an API definition to routes, after resolving its references to
+/// other documents and rewriting it as an OpenAPI 3.0 one if it is an OpenAPI 3.1 one.
+fn from_document(
+    document: Value,
+    path: &Path,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let is_openapi31 = document
+        .get("openapi")
+        .and_then(Value::as_str)
+        .is_some_and(is_openapi31);
+
+    let mut document = bundle(document, path)?;
+    let mut diagnostics = Diagnostics::new();
+
+    if is_openapi31 {
+        document = downgrade(document, &mut diagnostics);
+    }
+
+    if diagnostics.has_errors() {
+        return Err(diagnostics);
+    }
+
+    let openapi: OpenApi = match serde_json::from_value(document) {
+        Ok(openapi) => openapi,
+        Err(err) => {
+            diagnostics.push(Diagnostic::error(format!(
+                "Failed to parse API definition: {}",
+                err
+            )));
+
+            return Err(diagnostics);
+        }
+    };
+
+    match from_openapi(openapi, filter) {
+        Ok((api, warnings)) => {
+            diagnostics.extend(warnings);
+
+            diagnostics.into_result_with_warnings(api)
+        }
+        Err(errors) => {
+            diagnostics.extend(errors);
+
+            Err(diagnostics)
+        }
+    }
+}
+
+/// Converts the operations of an API definition to routes, skipping the paths that do not match
+/// the filter. The routes and the types they refer to are returned along with any warnings.
+pub fn from_openapi(
+    openapi: OpenApi,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let mut diagnostics = Diagnostics::new();
     let mut routes = Vec::new();
 
     for (path, reference_or_path_item) in openapi.paths.iter() {
+        if !filter.is_match(path) {
+            continue;
+        }
+
+        let pointer = JsonPointer::root().join("paths").join(path.clone());
+
         match reference_or_path_item {
-            ReferenceOr::Reference { .. } => {
-                println!("Unhandled reference at path: '{}'", &path);
-                continue;
+            ReferenceOr::Reference { reference } => {
+                diagnostics.push(
+                    Diagnostic::error(format!(
+                        "Unsupported reference to path item: '{}'.",
+                        reference
+                    ))
+                    .with_pointer(pointer),
+                );
             }
             ReferenceOr::Item(path_item) => {
-                let routes_for_path = path_to_routes(&openapi, path.clone(), path_item.clone());
+                let routes_for_path = path_to_routes(
+                    &openapi,
+                    &mut diagnostics,
+                    &pointer,
+                    path.clone(),
+                    path_item.clone(),
+                );
 
                 routes.extend(routes_for_path);
             }
         }
     }
 
-    routes
+    let types = declare_types(&openapi, &routes);
+
+    diagnostics.into_result_with_warnings(Api { routes, types })
+}
+
+/// Declares the types of the routes, along with the named types that they refer to.
+fn declare_types(openapi: &OpenApi, routes: &[Route]) -> TypeDeclarations {
+    let mut types = TypeDeclarations::new();
+
+    for route in routes {
+        for parameter in route.all_parameters() {
+            declare_type(openapi, &mut types, parameter.ty);
+        }
+
+        for ty in route.responses.values().flatten() {
+            declare_type(openapi, &mut types, ty.to_owned());
+        }
+    }
+
+    types
+}
+
+/// Declares the type, followed by the named types that it refers to that are not declared yet.
+fn declare_type(openapi: &OpenApi, types: &mut TypeDeclarations, ty: Type) {
+    let mut referenced_types = ty.referenced_types();
+    referenced_types.insert(0, ty.clone());
+
+    types.register(ty);
+
+    for referenced_type in referenced_types {
+        if let Type::Reference(name) = referenced_type {
+            if types.get(&name).is_none() {
+                declare_type(openapi, types, named_schema_to_type(openapi, &name));
+            }
+        }
+    }
+}
+
+/// Converts the schema with the given name in the components of the API definition to the type it
+/// declares.
+///
+/// Any problems with the schema were reported where it was referred to, so they are not reported
+/// again.
+fn named_schema_to_type(openapi: &OpenApi, name: &str) -> Type {
+    let pointer = JsonPointer::root()
+        .join("components")
+        .join("schemas")
+        .join(name);
+
+    let schema = openapi
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.get(name));
+
+    match schema {
+        Some(ReferenceOr::Item(schema)) => schema_to_type(
+            openapi,
+            &mut Diagnostics::new(),
+            &mut vec![name.to_owned()],
+            &pointer,
+            NamedOrAnonymous::Named(name.to_owned(), schema.clone()),
+        ),
+        // References only ever name the schemas that other schemas resolve to.
+        Some(ReferenceOr::Reference { .. }) | None => Type::Primitive(Primitive::Any),
+    }
 }
 
-fn path_to_routes(openapi: &OpenApi, path: String, path_item: PathItem) -> Vec<Route> {
-    let get_route = path_item
-        .get
-        .map(|operation| operation_to_route(&openapi, path.clone(), HttpMethod::Get, operation));
+fn path_to_routes(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    pointer: &JsonPointer,
+    path: String,
+    path_item: PathItem,
+) -> Vec<Route> {
+    // Path item parameters are resolved once so that problems with them are reported once.
+    let mut parameters = Vec::new();
 
-    let post_route = path_item
-        .post
-        .map(|operation| operation_to_route(&openapi, path.clone(), HttpMethod::Post, operation));
+    for (index, parameter) in path_item.parameters.iter().enumerate() {
+        let parameter_pointer = pointer.join("parameters").join(index.to_string());
 
-    let put_route = path_item
-        .put
-        .map(|operation| operation_to_route(&openapi, path.clone(), HttpMethod::Put, operation));
+        match resolve_parameter(openapi, parameter.clone()) {
+            Ok(resolved) => {
+                parameters.push((parameter_pointer_of(parameter, parameter_pointer), resolved))
+            }
+            Err(message) => {
+                diagnostics.push(Diagnostic::error(message).with_pointer(parameter_pointer))
+            }
+        }
+    }
 
-    let patch_route = path_item
-        .patch
-        .map(|operation| operation_to_route(&openapi, path.clone(), HttpMethod::Patch, operation));
+    let path_item_defaults = PathItemDefaults {
+        parameters,
+        servers: &path_item.servers,
+    };
 
-    let delete_route = path_item
-        .delete
-        .map(|operation| operation_to_route(&openapi, path.clone(), HttpMethod::Delete, operation));
+    let operations = vec![
+        ("get", HttpMethod::Get, path_item.get),
+        ("post", HttpMethod::Post, path_item.post),
+        ("put", HttpMethod::Put, path_item.put),
+        ("patch", HttpMethod::Patch, path_item.patch),
+        ("delete", HttpMethod::Delete, path_item.delete),
+    ];
 
-    vec![get_route, post_route, put_route, patch_route, delete_route]
+    operations
         .into_iter()
-        .filter_map(|x| x)
+        .filter_map(|(key, method, operation)| {
+            operation_to_route(
+                openapi,
+                diagnostics,
+                &pointer.join(key),
+                path.clone(),
+                &path_item_defaults,
+                method,
+                operation?,
+            )
+        })
         .collect()
 }
 
+/// What the operations of a path item inherit from it.
+struct PathItemDefaults<'a> {
+    parameters: Vec<(JsonPointer, OpenApiParameter)>,
+    servers: &'a [OpenApiServer],
+}
+
+/// Returns the pointer to the definition of `parameter`, which is `pointer` unless it is a
+/// reference.
+fn parameter_pointer_of(
+    parameter: &ReferenceOr<OpenApiParameter>,
+    pointer: JsonPointer,
+) -> JsonPointer {
+    match parameter {
+        ReferenceOr::Reference { reference } => JsonPointer::parse(reference),
+        ReferenceOr::Item(_) => pointer,
+    }
+}
+
+fn parameter_data(parameter: &OpenApiParameter) -> &ParameterData {
+    match parameter {
+        OpenApiParameter::Path { parameter_data, .. }
+        | OpenApiParameter::Query { parameter_data, .. }
+        | OpenApiParameter::Header { parameter_data, .. }
+        | OpenApiParameter::Cookie { parameter_data, .. } => parameter_data,
+    }
+}
+
 fn parameter_to_url_parameter(parameter: ParameterData) -> UrlParameter {
     UrlParameter {
         name: parameter.name,
@@ -76,51 +367,473 @@ fn parameter_to_url_parameter(parameter: ParameterData) -> UrlParameter {
     }
 }
 
+fn parameter_to_parameter(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    pointer: &JsonPointer,
+    operation_id: &str,
+    parameter: ParameterData,
+) -> Parameter {
+    let name = parameter.name;
+
+    let schema = match parameter.format {
+        ParameterSchemaOrContent::Schema(schema) => Some((pointer.join("schema"), schema)),
+        ParameterSchemaOrContent::Content(content) => {
+            content
+                .into_iter()
+                .next()
+                .and_then(|(media_type, content)| {
+                    let pointer = pointer.join("content").join(media_type).join("schema");
+
+                    content.schema.map(|schema| (pointer, schema))
+                })
+        }
+    };
+
+    let is_nullable = schema
+        .as_ref()
+        .is_some_and(|(_, schema)| is_nullable(openapi, schema));
+
+    let ty = match schema {
+        Some((pointer, schema)) => resolve_schema_to_type(
+            openapi,
+            diagnostics,
+            &mut Vec::new(),
+            &pointer,
+            schema,
+            Some(format!("{} {}", operation_id, name)),
+        ),
+        None => Type::Primitive(Primitive::String),
+    };
+
+    Parameter {
+        name,
+        ty,
+        is_optional: !parameter.required,
+        is_nullable,
+    }
+}
+
+/// Converts an operation to a route.
+///
+/// Returns `None` if the operation cannot be converted, in which case the problems are reported
+/// to `diagnostics`.
 fn operation_to_route(
     openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    pointer: &JsonPointer,
     path: String,
+    path_item_defaults: &PathItemDefaults,
     method: HttpMethod,
     operation: Operation,
-) -> Route {
-    let url_parameters: Vec<UrlParameter> = operation
-        .parameters
-        .iter()
-        .filter_map(|parameter| match parameter {
-            ReferenceOr::Reference { .. } => None,
-            ReferenceOr::Item(Parameter::Path { parameter_data, .. }) => {
-                Some(parameter_to_url_parameter(parameter_data.clone()))
+) -> Option<Route> {
+    let operation_id = operation.operation_id.clone();
+
+    if operation_id.is_none() {
+        diagnostics.push(
+            Diagnostic::error(format!("No operation ID for '{}'.", path))
+                .with_pointer(pointer.clone()),
+        );
+    }
+
+    // Continue converting the rest of the operation so that any other problems with it are
+    // reported as well.
+    let name = operation_id.clone().unwrap_or_default();
+
+    let mut url_parameters = Vec::new();
+    let mut query_parameters = Vec::new();
+    let mut header_parameters = Vec::new();
+    let mut cookie_parameters = Vec::new();
+
+    // Operations can override the parameters of their path with a parameter with the same name and
+    // location.
+    let mut parameters = path_item_defaults.parameters.clone();
+
+    for (index, parameter) in operation.parameters.iter().enumerate() {
+        let parameter_pointer = pointer.join("parameters").join(index.to_string());
+
+        match resolve_parameter(openapi, parameter.clone()) {
+            Ok(resolved) => {
+                parameters.retain(|(_, existing)| {
+                    parameter_data(existing).name != parameter_data(&resolved).name
+                        || std::mem::discriminant(existing) != std::mem::discriminant(&resolved)
+                });
+                parameters.push((parameter_pointer_of(parameter, parameter_pointer), resolved));
+            }
+            Err(message) => {
+                diagnostics.push(Diagnostic::error(message).with_pointer(parameter_pointer))
+            }
+        }
+    }
+
+    for (parameter_pointer, parameter) in parameters {
+        match parameter {
+            OpenApiParameter::Path { parameter_data, .. } => {
+                url_parameters.push(parameter_to_url_parameter(parameter_data))
             }
-            ReferenceOr::Item(_) => None,
+            OpenApiParameter::Query { parameter_data, .. } => {
+                query_parameters.push(parameter_to_parameter(
+                    openapi,
+                    diagnostics,
+                    &parameter_pointer,
+                    &name,
+                    parameter_data,
+                ))
+            }
+            OpenApiParameter::Header { parameter_data, .. } => {
+                header_parameters.push(parameter_to_parameter(
+                    openapi,
+                    diagnostics,
+                    &parameter_pointer,
+                    &name,
+                    parameter_data,
+                ))
+            }
+            OpenApiParameter::Cookie { parameter_data, .. } => {
+                cookie_parameters.push(parameter_to_parameter(
+                    openapi,
+                    diagnostics,
+                    &parameter_pointer,
+                    &name,
+                    parameter_data,
+                ))
+            }
+        }
+    }
+
+    let responses_pointer = pointer.join("responses");
+
+    let documented_responses = operation
+        .responses
+        .responses
+        .iter()
+        .map(|(status, response)| {
+            let status = match status {
+                OpenApiStatusCode::Code(code) => StatusCode::Code(*code),
+                OpenApiStatusCode::Range(range) => StatusCode::Range(*range),
+            };
+
+            (status, response.clone())
+        })
+        .chain(
+            operation
+                .responses
+                .default
+                .clone()
+                .map(|response| (StatusCode::Default, response)),
+        )
+        .collect::<Vec<_>>();
+
+    let bodies: IndexMap<StatusCode, Option<(Type, MediaType)>> = documented_responses
+        .into_iter()
+        .map(|(status, response)| {
+            let pointer = responses_pointer.join(status.to_string());
+            let response_pointer = match &response {
+                ReferenceOr::Reference { reference } => JsonPointer::parse(reference),
+                ReferenceOr::Item(_) => pointer.clone(),
+            };
+
+            let body = match resolve_response(openapi, response) {
+                Ok(response) => content_to_body(
+                    openapi,
+                    diagnostics,
+                    &response_pointer.join("content"),
+                    &response.content,
+                    format!("{} {} response", name, status),
+                ),
+                Err(message) => {
+                    diagnostics.push(Diagnostic::error(message).with_pointer(pointer));
+
+                    None
+                }
+            };
+
+            (status, body)
         })
         .collect();
 
-    let default_or_ok_response = operation.responses.default.clone().or_else(|| {
-        operation
-            .responses
-            .responses
-            .get(&StatusCode::Code(200))
-            .cloned()
+    // The default response describes success when no successful responses are documented.
+    let has_success_responses = bodies.keys().any(StatusCode::is_success);
+    let (return_type, return_media_type) = if has_success_responses {
+        bodies
+            .iter()
+            .filter(|(status, _)| status.is_success())
+            .find_map(|(_, body)| body.clone())
+    } else {
+        bodies.get(&StatusCode::Default).cloned().flatten()
+    }
+    .map_or((None, MediaType::Json), |(ty, media_type)| {
+        (Some(ty), media_type)
     });
 
+    // Error responses are only read as JSON, so other bodies are treated as if they were absent.
+    let responses = bodies
+        .into_iter()
+        .map(|(status, body)| {
+            let ty = body
+                .filter(|(_, media_type)| status.is_success() || *media_type == MediaType::Json)
+                .map(|(ty, _)| ty);
+
+            (status, ty)
+        })
+        .collect();
+
+    let payload = operation.request_body.clone().and_then(|request_body| {
+        let request_body_pointer = match &request_body {
+            ReferenceOr::Reference { reference } => JsonPointer::parse(reference),
+            ReferenceOr::Item(_) => pointer.join("requestBody"),
+        };
+
+        match resolve_request_body(openapi, request_body) {
+            Ok(request_body) => content_to_body(
+                openapi,
+                diagnostics,
+                &request_body_pointer.join("content"),
+                &request_body.content,
+                format!("{} request", name),
+            ),
+            Err(message) => {
+                diagnostics
+                    .push(Diagnostic::error(message).with_pointer(pointer.join("requestBody")));
+
+                None
+            }
+        }
+    });
+    let (payload_type, payload_media_type) = payload
+        .map_or((None, MediaType::Json), |(ty, media_type)| {
+            (Some(ty), media_type)
+        });
+
+    // Operations without their own security requirements use those of the API.
+    let security_pointer = if operation.security.is_some() {
+        pointer.join("security")
+    } else {
+        JsonPointer::root().join("security")
+    };
+
+    let security = operation
+        .security
+        .as_ref()
+        .or(openapi.security.as_ref())
+        .map(|requirements| {
+            requirements
+                .iter()
+                .enumerate()
+                .filter_map(|(index, requirement)| {
+                    security_requirement_to_security_requirement(
+                        openapi,
+                        diagnostics,
+                        &security_pointer.join(index.to_string()),
+                        requirement,
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Operations without their own servers use those of their path, and otherwise those of the API.
+    let servers = [
+        &operation.servers[..],
+        path_item_defaults.servers,
+        &openapi.servers[..],
+    ]
+    .iter()
+    .find(|servers| !servers.is_empty())
+    .map(|servers| servers.iter().map(server_to_server).collect())
+    .unwrap_or_default();
+
     let description = build_description(&operation);
 
-    Route {
-        name: operation
-            .operation_id
-            .expect(&format!("No operation ID for {}", &path)),
+    Some(Route {
+        name: operation_id?,
         description,
         url: path.replace("{", ":").replace("}", ""),
         method,
         group: operation.tags.first().unwrap_or(&path).to_string(),
         version: "".into(),
         url_parameters,
-        payload_type: None,
-        return_type: default_or_ok_response.and_then(|default_response| match default_response {
-            ReferenceOr::Item(default_response) => {
-                response_to_return_type(&openapi, default_response).ok()
+        query_parameters,
+        header_parameters,
+        cookie_parameters,
+        payload_type,
+        payload_media_type,
+        return_type,
+        return_media_type,
+        responses,
+        security,
+        servers,
+    })
+}
+
+fn server_to_server(server: &OpenApiServer) -> Server {
+    Server {
+        url: server.url.clone(),
+        description: server.description.clone(),
+        variables: server
+            .variables
+            .iter()
+            .flatten()
+            .map(|(name, variable)| {
+                (
+                    name.clone(),
+                    ServerVariable {
+                        default: variable.default.clone(),
+                        allowed_values: variable.enumeration.clone(),
+                        description: variable.description.clone(),
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Converts a security requirement, resolving the schemes it refers to by name.
+///
+/// Returns `None` if any of the schemes cannot be resolved or are unsupported, reporting the
+/// problem to `diagnostics`.
+fn security_requirement_to_security_requirement(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    pointer: &JsonPointer,
+    requirement: &OpenApiSecurityRequirement,
+) -> Option<SecurityRequirement> {
+    let mut schemes = Vec::new();
+
+    for (name, scopes) in requirement {
+        let scheme_pointer = JsonPointer::root()
+            .join("components")
+            .join("securitySchemes")
+            .join(name.clone());
+
+        let scheme = match resolve_security_scheme(openapi, name) {
+            Ok(scheme) => scheme,
+            Err(message) => {
+                diagnostics
+                    .push(Diagnostic::error(message).with_pointer(pointer.join(name.clone())));
+
+                return None;
             }
-            ReferenceOr::Reference { .. } => None,
-        }),
+        };
+
+        let kind = match scheme {
+            OpenApiSecurityScheme::APIKey { location, name } => SecuritySchemeKind::ApiKey {
+                location: match location {
+                    APIKeyLocation::Header => ApiKeyLocation::Header,
+                    APIKeyLocation::Query => ApiKeyLocation::Query,
+                    APIKeyLocation::Cookie => ApiKeyLocation::Cookie,
+                },
+                parameter_name: name,
+            },
+            OpenApiSecurityScheme::HTTP {
+                scheme,
+                bearer_format,
+            } => match scheme.to_lowercase().as_str() {
+                "basic" => SecuritySchemeKind::Basic,
+                "bearer" => SecuritySchemeKind::Bearer {
+                    format: bearer_format,
+                },
+                _ => {
+                    diagnostics.push(
+                        Diagnostic::warning(format!(
+                            "Unsupported HTTP authentication scheme '{}', so requests are not authorized with '{}'.",
+                            scheme, name
+                        ))
+                        .with_pointer(scheme_pointer),
+                    );
+
+                    return None;
+                }
+            },
+            OpenApiSecurityScheme::OAuth2 { flows } => {
+                let mut oauth2_flows: Vec<OAuth2Flow> = Vec::new();
+
+                for flow in vec![
+                    flows.implicit,
+                    flows.password,
+                    flows.client_credentials,
+                    flows.authorization_code,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    let flow = oauth2_flow_to_oauth2_flow(flow);
+
+                    if !oauth2_flows.contains(&flow) {
+                        oauth2_flows.push(flow);
+                    }
+                }
+
+                SecuritySchemeKind::OAuth2 {
+                    flows: oauth2_flows,
+                }
+            }
+            OpenApiSecurityScheme::OpenIDConnect {
+                open_id_connect_url,
+            } => SecuritySchemeKind::OpenIdConnect {
+                url: open_id_connect_url,
+            },
+        };
+
+        schemes.push((
+            SecurityScheme {
+                name: name.clone(),
+                kind,
+            },
+            scopes.clone(),
+        ));
+    }
+
+    Some(SecurityRequirement { schemes })
+}
+
+fn oauth2_flow_to_oauth2_flow(flow: OpenApiOAuth2Flow) -> OAuth2Flow {
+    match flow {
+        OpenApiOAuth2Flow::Implicit {
+            authorization_url,
+            refresh_url,
+            scopes,
+        } => OAuth2Flow {
+            kind: OAuth2FlowKind::Implicit,
+            authorization_url: Some(authorization_url),
+            token_url: None,
+            refresh_url,
+            scopes,
+        },
+        OpenApiOAuth2Flow::Password {
+            refresh_url,
+            token_url,
+            scopes,
+        } => OAuth2Flow {
+            kind: OAuth2FlowKind::Password,
+            authorization_url: None,
+            token_url: Some(token_url),
+            refresh_url,
+            scopes,
+        },
+        OpenApiOAuth2Flow::ClientCredentials {
+            refresh_url,
+            token_url,
+            scopes,
+        } => OAuth2Flow {
+            kind: OAuth2FlowKind::ClientCredentials,
+            authorization_url: None,
+            token_url: Some(token_url),
+            refresh_url,
+            scopes,
+        },
+        OpenApiOAuth2Flow::AuthorizationCode {
+            authorization_url,
+            token_url,
+            refresh_url,
+            scopes,
+        } => OAuth2Flow {
+            kind: OAuth2FlowKind::AuthorizationCode,
+            authorization_url: Some(authorization_url),
+            token_url: Some(token_url),
+            refresh_url,
+            scopes,
+        },
     }
 }
 
@@ -144,35 +857,201 @@ fn build_description(operation: &Operation) -> Option<NonEmptyString> {
     }
 }
 
-fn response_to_return_type(openapi: &OpenApi, response: Response) -> Result<Type, String> {
-    let media_type = "application/json";
+/// Converts the body described by the content of a request or response to a type and the format
+/// it is sent in, naming the type `name` if it is anonymous.
+///
+/// JSON is preferred when the body may be sent in several formats.
+fn content_to_body(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    pointer: &JsonPointer,
+    content: &Content,
+    name: String,
+) -> Option<(Type, MediaType)> {
+    let (key, media) = content
+        .iter()
+        .find(|(key, _)| MediaType::parse(key) == MediaType::Json)
+        .or_else(|| content.iter().next())?;
 
-    let media_type = response
-        .content
-        .get(media_type)
-        .ok_or_else(|| format!("No response found for {}", media_type))?;
+    let media_type = MediaType::parse(key);
 
-    let schema = media_type
-        .schema
-        .clone()
-        .and_then(|schema| resolve_schema(&openapi, schema))
-        .ok_or_else(|| "No schema.".to_string())?;
+    let ty = match media_type {
+        MediaType::Text(_) => Type::Primitive(Primitive::String),
+        MediaType::Binary(_) => Type::Primitive(Primitive::Binary),
+        MediaType::Json | MediaType::FormUrlEncoded | MediaType::Multipart => {
+            resolve_schema_to_type(
+                openapi,
+                diagnostics,
+                &mut Vec::new(),
+                &pointer.join(key.as_str()).join("schema"),
+                media.schema.clone()?,
+                Some(name),
+            )
+        }
+    };
 
-    let return_type = schema_to_type(&openapi, schema);
+    Some((ty, media_type))
+}
 
-    Ok(return_type)
+/// Resolves the schema at the given location and converts it to a type, naming it `name` if it is
+/// anonymous.
+///
+/// A schema that is referred to and declares a named type becomes a reference to that type, which
+/// is declared once along with the routes rather than being copied into every type that uses it.
+///
+/// If the schema cannot be resolved the problem is reported to `diagnostics` and a string type is
+/// returned in its place.
+fn resolve_schema_to_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    schema: ReferenceOr<Schema>,
+    name: Option<String>,
+) -> Type {
+    match resolve_schema_to_named_type(openapi, diagnostics, resolving, pointer, schema, name) {
+        (Some(schema_name), ty) if ty.name() == Some(schema_name.as_str()) => {
+            Type::Reference(schema_name)
+        }
+        (_, ty) => ty,
+    }
+}
+
+/// Resolves the schema at the given location and converts it to a type, naming it `name` if it is
+/// anonymous. The type is returned along with the name of the schema if it is referred to.
+///
+/// `resolving` holds the names of the schemas being converted, so that a schema that refers back
+/// to one of them becomes a reference to its type rather than being converted again.
+fn resolve_schema_to_named_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    schema: ReferenceOr<Schema>,
+    name: Option<String>,
+) -> (Option<String>, Type) {
+    let (schema_pointer, is_reference) = match &schema {
+        ReferenceOr::Reference { reference } => (JsonPointer::parse(reference), true),
+        ReferenceOr::Item(_) => (pointer.clone(), false),
+    };
+
+    match resolve_schema(openapi, schema) {
+        Ok(schema) => {
+            let schema_name = schema.name().filter(|_| is_reference).cloned();
+
+            if let Some(schema_name) = schema_name.as_ref() {
+                if resolving.contains(schema_name) {
+                    return (
+                        Some(schema_name.clone()),
+                        Type::Reference(schema_name.clone()),
+                    );
+                }
+
+                resolving.push(schema_name.clone());
+            }
+
+            let schema = match name {
+                Some(name) => schema.or_named(name),
+                None => schema,
+            };
+
+            let ty = schema_to_type(openapi, diagnostics, resolving, &schema_pointer, schema);
+
+            if schema_name.is_some() {
+                resolving.pop();
+            }
+
+            (schema_name, ty)
+        }
+        Err(message) => {
+            diagnostics.push(Diagnostic::error(message).with_pointer(pointer.clone()));
+
+            (None, Type::Primitive(Primitive::String))
+        }
+    }
 }
 
-fn schema_to_type(openapi: &OpenApi, schema: NamedOrAnonymous<Schema>) -> Type {
+fn schema_to_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    schema: NamedOrAnonymous<Schema>,
+) -> Type {
     let name = schema.name().cloned();
 
-    let mut ty = match schema.into_value().schema_kind {
-        SchemaKind::Type(ty) => openapi_type_to_type(&openapi, ty),
-        // TODO: Finish implementing remaining schema kinds.
-        _ => Type::Map {
-            key: Box::new(Type::Primitive(Primitive::String)),
-            value: Box::new(Type::Primitive(Primitive::String)),
-        },
+    let schema = schema.into_value();
+
+    // A lone `allOf` only wraps a reference so that keywords such as `nullable` can sit alongside
+    // it, so the type is that of the schema it wraps.
+    if let SchemaKind::AllOf { all_of } = &schema.schema_kind {
+        if let [item_schema] = all_of.as_slice() {
+            return resolve_schema_to_type(
+                openapi,
+                diagnostics,
+                resolving,
+                &pointer.join("allOf").join("0"),
+                item_schema.clone(),
+                name,
+            );
+        }
+    }
+
+    let mut ty = match schema.schema_kind {
+        SchemaKind::Type(ty) => openapi_type_to_type(
+            openapi,
+            diagnostics,
+            resolving,
+            pointer,
+            name.as_deref(),
+            ty,
+        ),
+        SchemaKind::OneOf { one_of: schemas } => union_to_type(
+            openapi,
+            diagnostics,
+            resolving,
+            &pointer.join("oneOf"),
+            name.as_deref(),
+            schema.schema_data.discriminator,
+            schemas,
+        ),
+        SchemaKind::AnyOf { any_of: schemas } => union_to_type(
+            openapi,
+            diagnostics,
+            resolving,
+            &pointer.join("anyOf"),
+            name.as_deref(),
+            schema.schema_data.discriminator,
+            schemas,
+        ),
+        SchemaKind::AllOf { all_of } => intersection_to_type(
+            openapi,
+            diagnostics,
+            resolving,
+            &pointer.join("allOf"),
+            name.as_deref(),
+            all_of,
+        ),
+        // Schemas without a type that describe properties are objects all the same.
+        SchemaKind::Any(any)
+            if !any.properties.is_empty() || any.additional_properties.is_some() =>
+        {
+            openapi_type_to_type(
+                openapi,
+                diagnostics,
+                resolving,
+                pointer,
+                name.as_deref(),
+                OpenApiType::Object(ObjectType {
+                    properties: any.properties,
+                    required: any.required,
+                    additional_properties: any.additional_properties,
+                    min_properties: any.min_properties,
+                    max_properties: any.max_properties,
+                }),
+            )
+        }
+        SchemaKind::Any(_) => Type::Primitive(Primitive::Any),
     };
 
     if let Some(name) = name {
@@ -182,47 +1061,340 @@ fn schema_to_type(openapi: &OpenApi, schema: NamedOrAnonymous<Schema>) -> Type {
     ty
 }
 
-fn openapi_type_to_type(openapi: &OpenApi, ty: OpenApiType) -> Type {
+/// Converts an OpenAPI type to a type.
+///
+/// The `name` of the schema is used to name any anonymous types nested within it.
+fn openapi_type_to_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    name: Option<&str>,
+    ty: OpenApiType,
+) -> Type {
     match ty {
-        OpenApiType::String(_) => Type::Primitive(Primitive::String),
-        OpenApiType::Number(_) => Type::Primitive(Primitive::Float),
-        OpenApiType::Integer(_) => Type::Primitive(Primitive::Integer),
+        OpenApiType::String(StringType { enumeration, .. }) if !enumeration.is_empty() => {
+            Type::Enum {
+                name: type_name(diagnostics, pointer, name),
+                variants: enumeration
+                    .into_iter()
+                    .map(|value| EnumVariant {
+                        name: value.clone(),
+                        value: EnumValue::String(value),
+                    })
+                    .collect(),
+            }
+        }
+        OpenApiType::Integer(IntegerType { enumeration, .. }) if !enumeration.is_empty() => {
+            Type::Enum {
+                name: type_name(diagnostics, pointer, name),
+                variants: enumeration
+                    .into_iter()
+                    .map(|value| EnumVariant {
+                        name: format!("Value {}", value),
+                        value: EnumValue::Integer(value),
+                    })
+                    .collect(),
+            }
+        }
+        OpenApiType::String(StringType { format, .. }) => {
+            Type::Primitive(string_format_to_primitive(format))
+        }
+        OpenApiType::Number(NumberType { format, .. }) => {
+            Type::Primitive(number_format_to_primitive(format))
+        }
+        OpenApiType::Integer(IntegerType { format, .. }) => {
+            Type::Primitive(integer_format_to_primitive(format))
+        }
         OpenApiType::Boolean {} => Type::Primitive(Primitive::Boolean),
+        // Objects that only describe their additional properties are dictionaries.
+        OpenApiType::Object(ObjectType {
+            properties,
+            additional_properties: Some(additional_properties),
+            ..
+        }) if properties.is_empty()
+            && !matches!(additional_properties, AdditionalProperties::Any(false)) =>
+        {
+            let value_type = match additional_properties {
+                AdditionalProperties::Schema(schema) => resolve_schema_to_type(
+                    openapi,
+                    diagnostics,
+                    resolving,
+                    &pointer.join("additionalProperties"),
+                    *schema,
+                    name.map(|name| format!("{} value", name)),
+                ),
+                AdditionalProperties::Any(_) => Type::Primitive(Primitive::Any),
+            };
+
+            Type::Map {
+                key: Box::new(Type::Primitive(Primitive::String)),
+                value: Box::new(value_type),
+            }
+        }
         OpenApiType::Object(ObjectType {
             properties,
             required,
             ..
         }) => Type::Record {
-            name: "No Name".into(),
+            name: type_name(diagnostics, pointer, name),
             members: properties
                 .into_iter()
-                .map(|(name, schema)| {
-                    let is_optional = !required.contains(&name);
+                .map(|(member_name, schema)| {
+                    let schema = schema.unbox();
+
+                    let is_optional = !required.contains(&member_name);
+                    let is_nullable = is_nullable(openapi, &schema);
 
-                    let schema = resolve_schema(&openapi, schema.unbox());
+                    let ty = resolve_schema_to_type(
+                        openapi,
+                        diagnostics,
+                        resolving,
+                        &pointer.join("properties").join(member_name.clone()),
+                        schema,
+                        name.map(|name| format!("{} {}", name, member_name)),
+                    );
 
                     Member {
-                        name,
+                        name: member_name.clone(),
+                        wire_name: member_name,
                         description: None,
-                        ty: schema
-                            .map(|schema| schema_to_type(&openapi, schema))
-                            .unwrap_or(Type::Primitive(Primitive::String)),
+                        ty,
                         is_optional,
+                        is_nullable,
                     }
                 })
                 .collect(),
         },
+        OpenApiType::Array(_) if tuple_items(openapi, pointer).is_some() => {
+            let item_schemas = tuple_items(openapi, pointer).unwrap_or_default().to_vec();
+
+            let item_types = item_schemas
+                .into_iter()
+                .enumerate()
+                .map(|(index, item_schema)| {
+                    let pointer = pointer.join("prefixItems").join(index.to_string());
+
+                    match serde_json::from_value(item_schema) {
+                        Ok(item_schema) => resolve_schema_to_type(
+                            openapi,
+                            diagnostics,
+                            resolving,
+                            &pointer,
+                            item_schema,
+                            name.map(|name| format!("{} item {}", name, index + 1)),
+                        ),
+                        Err(err) => {
+                            diagnostics.push(
+                                Diagnostic::error(format!("Invalid schema: {}", err))
+                                    .with_pointer(pointer),
+                            );
+
+                            Type::Primitive(Primitive::String)
+                        }
+                    }
+                })
+                .collect();
+
+            Type::Tuple(item_types)
+        }
         OpenApiType::Array(ArrayType { items, .. }) => {
-            let item_type = match resolve_schema(&openapi, items.unbox()) {
-                Some(schema) => schema_to_type(&openapi, schema),
-                None => Type::Primitive(Primitive::String),
-            };
+            let item_type = resolve_schema_to_type(
+                openapi,
+                diagnostics,
+                resolving,
+                &pointer.join("items"),
+                items.unbox(),
+                name.map(|name| format!("{} item", name)),
+            );
 
             Type::Array(Box::new(item_type))
         }
     }
 }
 
+/// Returns the name for a type that must be named, reporting a problem if the schema it comes
+/// from is anonymous and no name can be derived from where it is used.
+fn type_name(diagnostics: &mut Diagnostics, pointer: &JsonPointer, name: Option<&str>) -> String {
+    match name {
+        Some(name) => name.to_owned(),
+        None => {
+            diagnostics.push(
+                Diagnostic::error("Could not name the type of the schema. Declare it in `components/schemas` instead.")
+                    .with_pointer(pointer.clone()),
+            );
+
+            String::new()
+        }
+    }
+}
+
+/// Returns whether the schema, or the schema it refers to, allows `null`.
+fn is_nullable(openapi: &OpenApi, schema: &ReferenceOr<Schema>) -> bool {
+    resolve_schema(openapi, schema.clone())
+        .map(|schema| schema.into_value().schema_data.nullable)
+        .unwrap_or(false)
+}
+
+fn string_format_to_primitive(format: VariantOrUnknownOrEmpty<StringFormat>) -> Primitive {
+    match format {
+        VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => Primitive::DateTime,
+        VariantOrUnknownOrEmpty::Item(StringFormat::Date) => Primitive::Date,
+        VariantOrUnknownOrEmpty::Item(StringFormat::Byte) => Primitive::Bytes,
+        VariantOrUnknownOrEmpty::Item(StringFormat::Binary) => Primitive::Binary,
+        VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+            "time" => Primitive::Time,
+            "uuid" => Primitive::Uuid,
+            "uri" | "url" => Primitive::Uri,
+            // Numbers written as strings, such as `decimal` and `int64`, are kept as strings, as
+            // that is how they are sent.
+            _ => Primitive::String,
+        },
+        VariantOrUnknownOrEmpty::Item(StringFormat::Password) | VariantOrUnknownOrEmpty::Empty => {
+            Primitive::String
+        }
+    }
+}
+
+fn number_format_to_primitive(format: VariantOrUnknownOrEmpty<NumberFormat>) -> Primitive {
+    match format {
+        VariantOrUnknownOrEmpty::Item(NumberFormat::Double) => Primitive::Double,
+        VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+            "decimal" => Primitive::Decimal,
+            "int64" => Primitive::Long,
+            "int32" => Primitive::Integer,
+            _ => Primitive::Float,
+        },
+        VariantOrUnknownOrEmpty::Item(NumberFormat::Float) | VariantOrUnknownOrEmpty::Empty => {
+            Primitive::Float
+        }
+    }
+}
+
+fn integer_format_to_primitive(format: VariantOrUnknownOrEmpty<IntegerFormat>) -> Primitive {
+    match format {
+        VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64) => Primitive::Long,
+        VariantOrUnknownOrEmpty::Unknown(format) if format == "uint64" => Primitive::Long,
+        VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32)
+        | VariantOrUnknownOrEmpty::Unknown(_)
+        | VariantOrUnknownOrEmpty::Empty => Primitive::Integer,
+    }
+}
+
+/// Converts the schemas of a `oneOf` or `anyOf` into a union.
+fn union_to_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    name: Option<&str>,
+    discriminator: Option<Discriminator>,
+    schemas: Vec<ReferenceOr<Schema>>,
+) -> Type {
+    let cases = schemas
+        .into_iter()
+        .enumerate()
+        .map(|(index, schema)| {
+            let reference = match &schema {
+                ReferenceOr::Reference { reference } => Some(reference.clone()),
+                ReferenceOr::Item(_) => None,
+            };
+
+            let schema_name = reference
+                .as_ref()
+                .and_then(|reference| reference.rsplit('/').next())
+                .map(str::to_owned);
+
+            let ty = resolve_schema_to_type(
+                openapi,
+                diagnostics,
+                resolving,
+                &pointer.join(index.to_string()),
+                schema,
+                name.map(|name| format!("{} case {}", name, index + 1)),
+            );
+
+            let mapped_tag =
+                discriminator
+                    .as_ref()
+                    .zip(reference)
+                    .and_then(|(discriminator, reference)| {
+                        discriminator
+                            .mapping
+                            .iter()
+                            .find(|(_, target)| {
+                                **target == reference
+                                    || reference.ends_with(&format!("/{}", target))
+                            })
+                            .map(|(tag, _)| tag.clone())
+                    });
+
+            let case_name = mapped_tag
+                .or(schema_name)
+                .unwrap_or_else(|| format!("Case{}", index + 1));
+
+            UnionCase {
+                name: case_name,
+                ty,
+            }
+        })
+        .collect();
+
+    Type::Union {
+        name: type_name(diagnostics, pointer, name),
+        discriminator: discriminator.map(|discriminator| discriminator.property_name),
+        cases,
+    }
+}
+
+/// Converts the schemas of an `allOf` into a single record containing the members of each schema.
+///
+/// Members from later schemas take precedence over members with the same name from earlier ones.
+fn intersection_to_type(
+    openapi: &OpenApi,
+    diagnostics: &mut Diagnostics,
+    resolving: &mut Vec<String>,
+    pointer: &JsonPointer,
+    name: Option<&str>,
+    schemas: Vec<ReferenceOr<Schema>>,
+) -> Type {
+    let mut members: Vec<Member> = Vec::new();
+
+    for (index, schema) in schemas.into_iter().enumerate() {
+        // The members of the schemas are copied, so the types that they declare are needed rather
+        // than references to them.
+        let (_, ty) = resolve_schema_to_named_type(
+            openapi,
+            diagnostics,
+            resolving,
+            &pointer.join(index.to_string()),
+            schema,
+            name.map(str::to_owned),
+        );
+
+        if let Type::Record {
+            members: schema_members,
+            ..
+        } = ty
+        {
+            for member in schema_members {
+                match members
+                    .iter_mut()
+                    .find(|existing| existing.name == member.name)
+                {
+                    Some(existing) => *existing = member,
+                    None => members.push(member),
+                }
+            }
+        }
+    }
+
+    Type::Record {
+        name: type_name(diagnostics, pointer, name),
+        members,
+    }
+}
+
 #[derive(Debug, Clone)]
 enum NamedOrAnonymous<T> {
     Named(String, T),
@@ -237,6 +1409,14 @@ impl<T> NamedOrAnonymous<T> {
         }
     }
 
+    /// Names the value if it is anonymous.
+    fn or_named(self, name: String) -> Self {
+        match self {
+            NamedOrAnonymous::Named(..) => self,
+            NamedOrAnonymous::Anonymous(value) => NamedOrAnonymous::Named(name, value),
+        }
+    }
+
     fn into_value(self) -> T {
         match self {
             NamedOrAnonymous::Named(_, value) | NamedOrAnonymous::Anonymous(value) => value,
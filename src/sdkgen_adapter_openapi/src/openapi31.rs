@@ -0,0 +1,720 @@
+use std::collections::HashMap;
+
+use openapiv3::OpenAPI as OpenApi;
+use sdkgen_core::{Diagnostic, Diagnostics, JsonPointer};
+use serde_json::{json, Map, Value};
+
+/// The extension that records the `prefixItems` of tuple schemas, keyed by the location of each
+/// schema, since OpenAPI 3.0 schemas have nowhere to keep them.
+const TUPLES_EXTENSION: &str = "x-sdkgen-tuples";
+
+/// Returns whether the document is an OpenAPI 3.1 API definition.
+pub(crate) fn is_openapi31(version: &str) -> bool {
+    version.starts_with("3.1")
+}
+
+/// Rewrites an OpenAPI 3.1 document as an OpenAPI 3.0 one.
+///
+/// The JSON Schema 2020-12 keywords of its schemas are rewritten to their closest OpenAPI 3.0
+/// equivalents:
+///
+/// - `type` arrays containing `"null"`, and `anyOf` or `oneOf` cases of `type: "null"`, become
+///   `nullable`
+/// - `const` becomes an `enum` with a single value
+/// - numeric `exclusiveMinimum` and `exclusiveMaximum` become `minimum` and `maximum`
+/// - `$defs` are moved to `components/schemas`
+/// - `prefixItems` are recorded in the document's extensions, to be read by [`tuple_items`]
+///
+/// Webhooks are dropped, since they are not part of the client. The features that are dropped or
+/// rewritten with a different meaning are reported to `diagnostics`.
+pub(crate) fn downgrade(mut document: Value, diagnostics: &mut Diagnostics) -> Value {
+    hoist_defs(&mut document, diagnostics);
+
+    let mut tuples = Map::new();
+    downgrade_schemas(
+        &mut document,
+        &JsonPointer::root(),
+        &mut tuples,
+        diagnostics,
+    );
+
+    if let Value::Object(object) = &mut document {
+        if object.remove("webhooks").is_some() {
+            diagnostics.push(
+                Diagnostic::warning(
+                    "Webhooks are not supported, so no SDK code is generated for them.",
+                )
+                .with_pointer(JsonPointer::root().join("webhooks")),
+            );
+        }
+
+        object
+            .entry("paths")
+            .or_insert_with(|| Value::Object(Map::new()));
+        object.insert(TUPLES_EXTENSION.into(), Value::Object(tuples));
+    }
+
+    document
+}
+
+/// Returns the `prefixItems` of the tuple schema at the given location, if it is one.
+pub(crate) fn tuple_items<'a>(openapi: &'a OpenApi, pointer: &JsonPointer) -> Option<&'a [Value]> {
+    openapi
+        .extensions
+        .get(TUPLES_EXTENSION)?
+        .get(pointer.to_string())?
+        .as_array()
+        .map(Vec::as_slice)
+}
+
+/// Moves the `$defs` found anywhere in the document to `components/schemas`, and rewrites the
+/// references to them.
+///
+/// A definition is named after its key, or after both the schema containing it and its key if a
+/// schema with that name already exists. Definitions that cannot be named without replacing
+/// another schema are reported to `diagnostics` instead.
+fn hoist_defs(document: &mut Value, diagnostics: &mut Diagnostics) {
+    let mut defs = Vec::new();
+    take_defs(document, &JsonPointer::root(), &mut defs);
+
+    if defs.is_empty() {
+        return;
+    }
+
+    let schemas = match document
+        .as_object_mut()
+        .map(|object| object.entry("components").or_insert_with(|| json!({})))
+        .and_then(Value::as_object_mut)
+        .map(|components| components.entry("schemas").or_insert_with(|| json!({})))
+        .and_then(Value::as_object_mut)
+    {
+        Some(schemas) => schemas,
+        None => return,
+    };
+
+    let mut references = HashMap::new();
+
+    for (pointer, schema) in defs {
+        let segments = pointer.segments();
+        let key = segments[segments.len() - 1].clone();
+
+        let name = if schemas.contains_key(&key) {
+            let parent = segments
+                .len()
+                .checked_sub(3)
+                .map(|index| segments[index].as_str())
+                .unwrap_or("Root");
+
+            format!("{} {}", parent, key)
+        } else {
+            key
+        };
+
+        if schemas.contains_key(&name) {
+            diagnostics.push(
+                Diagnostic::error(format!(
+                    "Could not move the definition to `components/schemas`, which already has a schema named '{}'.",
+                    name
+                ))
+                .with_pointer(pointer),
+            );
+
+            continue;
+        }
+
+        references.insert(
+            format!("#{}", pointer),
+            format!(
+                "#{}",
+                JsonPointer::parse("/components/schemas").join(name.clone())
+            ),
+        );
+        schemas.insert(name, schema);
+    }
+
+    rewrite_references(document, &references);
+}
+
+/// Removes the `$defs` within the value, including those nested within other definitions, along
+/// with the locations they were found at.
+fn take_defs(value: &mut Value, pointer: &JsonPointer, defs: &mut Vec<(JsonPointer, Value)>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(entries)) = object.remove("$defs") {
+                for (key, mut schema) in entries {
+                    let pointer = pointer.join("$defs").join(key);
+
+                    take_defs(&mut schema, &pointer, defs);
+                    defs.push((pointer, schema));
+                }
+            }
+
+            for (key, value) in object.iter_mut() {
+                take_defs(value, &pointer.join(key.clone()), defs);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                take_defs(value, &pointer.join(index.to_string()), defs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_references(value: &mut Value, references: &HashMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        if let Some(target) = references.get(reference) {
+                            *reference = target.clone();
+                        }
+                    }
+                    value => rewrite_references(value, references),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                rewrite_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Finds the schemas within the document and downgrades each of them.
+fn downgrade_schemas(
+    value: &mut Value,
+    pointer: &JsonPointer,
+    tuples: &mut Map<String, Value>,
+    diagnostics: &mut Diagnostics,
+) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                let pointer = pointer.join(key.clone());
+
+                match key.as_str() {
+                    "schema" => downgrade_schema(value, &pointer, tuples, diagnostics),
+                    "schemas" if pointer.segments() == ["components", "schemas"] => {
+                        if let Value::Object(schemas) = value {
+                            for (name, schema) in schemas.iter_mut() {
+                                downgrade_schema(
+                                    schema,
+                                    &pointer.join(name.clone()),
+                                    tuples,
+                                    diagnostics,
+                                );
+                            }
+                        }
+                    }
+                    "example" | "examples" => {}
+                    _ => downgrade_schemas(value, &pointer, tuples, diagnostics),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                downgrade_schemas(value, &pointer.join(index.to_string()), tuples, diagnostics);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Downgrades the schema and the schemas nested within it.
+fn downgrade_schema(
+    schema: &mut Value,
+    pointer: &JsonPointer,
+    tuples: &mut Map<String, Value>,
+    diagnostics: &mut Diagnostics,
+) {
+    // A schema of `true` accepts anything, the same as an empty schema.
+    if *schema == Value::Bool(true) {
+        *schema = json!({});
+    }
+
+    let object = match schema {
+        Value::Object(object) => object,
+        _ => return,
+    };
+
+    for keyword in &["items", "additionalProperties", "not"] {
+        if let Some(subschema) = object.get_mut(*keyword) {
+            if subschema.is_object() {
+                downgrade_schema(subschema, &pointer.join(*keyword), tuples, diagnostics);
+            }
+        }
+    }
+
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        for (name, property) in properties.iter_mut() {
+            downgrade_schema(
+                property,
+                &pointer.join("properties").join(name.clone()),
+                tuples,
+                diagnostics,
+            );
+        }
+    }
+
+    for keyword in &["prefixItems", "allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(subschemas)) = object.get_mut(*keyword) {
+            for (index, subschema) in subschemas.iter_mut().enumerate() {
+                let pointer = pointer.join(*keyword).join(index.to_string());
+
+                downgrade_schema(subschema, &pointer, tuples, diagnostics);
+            }
+        }
+    }
+
+    downgrade_type_array(object);
+    downgrade_const(object, pointer, diagnostics);
+    downgrade_exclusive_bounds(object);
+    downgrade_null_cases(object, "anyOf");
+    downgrade_null_cases(object, "oneOf");
+
+    if let Some(Value::Array(items)) = object.remove("prefixItems") {
+        tuples.insert(pointer.to_string(), Value::Array(items));
+
+        object.insert("type".into(), json!("array"));
+        object.insert("items".into(), json!({}));
+    }
+}
+
+fn is_null_type(value: &Value) -> bool {
+    value.as_str() == Some("null")
+}
+
+/// Rewrites a `type` array as a single type, or as an `anyOf` with a case for each type.
+fn downgrade_type_array(object: &mut Map<String, Value>) {
+    let types = match object.get("type") {
+        Some(Value::Array(types)) => types.clone(),
+        _ => return,
+    };
+
+    object.remove("type");
+
+    if types.iter().any(is_null_type) {
+        object.insert("nullable".into(), Value::Bool(true));
+    }
+
+    let types: Vec<Value> = types.into_iter().filter(|ty| !is_null_type(ty)).collect();
+
+    match types.as_slice() {
+        [] => {}
+        [ty] => {
+            object.insert("type".into(), ty.clone());
+        }
+        types => {
+            let cases = types
+                .iter()
+                .map(|ty| {
+                    let mut case = object.clone();
+                    case.remove("nullable");
+                    case.remove("description");
+                    case.insert("type".into(), ty.clone());
+
+                    Value::Object(case)
+                })
+                .collect();
+
+            *object = object
+                .iter()
+                .filter(|(key, _)| *key == "nullable" || *key == "description")
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            object.insert("anyOf".into(), Value::Array(cases));
+        }
+    }
+}
+
+/// Rewrites `const` as an `enum` with a single value, and removes any `null` from an `enum` in
+/// favor of `nullable`.
+///
+/// Emitters declare an enum for the single value, rather than a constant, which is reported to
+/// `diagnostics`.
+fn downgrade_const(
+    object: &mut Map<String, Value>,
+    pointer: &JsonPointer,
+    diagnostics: &mut Diagnostics,
+) {
+    if let Some(value) = object.remove("const") {
+        diagnostics.push(
+            Diagnostic::warning(
+                "`const` is not supported, so it is read as an `enum` with a single value.",
+            )
+            .with_pointer(pointer.join("const")),
+        );
+
+        object.insert("enum".into(), Value::Array(vec![value]));
+    }
+
+    let values = match object.get_mut("enum") {
+        Some(Value::Array(values)) => values,
+        _ => return,
+    };
+
+    if values.iter().any(Value::is_null) {
+        values.retain(|value| !value.is_null());
+        object.insert("nullable".into(), Value::Bool(true));
+    }
+
+    // OpenAPI 3.0 only reads `enum` alongside a `type`, which JSON Schema lets you leave out.
+    if !object.contains_key("type") {
+        let ty = object
+            .get("enum")
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(|value| match value {
+                Value::String(_) => Some("string"),
+                Value::Number(number) if number.is_i64() || number.is_u64() => Some("integer"),
+                Value::Number(_) => Some("number"),
+                Value::Bool(_) => Some("boolean"),
+                _ => None,
+            });
+
+        if let Some(ty) = ty {
+            object.insert("type".into(), json!(ty));
+        }
+    }
+}
+
+/// Rewrites numeric `exclusiveMinimum` and `exclusiveMaximum` as the bound they exclude.
+fn downgrade_exclusive_bounds(object: &mut Map<String, Value>) {
+    for (exclusive_keyword, keyword) in &[
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(Value::Number(bound)) = object.get(*exclusive_keyword).cloned() {
+            object.insert((*keyword).into(), Value::Number(bound));
+            object.insert((*exclusive_keyword).into(), Value::Bool(true));
+        }
+    }
+}
+
+/// Removes the cases of type `null` from an `anyOf` or `oneOf`, in favor of `nullable`.
+///
+/// If a single case remains the schema becomes that case, wrapped in an `allOf` if it is a
+/// reference so that it can stay `nullable`.
+fn downgrade_null_cases(object: &mut Map<String, Value>, keyword: &str) {
+    let cases = match object.get_mut(keyword) {
+        Some(Value::Array(cases)) => cases,
+        _ => return,
+    };
+
+    let case_count = cases.len();
+    cases.retain(|case| !case.get("type").is_some_and(is_null_type));
+
+    if cases.len() == case_count {
+        return;
+    }
+
+    let mut cases = match object.remove(keyword) {
+        Some(Value::Array(cases)) => cases,
+        _ => unreachable!("the cases were just found"),
+    };
+
+    object.insert("nullable".into(), Value::Bool(true));
+
+    match cases.len() {
+        0 => {}
+        1 => match cases.remove(0) {
+            case if case.get("$ref").is_some() => {
+                object.insert("allOf".into(), Value::Array(vec![case]));
+            }
+            Value::Object(case) => {
+                for (key, value) in case {
+                    object.entry(key).or_insert(value);
+                }
+            }
+            case => {
+                object.insert(keyword.into(), Value::Array(vec![case]));
+            }
+        },
+        _ => {
+            object.insert(keyword.into(), Value::Array(cases));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdkgen_core::Severity;
+
+    fn messages(diagnostics: &Diagnostics) -> Vec<String> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.clone())
+            .collect()
+    }
+
+    fn downgrade_schema_value(schema: Value) -> Value {
+        let document = downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "components": { "schemas": { "Pet": schema } }
+            }),
+            &mut Diagnostics::new(),
+        );
+
+        document["components"]["schemas"]["Pet"].clone()
+    }
+
+    #[test]
+    fn type_array_with_null_becomes_nullable() {
+        let schema = downgrade_schema_value(json!({ "type": ["string", "null"] }));
+
+        assert_eq!(schema, json!({ "type": "string", "nullable": true }));
+    }
+
+    #[test]
+    fn type_array_with_several_types_becomes_any_of() {
+        let schema = downgrade_schema_value(json!({
+            "type": ["string", "integer", "null"],
+            "description": "An identifier."
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "description": "An identifier.",
+                "nullable": true,
+                "anyOf": [{ "type": "string" }, { "type": "integer" }]
+            })
+        );
+    }
+
+    #[test]
+    fn const_becomes_enum_with_type() {
+        let schema = downgrade_schema_value(json!({ "const": "dog" }));
+
+        assert_eq!(schema, json!({ "enum": ["dog"], "type": "string" }));
+    }
+
+    #[test]
+    fn null_in_enum_becomes_nullable() {
+        let schema = downgrade_schema_value(json!({ "type": "integer", "enum": [1, 2, null] }));
+
+        assert_eq!(
+            schema,
+            json!({ "type": "integer", "enum": [1, 2], "nullable": true })
+        );
+    }
+
+    #[test]
+    fn numeric_exclusive_bounds_become_bounds() {
+        let schema = downgrade_schema_value(json!({
+            "type": "number",
+            "exclusiveMinimum": 0,
+            "exclusiveMaximum": 10
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "number",
+                "exclusiveMinimum": true,
+                "exclusiveMaximum": true,
+                "minimum": 0,
+                "maximum": 10
+            })
+        );
+    }
+
+    #[test]
+    fn null_case_of_reference_becomes_nullable_all_of() {
+        let schema = downgrade_schema_value(json!({
+            "oneOf": [{ "$ref": "#/components/schemas/Owner" }, { "type": "null" }]
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "nullable": true,
+                "allOf": [{ "$ref": "#/components/schemas/Owner" }]
+            })
+        );
+    }
+
+    #[test]
+    fn null_case_of_inline_schema_is_merged() {
+        let schema = downgrade_schema_value(json!({
+            "anyOf": [{ "type": "string", "format": "uuid" }, { "type": "null" }]
+        }));
+
+        assert_eq!(
+            schema,
+            json!({ "nullable": true, "type": "string", "format": "uuid" })
+        );
+    }
+
+    #[test]
+    fn nested_schemas_are_downgraded() {
+        let schema = downgrade_schema_value(json!({
+            "type": "object",
+            "properties": {
+                "tags": { "type": "array", "items": { "type": ["string", "null"] } },
+                "extra": { "additionalProperties": { "const": 1 } }
+            }
+        }));
+
+        assert_eq!(
+            schema["properties"]["tags"]["items"],
+            json!({ "type": "string", "nullable": true })
+        );
+        assert_eq!(
+            schema["properties"]["extra"]["additionalProperties"],
+            json!({ "enum": [1], "type": "integer" })
+        );
+    }
+
+    #[test]
+    fn examples_are_left_alone() {
+        let schema = downgrade_schema_value(json!({
+            "type": "object",
+            "example": { "type": ["string", "null"] }
+        }));
+
+        assert_eq!(schema["example"], json!({ "type": ["string", "null"] }));
+    }
+
+    #[test]
+    fn defs_are_hoisted_to_components() {
+        let mut diagnostics = Diagnostics::new();
+        let document = downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "components": {
+                    "schemas": {
+                        "Owner": { "type": "object" },
+                        "Pet": {
+                            "type": "object",
+                            "properties": {
+                                "owner": { "$ref": "#/components/schemas/Pet/$defs/Owner" },
+                                "tag": { "$ref": "#/components/schemas/Pet/$defs/Tag" }
+                            },
+                            "$defs": {
+                                "Owner": { "type": "string" },
+                                "Tag": { "type": "string" }
+                            }
+                        }
+                    }
+                }
+            }),
+            &mut diagnostics,
+        );
+
+        assert!(diagnostics.is_empty());
+
+        let schemas = &document["components"]["schemas"];
+
+        assert_eq!(schemas["Pet Owner"], json!({ "type": "string" }));
+        assert_eq!(schemas["Tag"], json!({ "type": "string" }));
+        assert_eq!(schemas["Pet"].get("$defs"), None);
+        assert_eq!(
+            schemas["Pet"]["properties"]["owner"],
+            json!({ "$ref": "#/components/schemas/Pet Owner" })
+        );
+        assert_eq!(
+            schemas["Pet"]["properties"]["tag"],
+            json!({ "$ref": "#/components/schemas/Tag" })
+        );
+    }
+
+    #[test]
+    fn prefix_items_are_recorded_as_tuples() {
+        let mut diagnostics = Diagnostics::new();
+        let document = downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "components": {
+                    "schemas": {
+                        "Point": { "prefixItems": [{ "type": "number" }, { "type": "number" }] }
+                    }
+                }
+            }),
+            &mut diagnostics,
+        );
+
+        assert_eq!(
+            document["components"]["schemas"]["Point"],
+            json!({ "type": "array", "items": {} })
+        );
+        assert_eq!(
+            document[TUPLES_EXTENSION]["/components/schemas/Point"],
+            json!([{ "type": "number" }, { "type": "number" }])
+        );
+    }
+
+    #[test]
+    fn webhooks_are_dropped_and_paths_added() {
+        let mut diagnostics = Diagnostics::new();
+        let document = downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "webhooks": { "newPet": {} }
+            }),
+            &mut diagnostics,
+        );
+
+        assert_eq!(document.get("webhooks"), None);
+        assert_eq!(document["paths"], json!({}));
+        assert_eq!(
+            messages(&diagnostics),
+            vec!["Webhooks are not supported, so no SDK code is generated for them."]
+        );
+    }
+
+    #[test]
+    fn const_is_reported() {
+        let mut diagnostics = Diagnostics::new();
+        downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "components": { "schemas": { "Kind": { "const": "dog" } } }
+            }),
+            &mut diagnostics,
+        );
+
+        let diagnostic = diagnostics.iter().next().unwrap();
+
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(
+            diagnostic.pointer.as_ref().unwrap().to_string(),
+            "/components/schemas/Kind/const"
+        );
+    }
+
+    #[test]
+    fn reports_defs_that_would_replace_a_schema() {
+        let mut diagnostics = Diagnostics::new();
+        let document = downgrade(
+            json!({
+                "openapi": "3.1.0",
+                "components": {
+                    "schemas": {
+                        "Owner": { "type": "object" },
+                        "Pet Owner": { "type": "integer" },
+                        "Pet": { "$defs": { "Owner": { "type": "string" } } }
+                    }
+                }
+            }),
+            &mut diagnostics,
+        );
+
+        assert_eq!(
+            messages(&diagnostics),
+            vec!["Could not move the definition to `components/schemas`, which already has a schema named 'Pet Owner'."]
+        );
+        assert!(diagnostics.has_errors());
+        assert_eq!(
+            document["components"]["schemas"]["Pet Owner"],
+            json!({ "type": "integer" })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use openapiv3::{OpenAPI as OpenApi, ReferenceOr, RequestBody};
+
+use crate::component::resolve_component;
+
+pub(crate) fn resolve_request_body(
+    api: &OpenApi,
+    request_body: ReferenceOr<RequestBody>,
+) -> Result<RequestBody, String> {
+    match request_body {
+        ReferenceOr::Item(request_body) => Ok(request_body),
+        ReferenceOr::Reference { reference } => resolve_component(
+            api,
+            "requestBodies",
+            "request body",
+            |components| &components.request_bodies,
+            &reference,
+        )
+        .map(|(_, request_body)| request_body),
+    }
+}
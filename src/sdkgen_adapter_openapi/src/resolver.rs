@@ -0,0 +1,348 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sdkgen_core::{Diagnostic, Diagnostics, JsonPointer};
+use serde_json::{json, Map, Value};
+
+/// Combines an API definition with the documents that its references point to, so that it refers
+/// only to itself.
+///
+/// Schemas from other documents are added to `components/schemas` and referred to by name, so
+/// that they can refer to each other, or to themselves. Anything else from another document, along
+/// with path items, is copied to where it is referred to.
+pub(crate) fn bundle(document: Value, path: &Path) -> Result<Value, Diagnostics> {
+    let root = canonical_path(path);
+
+    let mut resolver = ReferenceResolver {
+        root: root.clone(),
+        documents: HashMap::new(),
+        schema_names: HashMap::new(),
+        schemas: Map::new(),
+        taken_schema_names: document
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+            .map(|schemas| schemas.keys().cloned().collect())
+            .unwrap_or_default(),
+        diagnostics: Diagnostics::new(),
+    };
+
+    resolver.documents.insert(root.clone(), document.clone());
+
+    let mut document = document;
+    resolver.resolve_references(
+        &mut document,
+        &root,
+        &JsonPointer::root(),
+        false,
+        false,
+        &mut Vec::new(),
+    );
+
+    if !resolver.schemas.is_empty() {
+        let schemas = document
+            .as_object_mut()
+            .map(|object| object.entry("components").or_insert_with(|| json!({})))
+            .and_then(Value::as_object_mut)
+            .map(|components| components.entry("schemas").or_insert_with(|| json!({})))
+            .and_then(Value::as_object_mut);
+
+        if let Some(schemas) = schemas {
+            schemas.extend(resolver.schemas);
+        }
+    }
+
+    resolver.diagnostics.into_result(document)
+}
+
+/// The schema keywords whose values map names to schemas.
+const SCHEMA_MAP_KEYWORDS: &[&str] = &[
+    "properties",
+    "patternProperties",
+    "dependentSchemas",
+    "definitions",
+    "$defs",
+];
+
+/// The fields outside of schemas whose values map names, such as media types or status codes, to
+/// objects.
+const MAP_FIELDS: &[&str] = &[
+    "paths",
+    "webhooks",
+    "responses",
+    "content",
+    "headers",
+    "links",
+    "callbacks",
+    "encoding",
+    "variables",
+    "examples",
+    "scopes",
+];
+
+fn canonical_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_owned())
+}
+
+struct ReferenceResolver {
+    /// The path of the API definition being bundled.
+    root: PathBuf,
+    /// The documents that have been read, by their path.
+    documents: HashMap<PathBuf, Value>,
+    /// The names of the schemas from other documents, by their location.
+    schema_names: HashMap<String, String>,
+    /// The schemas from other documents, to be added to `components/schemas`.
+    schemas: Map<String, Value>,
+    taken_schema_names: HashSet<String>,
+    diagnostics: Diagnostics,
+}
+
+impl ReferenceResolver {
+    /// Resolves the references within the value, which came from `file` and is found at `pointer`
+    /// within the bundled document.
+    ///
+    /// `is_map` is whether the keys of the value are names, such as those of the properties of a
+    /// schema, rather than keywords. `copying` holds the locations of the references currently
+    /// being copied, to detect the references that would copy themselves forever.
+    fn resolve_references(
+        &mut self,
+        value: &mut Value,
+        file: &Path,
+        pointer: &JsonPointer,
+        is_schema: bool,
+        is_map: bool,
+        copying: &mut Vec<String>,
+    ) {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    let reference = reference.clone();
+
+                    self.resolve_reference(value, &reference, file, pointer, is_schema, copying);
+                    return;
+                }
+
+                let is_components = pointer.segments() == ["components"];
+
+                for (key, value) in object.iter_mut() {
+                    let child_pointer = pointer.join(key.clone());
+
+                    // Examples are literal values, where `$ref` has no special meaning. Properties
+                    // and other entries of maps can still be named like them.
+                    if !is_map && (key == "example" || (is_schema && key == "examples")) {
+                        continue;
+                    }
+
+                    let child_is_schema = is_schema
+                        || (!is_map && key == "schema")
+                        || child_pointer
+                            .segments()
+                            .starts_with(&["components".into(), "schemas".into()]);
+
+                    let child_is_map = !is_map
+                        && if is_schema {
+                            SCHEMA_MAP_KEYWORDS.contains(&key.as_str())
+                        } else {
+                            is_components || MAP_FIELDS.contains(&key.as_str())
+                        };
+
+                    self.resolve_references(
+                        value,
+                        file,
+                        &child_pointer,
+                        child_is_schema,
+                        child_is_map,
+                        copying,
+                    );
+                }
+            }
+            Value::Array(values) => {
+                for (index, value) in values.iter_mut().enumerate() {
+                    let pointer = pointer.join(index.to_string());
+
+                    self.resolve_references(value, file, &pointer, is_schema, false, copying);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn resolve_reference(
+        &mut self,
+        value: &mut Value,
+        reference: &str,
+        file: &Path,
+        pointer: &JsonPointer,
+        is_schema: bool,
+        copying: &mut Vec<String>,
+    ) {
+        let (target_file, fragment) = match reference.find('#') {
+            Some(index) => (&reference[..index], &reference[index + 1..]),
+            None => (reference, ""),
+        };
+
+        let target_file = if target_file.is_empty() {
+            file.to_owned()
+        } else {
+            canonical_path(
+                &file
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .join(target_file),
+            )
+        };
+
+        let target_pointer = JsonPointer::parse(fragment);
+        let location = format!("{}#{}", target_file.display(), target_pointer);
+
+        let is_path_item = pointer.segments().len() == 2 && pointer.segments()[0] == "paths";
+
+        if target_file == self.root && !is_path_item {
+            value["$ref"] = json!(format!("#{}", target_pointer));
+            return;
+        }
+
+        if is_schema {
+            let name = match self.schema_names.get(&location) {
+                Some(name) => name.clone(),
+                None => {
+                    let name = self.schema_name(&target_file, &target_pointer);
+                    self.schema_names.insert(location, name.clone());
+
+                    let schema_pointer =
+                        JsonPointer::parse("/components/schemas").join(name.clone());
+
+                    let schema = match self.load(&target_file, &target_pointer) {
+                        Ok(mut schema) => {
+                            self.resolve_references(
+                                &mut schema,
+                                &target_file,
+                                &schema_pointer,
+                                true,
+                                false,
+                                copying,
+                            );
+
+                            schema
+                        }
+                        Err(message) => {
+                            self.diagnostics
+                                .push(Diagnostic::error(message).with_pointer(pointer.clone()));
+
+                            json!({})
+                        }
+                    };
+
+                    self.schemas.insert(name.clone(), schema);
+
+                    name
+                }
+            };
+
+            *value = json!({
+                "$ref": format!("#{}", JsonPointer::parse("/components/schemas").join(name))
+            });
+            return;
+        }
+
+        if copying.contains(&location) {
+            copying.push(location);
+
+            self.diagnostics.push(
+                Diagnostic::error(format!(
+                    "The reference '{}' refers to itself: {}.",
+                    reference,
+                    copying.join(" -> ")
+                ))
+                .with_pointer(pointer.clone()),
+            );
+
+            copying.pop();
+            return;
+        }
+
+        match self.load(&target_file, &target_pointer) {
+            Ok(mut target) => {
+                copying.push(location);
+                self.resolve_references(&mut target, &target_file, pointer, false, false, copying);
+                copying.pop();
+
+                *value = target;
+            }
+            Err(message) => self
+                .diagnostics
+                .push(Diagnostic::error(message).with_pointer(pointer.clone())),
+        }
+    }
+
+    /// Chooses a name for the schema from another document, from the last segment of its location
+    /// or from the name of its document, qualified by the name of its document if the name is
+    /// taken.
+    fn schema_name(&mut self, file: &Path, pointer: &JsonPointer) -> String {
+        let file_stem = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let name = match pointer.segments().last() {
+            Some(segment) => segment.clone(),
+            None => file_stem.clone(),
+        };
+
+        let candidates = std::iter::once(name.clone())
+            .chain(std::iter::once(format!("{} {}", file_stem, name)))
+            .chain((2..).map(|number| format!("{} {} {}", file_stem, name, number)));
+
+        for candidate in candidates {
+            if self.taken_schema_names.insert(candidate.clone()) {
+                return candidate;
+            }
+        }
+
+        unreachable!("there are always more candidates")
+    }
+
+    /// Returns the value at the location within the document, reading the document if it has not
+    /// been read yet.
+    fn load(&mut self, file: &Path, pointer: &JsonPointer) -> Result<Value, String> {
+        if !self.documents.contains_key(file) {
+            let document = read_document(file)?;
+            self.documents.insert(file.to_owned(), document);
+        }
+
+        let mut value = &self.documents[file];
+
+        for segment in pointer.segments() {
+            let child = match value {
+                Value::Object(object) => object.get(segment),
+                Value::Array(values) => segment
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| values.get(index)),
+                _ => None,
+            };
+
+            value = child.ok_or_else(|| {
+                format!(
+                    "Could not resolve reference: '{}#{}'.",
+                    file.display(),
+                    pointer
+                )
+            })?;
+        }
+
+        Ok(value.clone())
+    }
+}
+
+fn read_document(file: &Path) -> Result<Value, String> {
+    let source = fs::read_to_string(file)
+        .map_err(|err| format!("Failed to read '{}': {}", file.display(), err))?;
+
+    let document = match file.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => serde_json::from_str(&source).map_err(|err| err.to_string()),
+        _ => serde_yaml::from_str(&source).map_err(|err| err.to_string()),
+    };
+
+    document.map_err(|err| format!("Failed to parse '{}': {}", file.display(), err))
+}
@@ -0,0 +1,706 @@
+use std::path::{Path, PathBuf};
+
+use sdkgen_core::{
+    Api, ApiKeyLocation, Diagnostics, PathFilter, Primitive, Route, SecuritySchemeKind, StatusCode,
+    Type,
+};
+
+fn api(openapi_yaml: &str) -> Api {
+    sdkgen_adapter_openapi::from_yaml(
+        openapi_yaml,
+        Path::new("openapi.yaml"),
+        &PathFilter::default(),
+    )
+    .unwrap_or_else(|diagnostics| panic!("{}", messages(&diagnostics).join("\n")))
+    .0
+}
+
+fn routes(openapi_yaml: &str) -> Vec<Route> {
+    api(openapi_yaml).routes
+}
+
+fn route(openapi_yaml: &str) -> Route {
+    routes(openapi_yaml).remove(0)
+}
+
+/// Wraps the schema as the body of a successful response to a single operation.
+fn response_spec(schema: &str) -> String {
+    format!(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: The pet.
+          content:
+            application/json:
+              schema:
+{}
+"#,
+        schema
+            .lines()
+            .map(|line| format!("                {}", line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+fn return_type(schema: &str) -> Type {
+    route(&response_spec(schema))
+        .return_type
+        .expect("the route returns a body")
+}
+
+fn members(ty: &Type) -> Vec<(&str, &Type)> {
+    match ty {
+        Type::Record { members, .. } => members
+            .iter()
+            .map(|member| (member.name.as_str(), &member.ty))
+            .collect(),
+        ty => panic!("Expected a record, found {:?}", ty),
+    }
+}
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn messages(diagnostics: &Diagnostics) -> Vec<String> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message.clone())
+        .collect()
+}
+
+#[test]
+fn reports_operations_without_an_id() {
+    let diagnostics = sdkgen_adapter_openapi::from_yaml(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      responses:
+        "204":
+          description: Nothing.
+"#,
+        Path::new("openapi.yaml"),
+        &PathFilter::default(),
+    )
+    .expect_err("the operation has no ID");
+
+    assert_eq!(messages(&diagnostics), vec!["No operation ID for '/pets'."]);
+    assert_eq!(
+        diagnostics
+            .iter()
+            .next()
+            .unwrap()
+            .pointer
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "/paths/~1pets/get"
+    );
+}
+
+#[test]
+fn inline_array_items_are_named_after_their_member() {
+    let api = api(r##"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: The pet.
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Pet"
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        colors:
+          type: array
+          items:
+            type: string
+            enum: [black, white]
+        sizes:
+          type: array
+          items:
+            type: integer
+            enum: [1, 2]
+        toys:
+          type: array
+          items:
+            type: object
+            properties:
+              name:
+                type: string
+        owners:
+          type: array
+          items:
+            oneOf:
+              - type: string
+              - type: object
+                properties:
+                  name:
+                    type: string
+"##);
+
+    let item_names: Vec<Option<&str>> = members(api.types.get("Pet").unwrap())
+        .into_iter()
+        .map(|(_, ty)| match ty {
+            Type::Array(item) => item.name(),
+            ty => panic!("Expected an array, found {:?}", ty),
+        })
+        .collect();
+
+    assert_eq!(
+        item_names,
+        vec![
+            Some("Pet colors item"),
+            Some("Pet sizes item"),
+            Some("Pet toys item"),
+            Some("Pet owners item"),
+        ]
+    );
+}
+
+#[test]
+fn operations_inherit_the_parameters_of_their_path() {
+    let route = route(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets/{id}:
+    parameters:
+      - name: id
+        in: path
+        required: true
+        schema:
+          type: string
+      - name: verbose
+        in: query
+        schema:
+          type: boolean
+    get:
+      operationId: getPet
+      parameters:
+        - name: verbose
+          in: query
+          required: true
+          schema:
+            type: boolean
+        - name: verbose
+          in: header
+          schema:
+            type: string
+      responses:
+        "204":
+          description: Nothing.
+"#,
+    );
+
+    let url_parameters: Vec<&str> = route
+        .url_parameters
+        .iter()
+        .map(|parameter| parameter.name.as_str())
+        .collect();
+    assert_eq!(url_parameters, vec!["id"]);
+    assert_eq!(route.query_parameters.len(), 1);
+    assert!(!route.query_parameters[0].is_optional);
+    assert_eq!(route.header_parameters.len(), 1);
+}
+
+#[test]
+fn additional_properties_become_maps() {
+    let ty = return_type(
+        "
+type: object
+properties:
+  scores:
+    type: object
+    additionalProperties:
+      type: integer
+  labels:
+    type: object
+    additionalProperties: true
+  metadata: {}
+",
+    );
+
+    let members = members(&ty);
+
+    assert!(matches!(
+        members[0].1,
+        Type::Map { key, value }
+            if matches!(**key, Type::Primitive(Primitive::String))
+                && matches!(**value, Type::Primitive(Primitive::Integer))
+    ));
+    assert!(matches!(
+        members[1].1,
+        Type::Map { value, .. } if matches!(**value, Type::Primitive(Primitive::Any))
+    ));
+    assert!(matches!(members[2].1, Type::Primitive(Primitive::Any)));
+}
+
+#[test]
+fn string_formats_map_to_primitives() {
+    let ty = return_type(
+        "
+type: object
+properties:
+  bornAt:
+    type: string
+    format: date-time
+  id:
+    type: string
+    format: uuid
+  price:
+    type: string
+    format: decimal
+  count:
+    type: string
+    format: int64
+  weight:
+    type: number
+    format: decimal
+",
+    );
+
+    let primitives: Vec<String> = members(&ty)
+        .into_iter()
+        .map(|(_, ty)| format!("{:?}", ty))
+        .collect();
+
+    assert_eq!(
+        primitives,
+        vec![
+            "Primitive(DateTime)",
+            "Primitive(Uuid)",
+            "Primitive(String)",
+            "Primitive(String)",
+            "Primitive(Decimal)",
+        ]
+    );
+}
+
+#[test]
+fn nullable_parameters_stay_required() {
+    let route = route(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      parameters:
+        - name: owner
+          in: query
+          required: true
+          schema:
+            type: string
+            nullable: true
+        - name: limit
+          in: query
+          schema:
+            type: integer
+      responses:
+        "204":
+          description: Nothing.
+"#,
+    );
+
+    let parameters: Vec<(&str, bool, bool)> = route
+        .query_parameters
+        .iter()
+        .map(|parameter| {
+            (
+                parameter.name.as_str(),
+                parameter.is_optional,
+                parameter.is_nullable,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        parameters,
+        vec![("owner", false, true), ("limit", true, false)]
+    );
+}
+
+#[test]
+fn responses_without_a_body_return_nothing() {
+    let route = route(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets/{petId}:
+    delete:
+      operationId: deletePet
+      parameters:
+        - name: petId
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        "204":
+          description: Deleted.
+        default:
+          description: An error.
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  message:
+                    type: string
+"#,
+    );
+
+    assert!(route.return_type.is_none());
+    assert!(route.responses[&StatusCode::Code(204)].is_none());
+    assert_eq!(route.url, "/pets/:petId");
+    assert_eq!(route.error_responses()[0].0, &StatusCode::Default);
+}
+
+#[test]
+fn servers_and_security_fall_back_to_those_of_the_api() {
+    let routes = routes(
+        r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+servers:
+  - url: https://api.example.com
+security:
+  - apiKey: []
+paths:
+  /pets:
+    servers:
+      - url: https://pets.example.com
+    get:
+      operationId: listPets
+      responses:
+        "204":
+          description: Nothing.
+    post:
+      operationId: createPet
+      servers:
+        - url: https://{region}.example.com
+          variables:
+            region:
+              default: eu
+              enum: [eu, us]
+      security:
+        - oauth: [write]
+      responses:
+        "204":
+          description: Nothing.
+  /health:
+    get:
+      operationId: getHealth
+      security: []
+      responses:
+        "204":
+          description: Nothing.
+components:
+  securitySchemes:
+    apiKey:
+      type: apiKey
+      in: header
+      name: X-Api-Key
+    oauth:
+      type: oauth2
+      flows:
+        clientCredentials:
+          tokenUrl: https://example.com/token
+          scopes:
+            write: Modify pets.
+"#,
+    );
+
+    let servers: Vec<Vec<String>> = routes
+        .iter()
+        .map(|route| {
+            route
+                .servers
+                .iter()
+                .map(|server| server.default_url())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        servers,
+        vec![
+            vec!["https://pets.example.com"],
+            vec!["https://eu.example.com"],
+            vec!["https://api.example.com"],
+        ]
+    );
+
+    let (scheme, scopes) = &routes[0].security[0].schemes[0];
+    assert_eq!(
+        scheme.kind,
+        SecuritySchemeKind::ApiKey {
+            location: ApiKeyLocation::Header,
+            parameter_name: "X-Api-Key".into(),
+        }
+    );
+    assert!(scopes.is_empty());
+
+    let (scheme, scopes) = &routes[1].security[0].schemes[0];
+    assert_eq!(scheme.name, "oauth");
+    assert_eq!(scopes, &vec!["write".to_string()]);
+
+    assert!(routes[2].security.is_empty());
+}
+
+#[test]
+fn paths_are_filtered() {
+    let spec = response_spec("type: string").replace("/pets:", "/internal/pets:");
+    let filter = PathFilter {
+        include: Vec::new(),
+        exclude: vec!["/internal/**".into()],
+    };
+
+    let (api, _) = sdkgen_adapter_openapi::from_yaml(&spec, Path::new("openapi.yaml"), &filter)
+        .expect("the API definition is valid");
+
+    assert!(api.routes.is_empty());
+}
+
+#[test]
+fn openapi31_documents_are_downgraded() {
+    let route = route(
+        r#"
+openapi: 3.1.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: The pet.
+          content:
+            application/json:
+              schema:
+                type: object
+                required: [name, nickname]
+                properties:
+                  name:
+                    type: string
+                  nickname:
+                    type: [string, "null"]
+                  position:
+                    prefixItems:
+                      - type: number
+                      - type: number
+"#,
+    );
+
+    let ty = route.return_type.expect("the route returns a body");
+
+    match &ty {
+        Type::Record { members, .. } => {
+            assert!(!members[0].is_nullable);
+            assert!(members[1].is_nullable);
+            assert!(!members[1].is_optional);
+            assert!(matches!(&members[2].ty, Type::Tuple(items) if items.len() == 2));
+        }
+        ty => panic!("Expected a record, found {:?}", ty),
+    }
+}
+
+#[test]
+fn openapi31_features_that_are_not_supported_are_reported() {
+    let (_, warnings) = sdkgen_adapter_openapi::from_yaml(
+        r#"
+openapi: 3.1.0
+info:
+  title: Pets
+  version: 1.0.0
+webhooks:
+  newPet: {}
+paths: {}
+"#,
+        Path::new("openapi.yaml"),
+        &PathFilter::default(),
+    )
+    .expect("webhooks are only warned about");
+
+    assert_eq!(
+        messages(&warnings),
+        vec!["Webhooks are not supported, so no SDK code is generated for them."]
+    );
+}
+
+#[test]
+fn members_keep_the_order_of_the_source() {
+    let ty = return_type(
+        "
+type: object
+properties:
+  name:
+    type: string
+  age:
+    type: integer
+  breed:
+    type: string
+",
+    );
+
+    let names: Vec<&str> = members(&ty).into_iter().map(|(name, _)| name).collect();
+
+    assert_eq!(names, vec!["name", "age", "breed"]);
+}
+
+#[test]
+fn named_schemas_are_referred_to_by_name() {
+    let spec = response_spec(r##"$ref: "#/components/schemas/Pet""##)
+        + r##"
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        id:
+          $ref: "#/components/schemas/PetId"
+        owner:
+          $ref: "#/components/schemas/Owner"
+        children:
+          type: array
+          items:
+            $ref: "#/components/schemas/Pet"
+    PetId:
+      type: string
+    Owner:
+      type: object
+      properties:
+        name:
+          type: string
+"##;
+
+    let api = api(&spec);
+
+    assert!(matches!(
+        &api.routes[0].return_type,
+        Some(Type::Reference(name)) if name == "Pet"
+    ));
+
+    let pet = api.types.get("Pet").expect("the pet is declared");
+    let members = members(pet);
+
+    // Schemas that do not declare a named type are used as they are.
+    assert!(matches!(members[0].1, Type::Primitive(Primitive::String)));
+    assert!(matches!(members[1].1, Type::Reference(name) if name == "Owner"));
+    assert!(matches!(
+        members[2].1,
+        Type::Array(item) if matches!(&**item, Type::Reference(name) if name == "Pet")
+    ));
+
+    let names: Vec<&str> = api.types.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["Pet", "Owner"]);
+}
+
+#[test]
+fn references_to_other_documents_are_bundled() {
+    let path = fixture("bundle/openapi.yaml");
+    let source = std::fs::read_to_string(&path).unwrap();
+
+    let (api, _) = sdkgen_adapter_openapi::from_yaml(&source, &path, &PathFilter::default())
+        .unwrap_or_else(|diagnostics| panic!("{}", messages(&diagnostics).join("\n")));
+    let routes = &api.routes;
+
+    let names: Vec<&str> = routes.iter().map(|route| route.name.as_str()).collect();
+    assert_eq!(names, vec!["listPets", "listOwners"]);
+
+    assert_eq!(routes[0].query_parameters[0].name, "limit");
+
+    let pet = match routes[0].return_type.as_ref() {
+        Some(Type::Array(pet)) => pet,
+        ty => panic!("Expected an array, found {:?}", ty),
+    };
+
+    // The root document already has a `Pet` schema, so the other one is qualified by its document.
+    assert_eq!(pet.name(), Some("pet Pet"));
+
+    let members = members(api.types.resolve(pet));
+    assert_eq!(members[1].1.name(), Some("owner"));
+    assert!(matches!(members[2].1, Type::Reference(name) if name == "pet Pet"));
+
+    match routes[1].return_type.as_ref() {
+        Some(Type::Array(owner)) => assert_eq!(owner.name(), Some("owner")),
+        ty => panic!("Expected an array, found {:?}", ty),
+    }
+}
+
+#[test]
+fn reports_references_that_refer_to_themselves() {
+    let path = fixture("bundle/cycle.yaml");
+    let source = std::fs::read_to_string(&path).unwrap();
+
+    let diagnostics = sdkgen_adapter_openapi::from_yaml(&source, &path, &PathFilter::default())
+        .expect_err("the reference never resolves");
+
+    let messages = messages(&diagnostics);
+
+    assert_eq!(messages.len(), 1);
+    assert!(
+        messages[0].starts_with("The reference '#/limit' refers to itself: "),
+        "{}",
+        messages[0]
+    );
+}
+
+#[test]
+fn properties_named_like_examples_are_bundled() {
+    let path = fixture("bundle/examples.yaml");
+    let source = std::fs::read_to_string(&path).unwrap();
+
+    let (api, _) = sdkgen_adapter_openapi::from_yaml(&source, &path, &PathFilter::default())
+        .unwrap_or_else(|diagnostics| panic!("{}", messages(&diagnostics).join("\n")));
+
+    let members = members(
+        api.types
+            .resolve(api.routes[0].return_type.as_ref().unwrap()),
+    );
+
+    assert_eq!(members[0].0, "example");
+    assert_eq!(members[0].1.name(), Some("owner"));
+    assert_eq!(members[1].0, "examples");
+    assert_eq!(members[1].1.name(), Some("owner"));
+}
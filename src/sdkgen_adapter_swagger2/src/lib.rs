@@ -0,0 +1,755 @@
+mod schema;
+mod source_map;
+mod swagger;
+
+use indexmap::IndexMap;
+use openapiv3::{
+    APIKeyLocation, Components, HeaderStyle, MediaType, OAuth2Flow, OAuth2Flows,
+    OpenAPI as OpenApi, Operation as OpenApiOperation, Parameter as OpenApiParameter,
+    ParameterData, ParameterSchemaOrContent, PathItem as OpenApiPathItem, PathStyle, QueryStyle,
+    ReferenceOr, RequestBody, Response as OpenApiResponse, Responses,
+    SecurityScheme as OpenApiSecurityScheme, Server, StatusCode,
+};
+use sdkgen_core::{Api, Diagnostic, Diagnostics, JsonPointer, PathFilter, Span};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::schema::convert_schema;
+use crate::source_map::SourceMap;
+use crate::swagger::{
+    ApiKeyLocation, OAuth2FlowKind, Operation, Parameter, ParameterLocation, PathItem, Response,
+    SecurityScheme, Swagger,
+};
+
+/// The fields that distinguish Swagger 2.0 documents from OpenAPI ones.
+#[derive(Deserialize)]
+struct VersionFields {
+    swagger: Option<String>,
+}
+
+/// Returns whether the JSON document is a Swagger 2.0 API definition.
+pub fn is_swagger2_json(source: &str) -> bool {
+    serde_json::from_str::<VersionFields>(source)
+        .ok()
+        .and_then(|fields| fields.swagger)
+        .is_some()
+}
+
+/// Returns whether the YAML document is a Swagger 2.0 API definition.
+pub fn is_swagger2_yaml(source: &str) -> bool {
+    serde_yaml::from_str::<VersionFields>(source)
+        .ok()
+        .and_then(|fields| fields.swagger)
+        .is_some()
+}
+
+/// Converts the operations of the Swagger document to routes and the types they refer to, returned
+/// along with any warnings.
+pub fn from_json(
+    swagger_json: &str,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let swagger: Swagger = serde_json::from_str(swagger_json).map_err(|err| {
+        let offset = offset_at(swagger_json, err.line(), err.column());
+
+        Diagnostic::error(format!("Failed to parse API definition: {}", err)).with_span(Span {
+            start: offset,
+            end: offset,
+        })
+    })?;
+
+    from_swagger(swagger, filter)
+}
+
+/// Converts the operations of the Swagger document to routes and the types they refer to, returned
+/// along with any warnings.
+pub fn from_yaml(
+    swagger_yaml: &str,
+    filter: &PathFilter,
+) -> Result<(Api, Diagnostics), Diagnostics> {
+    let swagger: Swagger = serde_yaml::from_str(swagger_yaml).map_err(|err| {
+        let diagnostic = Diagnostic::error(format!("Failed to parse API definition: {}", err));
+
+        match err.location() {
+            Some(location) => diagnostic.with_span(Span {
+                start: location.index(),
+                end: location.index(),
+            }),
+            None => diagnostic,
+        }
+    })?;
+
+    from_swagger(swagger, filter)
+}
+
+/// Returns the byte offset of the given one-based line and column within the source.
+fn offset_at(source: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+
+    (line_start + column.saturating_sub(1)).min(source.len())
+}
+
+/// A location in the Swagger document, along with the corresponding location in the OpenAPI
+/// document it is converted to.
+#[derive(Debug, Clone)]
+struct Location {
+    swagger: JsonPointer,
+    openapi: JsonPointer,
+}
+
+impl Location {
+    fn join<S: Into<String> + Clone>(&self, segment: S) -> Self {
+        Self {
+            swagger: self.swagger.join(segment.clone()),
+            openapi: self.openapi.join(segment),
+        }
+    }
+}
+
+/// Converts the Swagger document to an OpenAPI document and reads the routes from it, so that
+/// both produce the same routes and types.
+fn from_swagger(swagger: Swagger, filter: &PathFilter) -> Result<(Api, Diagnostics), Diagnostics> {
+    if !swagger.swagger.starts_with("2.") {
+        return Err(Diagnostic::error(format!(
+            "Unsupported Swagger version: '{}'.",
+            swagger.swagger
+        ))
+        .with_pointer(JsonPointer::root().join("swagger"))
+        .into());
+    }
+
+    let mut diagnostics = Diagnostics::new();
+    let mut source_map = SourceMap::new();
+
+    let openapi = swagger_to_openapi(&swagger, &mut diagnostics, &mut source_map);
+
+    match sdkgen_adapter_openapi::from_openapi(openapi, filter) {
+        Ok((api, warnings)) => {
+            diagnostics.extend(
+                warnings
+                    .into_iter()
+                    .map(|diagnostic| source_map.to_swagger_diagnostic(diagnostic)),
+            );
+
+            diagnostics.into_result_with_warnings(api)
+        }
+        Err(openapi_diagnostics) => {
+            diagnostics.extend(
+                openapi_diagnostics
+                    .into_iter()
+                    .map(|diagnostic| source_map.to_swagger_diagnostic(diagnostic)),
+            );
+
+            Err(diagnostics)
+        }
+    }
+}
+
+fn swagger_to_openapi(
+    swagger: &Swagger,
+    diagnostics: &mut Diagnostics,
+    source_map: &mut SourceMap,
+) -> OpenApi {
+    // The base path is prepended to the paths, rather than added to the servers, so that the URLs
+    // of the routes are complete.
+    let base_path = swagger
+        .base_path
+        .as_deref()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+
+    let mut paths = IndexMap::new();
+
+    for (path, reference_or_path_item) in swagger.paths.iter() {
+        let full_path = format!("{}{}", base_path, path);
+
+        let location = Location {
+            swagger: JsonPointer::root().join("paths").join(path.clone()),
+            openapi: JsonPointer::root().join("paths").join(full_path.clone()),
+        };
+
+        source_map.insert(location.openapi.clone(), location.swagger.clone());
+
+        let path_item = match reference_or_path_item {
+            ReferenceOr::Reference { reference } => ReferenceOr::Reference {
+                reference: reference.clone(),
+            },
+            ReferenceOr::Item(path_item) => ReferenceOr::Item(path_item_to_openapi(
+                swagger,
+                diagnostics,
+                source_map,
+                &location,
+                path_item,
+            )),
+        };
+
+        paths.insert(full_path, path_item);
+    }
+
+    let mut schemas = IndexMap::new();
+
+    for (name, schema) in swagger.definitions.iter() {
+        let pointer = JsonPointer::root().join("definitions").join(name.clone());
+
+        source_map.insert(
+            JsonPointer::root()
+                .join("components")
+                .join("schemas")
+                .join(name.clone()),
+            pointer.clone(),
+        );
+
+        match convert_schema(schema.clone()) {
+            Ok(schema) => {
+                schemas.insert(name.clone(), schema);
+            }
+            Err(message) => diagnostics.push(Diagnostic::error(message).with_pointer(pointer)),
+        }
+    }
+
+    let mut security_schemes = IndexMap::new();
+
+    for (name, scheme) in swagger.security_definitions.iter() {
+        let pointer = JsonPointer::root()
+            .join("securityDefinitions")
+            .join(name.clone());
+
+        source_map.insert(
+            JsonPointer::root()
+                .join("components")
+                .join("securitySchemes")
+                .join(name.clone()),
+            pointer.clone(),
+        );
+
+        match security_scheme_to_openapi(scheme) {
+            Ok(scheme) => {
+                security_schemes.insert(name.clone(), ReferenceOr::Item(scheme));
+            }
+            Err(message) => diagnostics.push(Diagnostic::error(message).with_pointer(pointer)),
+        }
+    }
+
+    let servers = swagger
+        .host
+        .iter()
+        .flat_map(|host| {
+            let schemes = if swagger.schemes.is_empty() {
+                vec!["https".to_owned()]
+            } else {
+                swagger.schemes.clone()
+            };
+
+            schemes.into_iter().map(move |scheme| Server {
+                url: format!("{}://{}", scheme, host),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    OpenApi {
+        openapi: "3.0.0".into(),
+        info: swagger.info.clone(),
+        servers,
+        paths,
+        components: Some(Components {
+            schemas,
+            security_schemes,
+            ..Default::default()
+        }),
+        security: swagger.security.clone(),
+        ..Default::default()
+    }
+}
+
+/// Converts a security scheme, which OAuth 2.0 schemes in Swagger do with a single flow.
+fn security_scheme_to_openapi(scheme: &SecurityScheme) -> Result<OpenApiSecurityScheme, String> {
+    match scheme {
+        SecurityScheme::Basic => Ok(OpenApiSecurityScheme::HTTP {
+            scheme: "basic".into(),
+            bearer_format: None,
+        }),
+        SecurityScheme::ApiKey { name, location } => Ok(OpenApiSecurityScheme::APIKey {
+            location: match location {
+                ApiKeyLocation::Query => APIKeyLocation::Query,
+                ApiKeyLocation::Header => APIKeyLocation::Header,
+            },
+            name: name.clone(),
+        }),
+        SecurityScheme::Oauth2 {
+            flow,
+            authorization_url,
+            token_url,
+            scopes,
+        } => {
+            let url = |url: &Option<String>, field: &str| {
+                url.clone()
+                    .ok_or_else(|| format!("Missing '{}' for the OAuth 2.0 flow.", field))
+            };
+
+            let mut flows = OAuth2Flows::default();
+
+            match flow {
+                OAuth2FlowKind::Implicit => {
+                    flows.implicit = Some(OAuth2Flow::Implicit {
+                        authorization_url: url(authorization_url, "authorizationUrl")?,
+                        refresh_url: None,
+                        scopes: scopes.clone(),
+                    })
+                }
+                OAuth2FlowKind::Password => {
+                    flows.password = Some(OAuth2Flow::Password {
+                        refresh_url: None,
+                        token_url: url(token_url, "tokenUrl")?,
+                        scopes: scopes.clone(),
+                    })
+                }
+                OAuth2FlowKind::Application => {
+                    flows.client_credentials = Some(OAuth2Flow::ClientCredentials {
+                        refresh_url: None,
+                        token_url: url(token_url, "tokenUrl")?,
+                        scopes: scopes.clone(),
+                    })
+                }
+                OAuth2FlowKind::AccessCode => {
+                    flows.authorization_code = Some(OAuth2Flow::AuthorizationCode {
+                        authorization_url: url(authorization_url, "authorizationUrl")?,
+                        token_url: url(token_url, "tokenUrl")?,
+                        refresh_url: None,
+                        scopes: scopes.clone(),
+                    })
+                }
+            }
+
+            Ok(OpenApiSecurityScheme::OAuth2 { flows })
+        }
+    }
+}
+
+fn path_item_to_openapi(
+    swagger: &Swagger,
+    diagnostics: &mut Diagnostics,
+    source_map: &mut SourceMap,
+    location: &Location,
+    path_item: &PathItem,
+) -> OpenApiPathItem {
+    let mut convert_operation = |method: &str, operation: &Option<Operation>| {
+        operation.as_ref().map(|operation| {
+            operation_to_openapi(
+                swagger,
+                diagnostics,
+                source_map,
+                location,
+                method,
+                &path_item.parameters,
+                operation,
+            )
+        })
+    };
+
+    OpenApiPathItem {
+        get: convert_operation("get", &path_item.get),
+        put: convert_operation("put", &path_item.put),
+        post: convert_operation("post", &path_item.post),
+        delete: convert_operation("delete", &path_item.delete),
+        options: convert_operation("options", &path_item.options),
+        head: convert_operation("head", &path_item.head),
+        patch: convert_operation("patch", &path_item.patch),
+        ..Default::default()
+    }
+}
+
+/// Converts an operation, along with the parameters shared by the operations on its path.
+///
+/// Body and form data parameters become the request body of the operation.
+fn operation_to_openapi(
+    swagger: &Swagger,
+    diagnostics: &mut Diagnostics,
+    source_map: &mut SourceMap,
+    path_location: &Location,
+    method: &str,
+    path_parameters: &[ReferenceOr<Parameter>],
+    operation: &Operation,
+) -> OpenApiOperation {
+    let location = path_location.join(method);
+
+    let consumes = operation.consumes.as_ref().unwrap_or(&swagger.consumes);
+    let produces = operation.produces.as_ref().unwrap_or(&swagger.produces);
+
+    // Operations can override the parameters of the path with a parameter with the same name and
+    // location.
+    let mut parameters: Vec<(JsonPointer, Parameter)> = Vec::new();
+
+    let all_parameters = path_parameters
+        .iter()
+        .enumerate()
+        .map(|(index, parameter)| {
+            (
+                path_location
+                    .swagger
+                    .join("parameters")
+                    .join(index.to_string()),
+                parameter,
+            )
+        })
+        .chain(
+            operation
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(index, parameter)| {
+                    (
+                        location.swagger.join("parameters").join(index.to_string()),
+                        parameter,
+                    )
+                }),
+        );
+
+    for (pointer, parameter) in all_parameters {
+        match resolve_parameter(swagger, pointer, parameter) {
+            Ok((pointer, parameter)) => {
+                parameters.retain(|(_, existing)| {
+                    existing.name != parameter.name || existing.location != parameter.location
+                });
+                parameters.push((pointer, parameter));
+            }
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+
+    let mut openapi_parameters = Vec::new();
+    let mut request_body = None;
+    let mut form_properties = Map::new();
+    let mut required_form_properties = Vec::new();
+
+    for (pointer, parameter) in parameters {
+        match parameter.location {
+            ParameterLocation::Body => {
+                let body_schema = match parameter.schema.clone().map(convert_schema) {
+                    Some(Ok(schema)) => Some(schema),
+                    Some(Err(message)) => {
+                        diagnostics
+                            .push(Diagnostic::error(message).with_pointer(pointer.join("schema")));
+
+                        None
+                    }
+                    None => None,
+                };
+
+                let media_types = media_types_or(consumes, "application/json");
+
+                for media_type in media_types.iter() {
+                    source_map.insert(
+                        location
+                            .openapi
+                            .join("requestBody")
+                            .join("content")
+                            .join(media_type.clone())
+                            .join("schema"),
+                        pointer.join("schema"),
+                    );
+                }
+
+                source_map.insert(location.openapi.join("requestBody"), pointer);
+
+                request_body = Some(ReferenceOr::Item(RequestBody {
+                    description: parameter.description,
+                    content: media_types
+                        .into_iter()
+                        .map(|media_type| {
+                            (
+                                media_type,
+                                MediaType {
+                                    schema: body_schema.clone(),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect(),
+                    required: parameter.required,
+                    ..Default::default()
+                }));
+            }
+            ParameterLocation::FormData => {
+                for media_type in media_types_or(consumes, "application/x-www-form-urlencoded") {
+                    source_map.insert(
+                        location
+                            .openapi
+                            .join("requestBody")
+                            .join("content")
+                            .join(media_type)
+                            .join("schema")
+                            .join("properties")
+                            .join(parameter.name.clone()),
+                        pointer.clone(),
+                    );
+                }
+
+                if parameter.required {
+                    required_form_properties.push(Value::String(parameter.name.clone()));
+                }
+
+                form_properties.insert(
+                    parameter.name.clone(),
+                    Value::Object(parameter.schema_properties),
+                );
+            }
+            ParameterLocation::Query | ParameterLocation::Header | ParameterLocation::Path => {
+                let openapi_parameter_pointer = location
+                    .openapi
+                    .join("parameters")
+                    .join(openapi_parameters.len().to_string());
+
+                // The schema of the parameter is written inline in Swagger.
+                source_map.insert(openapi_parameter_pointer.join("schema"), pointer.clone());
+                source_map.insert(openapi_parameter_pointer, pointer.clone());
+
+                match parameter_to_openapi(parameter) {
+                    Ok(parameter) => openapi_parameters.push(ReferenceOr::Item(parameter)),
+                    Err(message) => {
+                        diagnostics.push(Diagnostic::error(message).with_pointer(pointer))
+                    }
+                }
+            }
+        }
+    }
+
+    if !form_properties.is_empty() {
+        let form_schema = serde_json::json!({
+            "type": "object",
+            "properties": form_properties,
+            "required": required_form_properties,
+        });
+
+        match convert_schema(form_schema) {
+            Ok(schema) => {
+                request_body = Some(ReferenceOr::Item(RequestBody {
+                    content: media_types_or(consumes, "application/x-www-form-urlencoded")
+                        .into_iter()
+                        .map(|media_type| {
+                            (
+                                media_type,
+                                MediaType {
+                                    schema: Some(schema.clone()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect(),
+                    required: true,
+                    ..Default::default()
+                }))
+            }
+            Err(message) => diagnostics
+                .push(Diagnostic::error(message).with_pointer(location.swagger.join("parameters"))),
+        }
+    }
+
+    let mut responses = Responses::default();
+
+    for (status, response) in operation.responses.iter() {
+        let pointer = location.swagger.join("responses").join(status.clone());
+        let openapi_response_pointer = location.openapi.join("responses").join(status.clone());
+
+        let (pointer, response) = match resolve_response(swagger, pointer, response) {
+            Ok(response) => response,
+            Err(diagnostic) => {
+                diagnostics.push(diagnostic);
+                continue;
+            }
+        };
+
+        source_map.insert(openapi_response_pointer.clone(), pointer.clone());
+
+        let response = match response_to_openapi(
+            source_map,
+            &Location {
+                swagger: pointer.clone(),
+                openapi: openapi_response_pointer,
+            },
+            produces,
+            response,
+        ) {
+            Ok(response) => ReferenceOr::Item(response),
+            Err(message) => {
+                diagnostics.push(Diagnostic::error(message).with_pointer(pointer.join("schema")));
+                continue;
+            }
+        };
+
+        if status == "default" {
+            responses.default = Some(response);
+        } else {
+            match status.parse() {
+                Ok(code) => {
+                    responses.responses.insert(StatusCode::Code(code), response);
+                }
+                Err(_) => diagnostics.push(
+                    Diagnostic::error(format!("Invalid status code: '{}'.", status))
+                        .with_pointer(pointer),
+                ),
+            }
+        }
+    }
+
+    OpenApiOperation {
+        tags: operation.tags.clone(),
+        summary: operation.summary.clone(),
+        description: operation.description.clone(),
+        operation_id: operation.operation_id.clone(),
+        parameters: openapi_parameters,
+        request_body,
+        responses,
+        deprecated: operation.deprecated,
+        security: operation.security.clone(),
+        ..Default::default()
+    }
+}
+
+/// Returns the media types, or the given media type if there are none.
+fn media_types_or(media_types: &[String], default: &str) -> Vec<String> {
+    if media_types.is_empty() {
+        vec![default.to_owned()]
+    } else {
+        media_types.to_vec()
+    }
+}
+
+/// Resolves a reference to one of the parameters of the API definition, returning it along with
+/// its location.
+fn resolve_parameter(
+    swagger: &Swagger,
+    pointer: JsonPointer,
+    parameter: &ReferenceOr<Parameter>,
+) -> Result<(JsonPointer, Parameter), Diagnostic> {
+    match parameter {
+        ReferenceOr::Item(parameter) => Ok((pointer, parameter.clone())),
+        ReferenceOr::Reference { reference } => reference
+            .strip_prefix("#/parameters/")
+            .and_then(|name| {
+                swagger.parameters.get(name).map(|parameter| {
+                    (
+                        JsonPointer::root().join("parameters").join(name),
+                        parameter.clone(),
+                    )
+                })
+            })
+            .ok_or_else(|| {
+                Diagnostic::error(format!(
+                    "Could not resolve parameter reference: '{}'.",
+                    reference
+                ))
+                .with_pointer(pointer)
+            }),
+    }
+}
+
+/// Resolves a reference to one of the responses of the API definition, returning it along with
+/// its location.
+fn resolve_response(
+    swagger: &Swagger,
+    pointer: JsonPointer,
+    response: &ReferenceOr<Response>,
+) -> Result<(JsonPointer, Response), Diagnostic> {
+    match response {
+        ReferenceOr::Item(response) => Ok((pointer, response.clone())),
+        ReferenceOr::Reference { reference } => reference
+            .strip_prefix("#/responses/")
+            .and_then(|name| {
+                swagger.responses.get(name).map(|response| {
+                    (
+                        JsonPointer::root().join("responses").join(name),
+                        response.clone(),
+                    )
+                })
+            })
+            .ok_or_else(|| {
+                Diagnostic::error(format!(
+                    "Could not resolve response reference: '{}'.",
+                    reference
+                ))
+                .with_pointer(pointer)
+            }),
+    }
+}
+
+/// Converts a query, header or path parameter.
+fn parameter_to_openapi(parameter: Parameter) -> Result<OpenApiParameter, String> {
+    let schema = convert_schema(Value::Object(parameter.schema_properties))?;
+
+    let parameter_data = ParameterData {
+        name: parameter.name,
+        description: parameter.description,
+        required: parameter.required,
+        deprecated: None,
+        format: ParameterSchemaOrContent::Schema(schema),
+        example: None,
+        examples: IndexMap::new(),
+        explode: parameter
+            .collection_format
+            .map(|collection_format| collection_format == "multi"),
+        extensions: IndexMap::new(),
+    };
+
+    Ok(match parameter.location {
+        ParameterLocation::Header => OpenApiParameter::Header {
+            parameter_data,
+            style: HeaderStyle::Simple,
+        },
+        ParameterLocation::Path => OpenApiParameter::Path {
+            parameter_data,
+            style: PathStyle::Simple,
+        },
+        ParameterLocation::Query => OpenApiParameter::Query {
+            parameter_data,
+            allow_reserved: false,
+            style: QueryStyle::Form,
+            allow_empty_value: None,
+        },
+        ParameterLocation::FormData | ParameterLocation::Body => {
+            unreachable!("Body and form data parameters are converted to request bodies")
+        }
+    })
+}
+
+fn response_to_openapi(
+    source_map: &mut SourceMap,
+    location: &Location,
+    produces: &[String],
+    response: Response,
+) -> Result<OpenApiResponse, String> {
+    let mut content = IndexMap::new();
+
+    if let Some(schema) = response.schema {
+        let schema = convert_schema(schema)?;
+
+        for media_type in media_types_or(produces, "application/json") {
+            source_map.insert(
+                location
+                    .openapi
+                    .join("content")
+                    .join(media_type.clone())
+                    .join("schema"),
+                location.swagger.join("schema"),
+            );
+
+            content.insert(
+                media_type,
+                MediaType {
+                    schema: Some(schema.clone()),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    Ok(OpenApiResponse {
+        description: response.description,
+        content,
+        ..Default::default()
+    })
+}
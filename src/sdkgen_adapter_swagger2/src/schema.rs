@@ -0,0 +1,62 @@
+use openapiv3::{ReferenceOr, Schema};
+use serde_json::{json, Value};
+
+/// Converts a Swagger schema to an OpenAPI schema.
+pub(crate) fn convert_schema(schema: Value) -> Result<ReferenceOr<Schema>, String> {
+    serde_json::from_value(rewrite_schema(schema)).map_err(|err| format!("Invalid schema: {}", err))
+}
+
+/// Rewrites the parts of a Swagger schema, and of the schemas nested within it, that differ from
+/// OpenAPI.
+///
+/// Only schema keywords are rewritten, so properties and examples named like them are left alone.
+fn rewrite_schema(mut schema: Value) -> Value {
+    let object = match &mut schema {
+        Value::Object(object) => object,
+        _ => return schema,
+    };
+
+    // Files are the binary strings of OpenAPI.
+    if object.get("type") == Some(&json!("file")) {
+        object.insert("type".into(), json!("string"));
+        object.insert("format".into(), json!("binary"));
+    }
+
+    if let Some(Value::String(reference)) = object.get_mut("$ref") {
+        *reference = reference.replacen("#/definitions/", "#/components/schemas/", 1);
+    }
+
+    if let Some(Value::String(property_name)) = object.get("discriminator") {
+        let discriminator = json!({ "propertyName": property_name });
+
+        object.insert("discriminator".into(), discriminator);
+    }
+
+    if let Some(nullable) = object.remove("x-nullable") {
+        object.insert("nullable".into(), nullable);
+    }
+
+    for keyword in &["items", "additionalProperties", "not"] {
+        if let Some(subschema) = object.get_mut(*keyword) {
+            if subschema.is_object() {
+                *subschema = rewrite_schema(subschema.take());
+            }
+        }
+    }
+
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        for property in properties.values_mut() {
+            *property = rewrite_schema(property.take());
+        }
+    }
+
+    for keyword in &["allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(subschemas)) = object.get_mut(*keyword) {
+            for subschema in subschemas.iter_mut() {
+                *subschema = rewrite_schema(subschema.take());
+            }
+        }
+    }
+
+    schema
+}
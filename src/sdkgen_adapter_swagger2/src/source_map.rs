@@ -0,0 +1,56 @@
+use sdkgen_core::{Diagnostic, JsonPointer};
+
+/// The locations in the Swagger document that parts of the converted OpenAPI document came from,
+/// so that diagnostics refer to the document that was read.
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    locations: Vec<(JsonPointer, JsonPointer)>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self {
+            locations: Vec::new(),
+        }
+    }
+
+    /// Records that the location in the OpenAPI document came from the location in the Swagger
+    /// document, along with everything within it.
+    pub fn insert(&mut self, openapi_pointer: JsonPointer, swagger_pointer: JsonPointer) {
+        self.locations.push((openapi_pointer, swagger_pointer));
+    }
+
+    /// Returns the location in the Swagger document that the location in the OpenAPI document
+    /// came from, using the most specific location recorded for it.
+    pub fn to_swagger_pointer(&self, pointer: &JsonPointer) -> JsonPointer {
+        self.locations
+            .iter()
+            .filter_map(|(openapi_pointer, swagger_pointer)| {
+                pointer
+                    .strip_prefix(openapi_pointer)
+                    .map(|rest| (openapi_pointer.segments().len(), swagger_pointer, rest))
+            })
+            .max_by_key(|(length, ..)| *length)
+            .map(|(_, swagger_pointer, rest)| {
+                rest.iter()
+                    .fold(swagger_pointer.clone(), |pointer, segment| {
+                        pointer.join(segment.clone())
+                    })
+            })
+            .unwrap_or_else(|| pointer.clone())
+    }
+
+    /// Rewrites the diagnostic to refer to the Swagger document, including any references to
+    /// schemas in its message.
+    pub fn to_swagger_diagnostic(&self, mut diagnostic: Diagnostic) -> Diagnostic {
+        diagnostic.message = diagnostic
+            .message
+            .replace("#/components/schemas/", "#/definitions/")
+            .replace("#/components/securitySchemes/", "#/securityDefinitions/");
+        diagnostic.pointer = diagnostic
+            .pointer
+            .map(|pointer| self.to_swagger_pointer(&pointer));
+
+        diagnostic
+    }
+}
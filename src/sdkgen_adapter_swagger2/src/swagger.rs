@@ -0,0 +1,189 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use openapiv3::{Info, ReferenceOr};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// A Swagger 2.0 API definition.
+///
+/// Schemas are kept as JSON values, since they are converted to OpenAPI schemas by rewriting them.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Swagger {
+    pub swagger: String,
+    pub info: Info,
+    pub host: Option<String>,
+    pub base_path: Option<String>,
+    #[serde(default)]
+    pub schemes: Vec<String>,
+    #[serde(default)]
+    pub consumes: Vec<String>,
+    #[serde(default)]
+    pub produces: Vec<String>,
+    pub paths: IndexMap<String, ReferenceOr<PathItem>>,
+    #[serde(default)]
+    pub definitions: IndexMap<String, Value>,
+    #[serde(default)]
+    pub parameters: IndexMap<String, Parameter>,
+    #[serde(default)]
+    pub responses: IndexMap<String, Response>,
+    #[serde(default)]
+    pub security_definitions: IndexMap<String, SecurityScheme>,
+    /// The security requirements of the operations that do not have their own.
+    pub security: Option<Vec<SecurityRequirement>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PathItem {
+    pub get: Option<Operation>,
+    pub put: Option<Operation>,
+    pub post: Option<Operation>,
+    pub delete: Option<Operation>,
+    pub options: Option<Operation>,
+    pub head: Option<Operation>,
+    pub patch: Option<Operation>,
+    /// The parameters shared by all of the operations on the path.
+    #[serde(default)]
+    pub parameters: Vec<ReferenceOr<Parameter>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub operation_id: Option<String>,
+    /// The media types of the request body, instead of those of the API definition.
+    pub consumes: Option<Vec<String>>,
+    /// The media types of the responses, instead of those of the API definition.
+    pub produces: Option<Vec<String>>,
+    #[serde(default)]
+    pub parameters: Vec<ReferenceOr<Parameter>>,
+    #[serde(deserialize_with = "deserialize_responses")]
+    pub responses: IndexMap<String, ReferenceOr<Response>>,
+    #[serde(default)]
+    pub deprecated: bool,
+    pub security: Option<Vec<SecurityRequirement>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterLocation {
+    Query,
+    Header,
+    Path,
+    FormData,
+    Body,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "in")]
+    pub location: ParameterLocation,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// The schema of a body parameter.
+    pub schema: Option<Value>,
+    pub collection_format: Option<String>,
+    /// The schema properties of any other parameter, such as `type`, `format` and `items`.
+    #[serde(flatten)]
+    pub schema_properties: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response {
+    #[serde(default)]
+    pub description: String,
+    pub schema: Option<Value>,
+}
+
+/// The names of the security schemes that must all be used, along with the scopes they need.
+pub type SecurityRequirement = IndexMap<String, Vec<String>>;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SecurityScheme {
+    Basic,
+    ApiKey {
+        name: String,
+        #[serde(rename = "in")]
+        location: ApiKeyLocation,
+    },
+    #[serde(rename_all = "camelCase")]
+    Oauth2 {
+        flow: OAuth2FlowKind,
+        authorization_url: Option<String>,
+        token_url: Option<String>,
+        #[serde(default)]
+        scopes: IndexMap<String, String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiKeyLocation {
+    Query,
+    Header,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OAuth2FlowKind {
+    Implicit,
+    Password,
+    /// The client credentials flow.
+    Application,
+    /// The authorization code flow.
+    AccessCode,
+}
+
+/// Deserializes the responses of an operation, keyed by status codes that YAML reads as integers
+/// unless they are quoted.
+fn deserialize_responses<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<String, ReferenceOr<Response>>, D::Error> {
+    #[derive(PartialEq, Eq, Hash)]
+    struct StatusCode(String);
+
+    struct StatusCodeVisitor;
+
+    impl<'de> Visitor<'de> for StatusCodeVisitor {
+        type Value = StatusCode;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a status code")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<StatusCode, E> {
+            Ok(StatusCode(value.to_owned()))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<StatusCode, E> {
+            Ok(StatusCode(value.to_string()))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<StatusCode, E> {
+            Ok(StatusCode(value.to_string()))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for StatusCode {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(StatusCodeVisitor)
+        }
+    }
+
+    Ok(
+        IndexMap::<StatusCode, ReferenceOr<Response>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(status, response)| (status.0, response))
+            .collect(),
+    )
+}
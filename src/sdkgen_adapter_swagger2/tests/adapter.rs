@@ -0,0 +1,334 @@
+use sdkgen_core::{
+    Api, ApiKeyLocation, Diagnostics, MediaType, OAuth2FlowKind, PathFilter, Route,
+    SecuritySchemeKind, StatusCode, Type,
+};
+
+fn api(swagger_yaml: &str) -> Api {
+    sdkgen_adapter_swagger2::from_yaml(swagger_yaml, &PathFilter::default())
+        .unwrap_or_else(|diagnostics| panic!("{}", messages(&diagnostics).join("\n")))
+        .0
+}
+
+fn routes(swagger_yaml: &str) -> Vec<Route> {
+    api(swagger_yaml).routes
+}
+
+fn messages(diagnostics: &Diagnostics) -> Vec<String> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message.clone())
+        .collect()
+}
+
+const PETSTORE: &str = r##"
+swagger: "2.0"
+info:
+  title: Pets
+  version: 1.0.0
+host: api.example.com
+basePath: /v1/
+schemes: [https, http]
+securityDefinitions:
+  apiKey:
+    type: apiKey
+    in: query
+    name: api_key
+  basic:
+    type: basic
+  oauth:
+    type: oauth2
+    flow: accessCode
+    authorizationUrl: https://example.com/authorize
+    tokenUrl: https://example.com/token
+    scopes:
+      write: Modify pets.
+security:
+  - apiKey: []
+paths:
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        type: string
+    get:
+      operationId: getPet
+      responses:
+        200:
+          description: The pet.
+          schema:
+            $ref: "#/definitions/Pet"
+        404:
+          description: Not found.
+          schema:
+            $ref: "#/definitions/Error"
+    put:
+      operationId: updatePet
+      security:
+        - oauth: [write]
+        - basic: []
+      parameters:
+        - name: pet
+          in: body
+          required: true
+          schema:
+            $ref: "#/definitions/Pet"
+      responses:
+        204:
+          description: Updated.
+  /pets/{petId}/photo:
+    post:
+      operationId: uploadPhoto
+      consumes: [multipart/form-data]
+      parameters:
+        - name: petId
+          in: path
+          required: true
+          type: string
+        - name: photo
+          in: formData
+          required: true
+          type: file
+        - name: caption
+          in: formData
+          type: string
+      responses:
+        "201":
+          description: Uploaded.
+definitions:
+  Pet:
+    type: object
+    required: [name]
+    properties:
+      name:
+        type: string
+      tag:
+        type: string
+  Error:
+    type: object
+    properties:
+      message:
+        type: string
+"##;
+
+#[test]
+fn base_path_is_prepended_and_host_becomes_servers() {
+    let routes = routes(PETSTORE);
+
+    let urls: Vec<&str> = routes.iter().map(|route| route.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "/v1/pets/:petId",
+            "/v1/pets/:petId",
+            "/v1/pets/:petId/photo"
+        ]
+    );
+
+    let servers: Vec<&str> = routes[0]
+        .servers
+        .iter()
+        .map(|server| server.url.as_str())
+        .collect();
+    assert_eq!(
+        servers,
+        vec!["https://api.example.com", "http://api.example.com"]
+    );
+}
+
+#[test]
+fn unquoted_status_codes_are_read() {
+    let route = routes(PETSTORE).remove(0);
+
+    let statuses: Vec<&StatusCode> = route.responses.keys().collect();
+    assert_eq!(
+        statuses,
+        vec![&StatusCode::Code(200), &StatusCode::Code(404)]
+    );
+    assert_eq!(route.return_type.as_ref().and_then(Type::name), Some("Pet"));
+    assert_eq!(route.error_responses()[0].1.name(), Some("Error"));
+}
+
+#[test]
+fn body_parameters_become_payloads() {
+    let route = routes(PETSTORE).remove(1);
+
+    assert_eq!(
+        route.payload_type.as_ref().and_then(Type::name),
+        Some("Pet")
+    );
+    assert_eq!(route.payload_media_type, MediaType::Json);
+    assert!(route.return_type.is_none());
+}
+
+#[test]
+fn form_data_parameters_become_multipart_payloads() {
+    let route = routes(PETSTORE).remove(2);
+
+    assert_eq!(route.url_parameters[0].name, "petId");
+    assert_eq!(route.payload_media_type, MediaType::Multipart);
+
+    match route.payload_type {
+        Some(Type::Record { members, .. }) => {
+            let members: Vec<(&str, bool)> = members
+                .iter()
+                .map(|member| (member.name.as_str(), member.is_optional))
+                .collect();
+
+            assert_eq!(members, vec![("photo", false), ("caption", true)]);
+        }
+        ty => panic!("Expected a record, found {:?}", ty),
+    }
+}
+
+#[test]
+fn only_schema_keywords_are_rewritten() {
+    let api = api(r##"
+swagger: "2.0"
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: getPet
+      responses:
+        200:
+          description: The pet.
+          schema:
+            $ref: "#/definitions/Pet"
+definitions:
+  Pet:
+    type: object
+    properties:
+      discriminator:
+        type: string
+      x-nullable:
+        type: boolean
+      owner:
+        type: object
+        x-nullable: true
+        properties:
+          name:
+            type: string
+    example:
+      discriminator: dog
+      x-nullable: false
+"##);
+
+    let members = match api.types.get("Pet").unwrap() {
+        Type::Record { members, .. } => members,
+        ty => panic!("Expected a record, found {:?}", ty),
+    };
+
+    let names: Vec<&str> = members.iter().map(|member| member.name.as_str()).collect();
+    assert_eq!(names, vec!["discriminator", "x-nullable", "owner"]);
+    assert!(matches!(members[0].ty, Type::Primitive(_)));
+    assert!(members[2].is_nullable);
+}
+
+#[test]
+fn security_definitions_are_converted() {
+    let routes = routes(PETSTORE);
+
+    let (scheme, _) = &routes[0].security[0].schemes[0];
+    assert_eq!(
+        scheme.kind,
+        SecuritySchemeKind::ApiKey {
+            location: ApiKeyLocation::Query,
+            parameter_name: "api_key".into(),
+        }
+    );
+
+    let requirements: Vec<Vec<&str>> = routes[1]
+        .security
+        .iter()
+        .map(|requirement| {
+            requirement
+                .schemes
+                .iter()
+                .map(|(scheme, _)| scheme.name.as_str())
+                .collect()
+        })
+        .collect();
+    assert_eq!(requirements, vec![vec!["oauth"], vec!["basic"]]);
+
+    let (scheme, scopes) = &routes[1].security[0].schemes[0];
+    assert_eq!(scopes, &vec!["write".to_string()]);
+
+    match &scheme.kind {
+        SecuritySchemeKind::OAuth2 { flows } => {
+            assert_eq!(flows.len(), 1);
+            assert_eq!(flows[0].kind, OAuth2FlowKind::AuthorizationCode);
+            assert_eq!(
+                flows[0].token_url.as_deref(),
+                Some("https://example.com/token")
+            );
+        }
+        kind => panic!("Expected an OAuth 2.0 scheme, found {:?}", kind),
+    }
+
+    assert_eq!(
+        routes[1].security[1].schemes[0].0.kind,
+        SecuritySchemeKind::Basic
+    );
+}
+
+#[test]
+fn reports_oauth2_flows_without_their_urls() {
+    let swagger = PETSTORE.replace("    tokenUrl: https://example.com/token\n", "");
+
+    let diagnostics = sdkgen_adapter_swagger2::from_yaml(&swagger, &PathFilter::default())
+        .expect_err("the flow has no token URL");
+
+    assert_eq!(
+        messages(&diagnostics)[0],
+        "Missing 'tokenUrl' for the OAuth 2.0 flow."
+    );
+    assert_eq!(
+        diagnostics
+            .iter()
+            .next()
+            .unwrap()
+            .pointer
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "/securityDefinitions/oauth"
+    );
+}
+
+#[test]
+fn reports_problems_at_their_location_in_the_swagger_document() {
+    let swagger = PETSTORE.replace("      operationId: getPet\n", "");
+
+    let diagnostics = sdkgen_adapter_swagger2::from_yaml(&swagger, &PathFilter::default())
+        .expect_err("the operation has no ID");
+
+    assert_eq!(
+        messages(&diagnostics),
+        vec!["No operation ID for '/v1/pets/{petId}'."]
+    );
+    assert_eq!(
+        diagnostics
+            .iter()
+            .next()
+            .unwrap()
+            .pointer
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "/paths/~1pets~1{petId}/get"
+    );
+}
+
+#[test]
+fn detects_swagger_documents() {
+    assert!(sdkgen_adapter_swagger2::is_swagger2_yaml(PETSTORE));
+    assert!(sdkgen_adapter_swagger2::is_swagger2_json(
+        r#"{ "swagger": "2.0" }"#
+    ));
+    assert!(!sdkgen_adapter_swagger2::is_swagger2_json(
+        r#"{ "openapi": "3.0.0" }"#
+    ));
+}
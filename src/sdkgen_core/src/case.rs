@@ -0,0 +1,72 @@
+use heck::{CamelCase, MixedCase, ShoutySnakeCase, SnakeCase};
+
+/// A case that identifiers are converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// Leaves identifiers as they appear in the API definition.
+    Preserve,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+}
+
+impl Case {
+    /// Converts the identifier to the case, treating any characters other than letters and digits
+    /// as word boundaries.
+    pub fn apply(self, identifier: String) -> String {
+        if self == Case::Preserve {
+            return identifier;
+        }
+
+        let identifier: String = identifier
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+            .collect();
+
+        match self {
+            Case::Preserve => identifier,
+            Case::Camel => identifier.to_mixed_case(),
+            Case::Pascal => identifier.to_camel_case(),
+            Case::Snake => identifier.to_snake_case(),
+            Case::ScreamingSnake => identifier.to_shouty_snake_case(),
+        }
+    }
+}
+
+/// The case used for each kind of identifier.
+#[derive(Debug, Clone, Copy)]
+pub struct IdentifierCases {
+    pub type_name: Case,
+    pub record_member: Case,
+    pub function_name: Case,
+    pub parameter: Case,
+    pub enum_variant: Case,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_treats_punctuation_as_word_boundaries() {
+        let identifier = || "pet-store.v2_owner name".to_string();
+
+        assert_eq!(Case::Camel.apply(identifier()), "petStoreV2OwnerName");
+        assert_eq!(Case::Pascal.apply(identifier()), "PetStoreV2OwnerName");
+        assert_eq!(Case::Snake.apply(identifier()), "pet_store_v2_owner_name");
+        assert_eq!(
+            Case::ScreamingSnake.apply(identifier()),
+            "PET_STORE_V2_OWNER_NAME"
+        );
+    }
+
+    #[test]
+    fn preserve_leaves_identifiers_alone() {
+        assert_eq!(Case::Preserve.apply("pet-store".into()), "pet-store");
+    }
+}
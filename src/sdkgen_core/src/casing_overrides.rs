@@ -0,0 +1,45 @@
+use indexmap::IndexMap;
+
+/// Explicit names for identifiers, which take precedence over the casing rules.
+#[derive(Debug, Clone, Default)]
+pub struct CasingOverrides {
+    overrides: IndexMap<String, String>,
+}
+
+impl CasingOverrides {
+    pub fn new() -> Self {
+        Self {
+            overrides: IndexMap::new(),
+        }
+    }
+
+    /// Overrides the name used for the identifier, as it appears in the API definition.
+    pub fn insert<I: Into<String>, N: Into<String>>(&mut self, identifier: I, name: N) {
+        self.overrides.insert(identifier.into(), name.into());
+    }
+
+    /// Returns the overridden name for the identifier, or applies the casing rule if it has none.
+    pub fn apply(&self, identifier: String, to_case: impl FnOnce(String) -> String) -> String {
+        match self.overrides.get(&identifier) {
+            Some(name) => name.clone(),
+            None => to_case(identifier),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Case;
+
+    #[test]
+    fn overrides_take_precedence_over_the_casing_rule() {
+        let mut overrides = CasingOverrides::new();
+        overrides.insert("petId", "identifier");
+
+        let to_snake_case = |value| Case::Snake.apply(value);
+
+        assert_eq!(overrides.apply("petId".into(), to_snake_case), "identifier");
+        assert_eq!(overrides.apply("ownerId".into(), to_snake_case), "owner_id");
+    }
+}
@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::map::IntoIter;
+use indexmap::IndexMap;
+
+/// A tree of generated files, keyed by their path relative to the output directory.
+#[derive(Debug, Clone, Default)]
+pub struct FileTree {
+    files: IndexMap<PathBuf, String>,
+}
+
+impl FileTree {
+    pub fn new() -> Self {
+        Self {
+            files: IndexMap::new(),
+        }
+    }
+
+    /// Adds a file to the tree, replacing any existing file at the same path.
+    pub fn add_file<P: Into<PathBuf>, C: Into<String>>(&mut self, path: P, contents: C) {
+        self.files.insert(path.into(), contents.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files
+            .iter()
+            .map(|(path, contents)| (path.as_path(), contents.as_str()))
+    }
+}
+
+impl IntoIterator for FileTree {
+    type Item = (PathBuf, String);
+    type IntoIter = IntoIter<PathBuf, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.into_iter()
+    }
+}
@@ -0,0 +1,305 @@
+use indexmap::IndexMap;
+
+use crate::{CasingRules, Diagnostic, Diagnostics, Member, SdkVersion, Type, TypeDeclarations};
+
+/// Makes the identifier valid in languages whose identifiers are made up of letters, digits and
+/// underscores, and cannot start with a digit.
+///
+/// Any other characters are replaced with underscores, and identifiers that are empty or start
+/// with a digit are prefixed with an underscore.
+pub fn to_valid_identifier(identifier: String) -> String {
+    let identifier: String = identifier
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+
+    match identifier.chars().next() {
+        Some(first) if !first.is_numeric() => identifier,
+        _ => format!("_{}", identifier),
+    }
+}
+
+/// Appends underscores to the identifier until it is not one of the reserved words.
+pub fn escape_reserved_word(mut identifier: String, reserved_words: &[&str]) -> String {
+    while reserved_words.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+
+    identifier
+}
+
+/// Reports the identifiers that would be emitted with the same name as another identifier in the
+/// same scope, once the casing rules are applied.
+///
+/// Record members are named by `member_name`, since emitters differ in whether they apply the
+/// casing rules to them or keep their wire names.
+pub fn find_identifier_collisions<R: CasingRules<String>>(
+    language: &str,
+    casing_rules: &R,
+    member_name: impl Fn(&Member) -> String,
+    type_decls: &TypeDeclarations,
+    versions: &[SdkVersion],
+) -> Diagnostics {
+    let mut diagnostics = Diagnostics::new();
+
+    diagnostics.extend(find_collisions(
+        language,
+        "types",
+        type_decls
+            .iter()
+            .map(|(name, _)| (name.as_str(), casing_rules.to_type_name_case(name.clone()))),
+    ));
+
+    for (name, ty) in type_decls.iter() {
+        match ty {
+            Type::Record { members, .. } => diagnostics.extend(find_collisions(
+                language,
+                &format!("members of '{}'", name),
+                members
+                    .iter()
+                    .map(|member| (member.name.as_str(), member_name(member))),
+            )),
+            Type::Enum { variants, .. } => diagnostics.extend(find_collisions(
+                language,
+                &format!("variants of '{}'", name),
+                variants.iter().map(|variant| {
+                    (
+                        variant.name.as_str(),
+                        casing_rules.to_enum_variant_case(variant.name.clone()),
+                    )
+                }),
+            )),
+            _ => (),
+        }
+    }
+
+    for version in versions {
+        for resource in version.resources.iter() {
+            diagnostics.extend(find_collisions(
+                language,
+                &format!("routes of '{}'", resource.resource),
+                resource.routes.iter().map(|route| {
+                    (
+                        route.name.as_str(),
+                        casing_rules.to_function_name_case(route.name.clone()),
+                    )
+                }),
+            ));
+
+            for route in resource.routes.iter() {
+                let parameters = route.all_parameters();
+
+                diagnostics.extend(find_collisions(
+                    language,
+                    &format!("parameters of '{}'", route.name),
+                    parameters.iter().map(|parameter| {
+                        (
+                            parameter.name.as_str(),
+                            casing_rules.to_parameter_case(parameter.name.clone()),
+                        )
+                    }),
+                ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Reports the identifiers whose emitted names, given alongside them, are the same.
+fn find_collisions<'a>(
+    language: &str,
+    kind: &str,
+    identifiers: impl Iterator<Item = (&'a str, String)>,
+) -> Vec<Diagnostic> {
+    let mut emitted_names: IndexMap<String, &str> = IndexMap::new();
+    let mut diagnostics = Vec::new();
+
+    for (identifier, emitted_name) in identifiers {
+        match emitted_names.get(&emitted_name) {
+            Some(&other) if other != identifier => diagnostics.push(Diagnostic::error(format!(
+                "'{}' and '{}' would both be named '{}' among the {} of the {} SDK",
+                other, identifier, emitted_name, kind, language
+            ))),
+            Some(_) => (),
+            None => {
+                emitted_names.insert(emitted_name, identifier);
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::route;
+    use crate::{Case, EnumValue, EnumVariant, Parameter, Primitive, SdkResource};
+
+    struct TestCasingRules;
+
+    impl CasingRules<String> for TestCasingRules {
+        fn to_type_name_case(&self, identifier: String) -> String {
+            Case::Pascal.apply(identifier)
+        }
+
+        fn to_record_member_case(&self, identifier: String) -> String {
+            Case::Snake.apply(identifier)
+        }
+
+        fn to_function_name_case(&self, identifier: String) -> String {
+            Case::Snake.apply(identifier)
+        }
+
+        fn to_parameter_case(&self, identifier: String) -> String {
+            Case::Snake.apply(identifier)
+        }
+
+        fn to_enum_variant_case(&self, identifier: String) -> String {
+            Case::ScreamingSnake.apply(identifier)
+        }
+    }
+
+    fn member(name: &str) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty: Type::Primitive(Primitive::String),
+            is_optional: false,
+            is_nullable: false,
+        }
+    }
+
+    fn messages(diagnostics: Diagnostics) -> Vec<String> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn to_valid_identifier_replaces_invalid_characters() {
+        assert_eq!(to_valid_identifier("pet-store.v2".into()), "pet_store_v2");
+        assert_eq!(to_valid_identifier("2xx".into()), "_2xx");
+        assert_eq!(to_valid_identifier("".into()), "_");
+    }
+
+    #[test]
+    fn escape_reserved_word_appends_underscores() {
+        let reserved_words = &["type", "type_"];
+
+        assert_eq!(
+            escape_reserved_word("type".into(), reserved_words),
+            "type__"
+        );
+        assert_eq!(escape_reserved_word("kind".into(), reserved_words), "kind");
+    }
+
+    #[test]
+    fn reports_types_and_variants_that_collide() {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(Type::Enum {
+            name: "pet status".into(),
+            variants: Vec::new(),
+        });
+        type_decls.register(Type::Enum {
+            name: "PetStatus".into(),
+            variants: vec![
+                EnumVariant {
+                    name: "in-stock".into(),
+                    value: EnumValue::String("in-stock".into()),
+                },
+                EnumVariant {
+                    name: "in_stock".into(),
+                    value: EnumValue::String("in_stock".into()),
+                },
+            ],
+        });
+
+        let diagnostics = find_identifier_collisions(
+            "Test",
+            &TestCasingRules,
+            |m| m.name.clone(),
+            &type_decls,
+            &[],
+        );
+
+        assert_eq!(
+            messages(diagnostics),
+            vec![
+                "'pet status' and 'PetStatus' would both be named 'PetStatus' among the types of the Test SDK",
+                "'in-stock' and 'in_stock' would both be named 'IN_STOCK' among the variants of 'PetStatus' of the Test SDK",
+            ]
+        );
+    }
+
+    #[test]
+    fn members_are_named_by_the_emitter() {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(Type::Record {
+            name: "Pet".into(),
+            members: vec![member("petId"), member("pet_id")],
+        });
+
+        let wire_names = find_identifier_collisions(
+            "Test",
+            &TestCasingRules,
+            |member| member.wire_name.clone(),
+            &type_decls,
+            &[],
+        );
+        let cased_names = find_identifier_collisions(
+            "Test",
+            &TestCasingRules,
+            |member| TestCasingRules.to_record_member_case(member.name.clone()),
+            &type_decls,
+            &[],
+        );
+
+        assert!(wire_names.is_empty());
+        assert_eq!(
+            messages(cased_names),
+            vec!["'petId' and 'pet_id' would both be named 'pet_id' among the members of 'Pet' of the Test SDK"]
+        );
+    }
+
+    #[test]
+    fn reports_routes_and_parameters_that_collide() {
+        let mut list_pets = route("listPets");
+        list_pets.query_parameters = ["pageSize", "page_size"]
+            .iter()
+            .map(|name| Parameter {
+                name: name.to_string(),
+                ty: Type::Primitive(Primitive::Integer),
+                is_optional: true,
+                is_nullable: false,
+            })
+            .collect();
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes: vec![list_pets, route("list_pets")],
+            }],
+        }];
+
+        let diagnostics = find_identifier_collisions(
+            "Test",
+            &TestCasingRules,
+            |m| m.name.clone(),
+            &TypeDeclarations::new(),
+            &versions,
+        );
+
+        assert_eq!(
+            messages(diagnostics),
+            vec![
+                "'listPets' and 'list_pets' would both be named 'list_pets' among the routes of 'pets' of the Test SDK",
+                "'pageSize' and 'page_size' would both be named 'page_size' among the parameters of 'listPets' of the Test SDK",
+            ]
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// A JSON Pointer (RFC 6901) identifying a location within a source document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct JsonPointer(Vec<String>);
+
+impl JsonPointer {
+    /// Returns a pointer to the root of the document.
+    pub fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Parses a JSON Pointer, such as `/components/schemas/Pet`.
+    ///
+    /// A leading `#`, as found in the fragment of a `$ref`, is ignored.
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim_start_matches('#');
+
+        Self(
+            value
+                .split('/')
+                .skip(1)
+                .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                .collect(),
+        )
+    }
+
+    /// Returns a new pointer to the given child of this location.
+    pub fn join<S: Into<String>>(&self, segment: S) -> Self {
+        let mut segments = self.0.clone();
+        segments.push(segment.into());
+
+        Self(segments)
+    }
+
+    /// Returns the segments that follow the given pointer, if this pointer is within it.
+    pub fn strip_prefix(&self, prefix: &JsonPointer) -> Option<&[String]> {
+        self.0.strip_prefix(prefix.0.as_slice())
+    }
+
+    /// Returns the unescaped segments of the pointer.
+    pub fn segments(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in self.0.iter() {
+            write!(f, "/{}", segment.replace('~', "~0").replace('/', "~1"))?;
+        }
+
+        Ok(())
+    }
+}
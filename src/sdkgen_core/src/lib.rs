@@ -1,18 +1,64 @@
+mod case;
+mod casing_overrides;
+mod diagnostic;
+mod file_tree;
+mod identifier;
+mod json_pointer;
+mod media_type;
 mod non_empty_string;
+mod path_filter;
+mod security;
+mod server;
+mod status_code;
+mod type_mappings;
 
+pub use case::*;
+pub use casing_overrides::*;
+pub use diagnostic::*;
+pub use file_tree::*;
+pub use identifier::*;
+pub use json_pointer::*;
+pub use media_type::*;
 pub use non_empty_string::*;
+pub use path_filter::*;
+pub use security::*;
+pub use server::*;
+pub use status_code::*;
+pub use type_mappings::*;
 
-use indexmap::map::IntoIter;
+use indexmap::map::{IntoIter, Iter};
 use indexmap::IndexMap;
 
 pub trait CasingRules<T: ToOwned> {
     fn to_type_name_case(&self, identifier: T) -> T::Owned;
     fn to_record_member_case(&self, identifier: T) -> T::Owned;
     fn to_function_name_case(&self, identifier: T) -> T::Owned;
+    fn to_parameter_case(&self, identifier: T) -> T::Owned;
+    fn to_enum_variant_case(&self, identifier: T) -> T::Owned;
+}
+
+/// Metadata for the package that an SDK is published as.
+#[derive(Debug, Clone)]
+pub struct PackageMetadata {
+    pub name: String,
+    pub version: String,
+}
+
+impl Default for PackageMetadata {
+    fn default() -> Self {
+        Self {
+            name: "sdk".into(),
+            version: "0.1.0".into(),
+        }
+    }
 }
 
 pub trait GenerateSdk {
-    fn generate_sdk(&self, types: TypeDeclarations, versions: Vec<SdkVersion>) -> String;
+    fn generate_sdk(
+        &self,
+        types: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics>;
 }
 
 #[derive(Debug, Clone)]
@@ -20,24 +66,65 @@ pub enum Primitive {
     String,
     Boolean,
     Integer,
+    /// A 64-bit integer.
+    Long,
     Float,
     Double,
+    /// A decimal number that must not lose precision, such as an amount of money.
+    Decimal,
+    /// A date and time with an offset from UTC, sent as an RFC 3339 string.
+    DateTime,
+    /// A calendar date, sent as an RFC 3339 `full-date` string.
+    Date,
+    /// A time of day, sent as an RFC 3339 `partial-time` string.
+    Time,
+    Uuid,
+    /// Binary data sent as a base64-encoded string.
+    Bytes,
+    Uri,
+    /// Binary data sent as it is, such as the contents of a file.
+    Binary,
+    /// Any JSON value.
+    Any,
 }
 
 #[derive(Debug, Clone)]
 pub enum Type {
     Primitive(Primitive),
     Array(Box<Type>),
-    Map { key: Box<Type>, value: Box<Type> },
-    Union { name: String, cases: Vec<String> },
-    Record { name: String, members: Vec<Member> },
+    /// An array with a fixed number of items, each with its own type.
+    Tuple(Vec<Type>),
+    Map {
+        key: Box<Type>,
+        value: Box<Type>,
+    },
+    Union {
+        name: String,
+        /// The name of the property used to discriminate between the cases, if the union is tagged.
+        discriminator: Option<String>,
+        cases: Vec<UnionCase>,
+    },
+    Record {
+        name: String,
+        members: Vec<Member>,
+    },
+    Enum {
+        name: String,
+        variants: Vec<EnumVariant>,
+    },
+    /// A reference to a named type that is declared in the type declarations, which is how types
+    /// refer to the named types of the API definition, including themselves.
+    Reference(String),
 }
 
 impl Type {
     pub fn name(&self) -> Option<&str> {
         match self {
-            Type::Union { name, .. } | Type::Record { name, .. } => Some(name),
-            Type::Primitive(_) | Type::Array(_) | Type::Map { .. } => None,
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => Some(name),
+            Type::Primitive(_) | Type::Array(_) | Type::Tuple(_) | Type::Map { .. } => None,
         }
     }
 
@@ -45,10 +132,16 @@ impl Type {
         let new_name = name;
 
         match self {
-            Type::Union { ref mut name, .. } | Type::Record { ref mut name, .. } => {
+            Type::Union { ref mut name, .. }
+            | Type::Record { ref mut name, .. }
+            | Type::Enum { ref mut name, .. } => {
                 *name = new_name.into();
             }
-            Type::Primitive(_) | Type::Array(_) | Type::Map { .. } => (),
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => (),
         };
 
         self
@@ -58,17 +151,29 @@ impl Type {
         let mut referenced_types = Vec::new();
 
         match self {
-            Type::Primitive(_) | Type::Union { .. } => (),
+            Type::Primitive(_) | Type::Enum { .. } | Type::Reference(_) => (),
             Type::Array(ty) => {
                 referenced_types.push(*ty.clone());
                 referenced_types.extend(ty.referenced_types());
             }
+            Type::Tuple(item_types) => {
+                for ty in item_types {
+                    referenced_types.push(ty.clone());
+                    referenced_types.extend(ty.referenced_types());
+                }
+            }
             Type::Map { key, value } => {
                 referenced_types.push(*key.clone());
                 referenced_types.push(*value.clone());
                 referenced_types.extend(key.referenced_types());
                 referenced_types.extend(value.referenced_types());
             }
+            Type::Union { cases, .. } => {
+                for case in cases {
+                    referenced_types.push(case.ty.clone());
+                    referenced_types.extend(case.ty.referenced_types());
+                }
+            }
             Type::Record { members, .. } => {
                 for member in members {
                     referenced_types.push(member.ty.clone());
@@ -82,6 +187,27 @@ impl Type {
 }
 
 #[derive(Debug, Clone)]
+pub struct UnionCase {
+    /// The name of the case.
+    ///
+    /// For tagged unions this is the discriminator value that selects the case.
+    pub name: String,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub name: String,
+    pub value: EnumValue,
+}
+
+#[derive(Debug, Clone)]
+pub enum EnumValue {
+    String(String),
+    Integer(i64),
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct TypeDeclarations {
     declarations: IndexMap<String, Type>,
 }
@@ -94,16 +220,98 @@ impl TypeDeclarations {
     }
 
     /// Registers a type declaration.
+    ///
+    /// Any named types referenced by the type are registered as well, even if the type itself is
+    /// anonymous. A named type that is already registered is left as it is, along with the types
+    /// it references.
     pub fn register(&mut self, ty: Type) {
+        if let Type::Reference(_) = ty {
+            return;
+        }
+
         if let Some(name) = ty.name() {
+            if self.declarations.contains_key(name) {
+                return;
+            }
+
             self.declarations.insert(name.to_owned(), ty.clone());
+        }
+
+        self.register_referenced_types(ty);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Type> {
+        self.declarations.get(name)
+    }
+
+    pub fn iter(&self) -> Iter<'_, String, Type> {
+        self.declarations.iter()
+    }
+
+    /// Returns the declaration of the type if it is a reference, or the type itself otherwise.
+    pub fn resolve<'a>(&'a self, ty: &'a Type) -> &'a Type {
+        match ty {
+            Type::Reference(name) => self.get(name).unwrap_or(ty),
+            _ => ty,
+        }
+    }
+
+    /// Returns whether a value of the type holds a value of the named type, other than within an
+    /// array or a map.
+    ///
+    /// A type that holds a value of itself would be infinitely large, so emitters that lay out
+    /// values inline use this to find the references that have to be held by a pointer instead.
+    pub fn contains(&self, ty: &Type, name: &str) -> bool {
+        self.contains_visiting(ty, name, &mut Vec::new())
+    }
+
+    fn contains_visiting<'a>(
+        &'a self,
+        ty: &'a Type,
+        name: &str,
+        visited: &mut Vec<&'a str>,
+    ) -> bool {
+        if ty.name() == Some(name) {
+            return true;
+        }
+
+        if let Type::Reference(reference) = ty {
+            if visited.contains(&reference.as_str()) {
+                return false;
+            }
+
+            visited.push(reference);
+        }
 
-            self.register_referenced_types(ty);
+        match self.resolve(ty) {
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Map { .. }
+            | Type::Enum { .. }
+            | Type::Reference(_) => false,
+            Type::Tuple(item_types) => item_types
+                .iter()
+                .any(|ty| self.contains_visiting(ty, name, visited)),
+            Type::Union { cases, .. } => cases
+                .iter()
+                .any(|case| self.contains_visiting(&case.ty, name, visited)),
+            Type::Record { members, .. } => members
+                .iter()
+                .any(|member| self.contains_visiting(&member.ty, name, visited)),
         }
     }
 
     fn register_referenced_types(&mut self, ty: Type) {
-        for ty in ty.referenced_types() {
+        let referenced_types = match ty {
+            Type::Primitive(_) | Type::Enum { .. } | Type::Reference(_) => Vec::new(),
+            Type::Array(ty) => vec![*ty],
+            Type::Tuple(item_types) => item_types,
+            Type::Map { key, value } => vec![*key, *value],
+            Type::Union { cases, .. } => cases.into_iter().map(|case| case.ty).collect(),
+            Type::Record { members, .. } => members.into_iter().map(|member| member.ty).collect(),
+        };
+
+        for ty in referenced_types {
             self.register(ty);
         }
     }
@@ -121,9 +329,15 @@ impl IntoIterator for TypeDeclarations {
 #[derive(Debug, Clone)]
 pub struct Member {
     pub name: String,
+    /// The name of the member in the serialized representation, which emitters must preserve
+    /// regardless of how they case the name.
+    pub wire_name: String,
     pub description: Option<String>,
     pub ty: Type,
+    /// Whether the member may be absent.
     pub is_optional: bool,
+    /// Whether the member may be `null`, which is distinct from being absent.
+    pub is_nullable: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -144,8 +358,24 @@ pub struct Route {
     pub group: String,
     pub version: String,
     pub url_parameters: Vec<UrlParameter>,
+    pub query_parameters: Vec<Parameter>,
+    pub header_parameters: Vec<Parameter>,
+    pub cookie_parameters: Vec<Parameter>,
     pub payload_type: Option<Type>,
+    /// The format that the payload is sent in.
+    pub payload_media_type: MediaType,
+    /// The type of the body of a successful response.
     pub return_type: Option<Type>,
+    /// The format of the body of a successful response.
+    pub return_media_type: MediaType,
+    /// The type of the body of each documented response, or `None` for responses without a body.
+    pub responses: IndexMap<StatusCode, Option<Type>>,
+    /// The alternative ways of authorizing a request to the route, any one of which suffices.
+    ///
+    /// Routes that do not require authorization have none.
+    pub security: Vec<SecurityRequirement>,
+    /// The servers that the route may be sent to, the first of which is preferred.
+    pub servers: Vec<Server>,
 }
 
 #[derive(Debug)]
@@ -155,6 +385,16 @@ pub enum UrlSegment {
 }
 
 impl Route {
+    /// Returns the server that the route is sent to instead of those that clients are configured
+    /// with, if its servers differ from them.
+    pub fn overridden_server(&self, default_servers: &[Server]) -> Option<&Server> {
+        if self.servers == default_servers {
+            None
+        } else {
+            self.servers.first()
+        }
+    }
+
     pub fn url_segments(&self) -> Vec<UrlSegment> {
         self.url
             .split('/')
@@ -168,32 +408,89 @@ impl Route {
             .collect()
     }
 
-    pub fn all_parameters(&self) -> Vec<(String, Type)> {
-        let mut all_parameters: Vec<(String, Type)> = self
+    /// Returns the documented responses for unsuccessful status codes that have a body, from the
+    /// most specific status codes to the least.
+    ///
+    /// The default response is only an error response if there are successful responses as well.
+    pub fn error_responses(&self) -> Vec<(&StatusCode, &Type)> {
+        let has_success_responses = self.responses.keys().any(StatusCode::is_success);
+
+        let mut error_responses: Vec<(&StatusCode, &Type)> = self
+            .responses
+            .iter()
+            .filter(|(status, _)| {
+                !status.is_success() && (*status != &StatusCode::Default || has_success_responses)
+            })
+            .filter_map(|(status, ty)| ty.as_ref().map(|ty| (status, ty)))
+            .collect();
+
+        error_responses.sort_by_key(|(status, _)| status.precedence());
+
+        error_responses
+    }
+
+    /// Returns all of the parameters for the route, with the optional parameters last.
+    pub fn all_parameters(&self) -> Vec<Parameter> {
+        let mut all_parameters: Vec<Parameter> = self
             .url_parameters
             .iter()
-            .map(|parameter| {
-                (
-                    parameter.name.clone(),
-                    Type::Primitive(parameter.ty.clone()),
-                )
+            .map(|parameter| Parameter {
+                name: parameter.name.clone(),
+                ty: Type::Primitive(parameter.ty.clone()),
+                is_optional: false,
+                is_nullable: false,
             })
             .collect();
 
-        if let Some((name, ty)) = self.payload_type.as_ref().map(|ty| ("payload".into(), ty)) {
-            all_parameters.push((name, ty.to_owned()));
+        if let Some(payload_type) = self.payload_type.as_ref() {
+            all_parameters.push(Parameter {
+                name: "payload".into(),
+                ty: payload_type.to_owned(),
+                is_optional: false,
+                is_nullable: false,
+            });
         }
 
+        let (optional_parameters, required_parameters): (Vec<Parameter>, Vec<Parameter>) = self
+            .query_parameters
+            .iter()
+            .chain(self.header_parameters.iter())
+            .chain(self.cookie_parameters.iter())
+            .cloned()
+            .partition(|parameter| parameter.is_optional);
+
+        all_parameters.extend(required_parameters);
+        all_parameters.extend(optional_parameters);
+
         all_parameters
     }
 }
 
+/// A parameter to a route.
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub name: String,
+    pub ty: Type,
+    /// Whether the parameter may be left out.
+    pub is_optional: bool,
+    /// Whether the parameter may be `null`, which is distinct from being left out. Parameters that
+    /// are `null` are not sent.
+    pub is_nullable: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct UrlParameter {
     pub name: String,
     pub ty: Primitive,
 }
 
+/// The routes converted from an API definition, along with the named types that they refer to.
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    pub routes: Vec<Route>,
+    pub types: TypeDeclarations,
+}
+
 #[derive(Debug, Clone)]
 pub struct SdkVersion {
     pub version: String,
@@ -205,3 +502,244 @@ pub struct SdkResource {
     pub resource: String,
     pub routes: Vec<Route>,
 }
+
+/// Returns the primitives used by the types and routes of an SDK, such as to decide which
+/// libraries it depends on.
+pub fn used_primitives(type_decls: &TypeDeclarations, versions: &[SdkVersion]) -> Vec<Primitive> {
+    let route_types = versions
+        .iter()
+        .flat_map(|version| version.resources.iter())
+        .flat_map(|resource| resource.routes.iter())
+        .flat_map(|route| {
+            route
+                .all_parameters()
+                .into_iter()
+                .map(|parameter| parameter.ty)
+                .chain(route.return_type.clone())
+                .chain(route.responses.values().flatten().cloned())
+        });
+
+    type_decls
+        .iter()
+        .map(|(_, ty)| ty.clone())
+        .chain(route_types)
+        .flat_map(|ty| {
+            let mut types = ty.referenced_types();
+            types.push(ty);
+            types
+        })
+        .filter_map(|ty| match ty {
+            Type::Primitive(primitive) => Some(primitive),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the security schemes used by the routes of an SDK, each only once.
+pub fn used_security_schemes(versions: &[SdkVersion]) -> Vec<SecurityScheme> {
+    let mut schemes: Vec<SecurityScheme> = Vec::new();
+
+    let used_schemes = versions
+        .iter()
+        .flat_map(|version| version.resources.iter())
+        .flat_map(|resource| resource.routes.iter())
+        .flat_map(|route| route.security.iter())
+        .flat_map(|requirement| requirement.schemes.iter());
+
+    for (scheme, _) in used_schemes {
+        if !schemes.iter().any(|used| used.name == scheme.name) {
+            schemes.push(scheme.clone());
+        }
+    }
+
+    schemes
+}
+
+/// Returns the servers that most routes of an SDK are sent to, which clients are configured with.
+///
+/// Routes sent to other servers override those of the client.
+pub fn default_servers(versions: &[SdkVersion]) -> Vec<Server> {
+    let mut server_lists: Vec<(&Vec<Server>, usize)> = Vec::new();
+
+    let routes = versions
+        .iter()
+        .flat_map(|version| version.resources.iter())
+        .flat_map(|resource| resource.routes.iter());
+
+    for route in routes {
+        match server_lists
+            .iter_mut()
+            .find(|(servers, _)| **servers == route.servers)
+        {
+            Some((_, count)) => *count += 1,
+            None => server_lists.push((&route.servers, 1)),
+        }
+    }
+
+    // The first of the most common lists is kept when several are equally common.
+    server_lists
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(servers, _)| servers.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn route(name: &str) -> Route {
+        Route {
+            name: name.into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: Vec::new(),
+            query_parameters: Vec::new(),
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: None,
+            return_media_type: MediaType::Json,
+            responses: IndexMap::new(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        }
+    }
+
+    fn parameter(name: &str, is_optional: bool) -> Parameter {
+        Parameter {
+            name: name.into(),
+            ty: Type::Primitive(Primitive::String),
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn server(url: &str) -> Server {
+        Server {
+            url: url.into(),
+            description: None,
+            variables: IndexMap::new(),
+        }
+    }
+
+    fn versions(routes: Vec<Route>) -> Vec<SdkVersion> {
+        vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes,
+            }],
+        }]
+    }
+
+    #[test]
+    fn all_parameters_puts_optional_parameters_last() {
+        let mut route = route("updatePet");
+        route.url_parameters.push(UrlParameter {
+            name: "petId".into(),
+            ty: Primitive::String,
+        });
+        route.payload_type = Some(Type::Primitive(Primitive::String));
+        route.query_parameters.push(parameter("dryRun", true));
+        route
+            .header_parameters
+            .push(parameter("X-Request-Id", false));
+        route.cookie_parameters.push(parameter("session", false));
+
+        let names: Vec<String> = route
+            .all_parameters()
+            .into_iter()
+            .map(|parameter| parameter.name)
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["petId", "payload", "X-Request-Id", "session", "dryRun"]
+        );
+    }
+
+    #[test]
+    fn error_responses_are_ordered_by_specificity() {
+        let mut route = route("getPet");
+        route
+            .responses
+            .insert(StatusCode::Default, Some(Type::Primitive(Primitive::Any)));
+        route.responses.insert(
+            StatusCode::Range(4),
+            Some(Type::Primitive(Primitive::String)),
+        );
+        route.responses.insert(StatusCode::Code(404), None);
+        route.responses.insert(
+            StatusCode::Code(409),
+            Some(Type::Primitive(Primitive::String)),
+        );
+
+        let statuses: Vec<&StatusCode> = route
+            .error_responses()
+            .into_iter()
+            .map(|(status, _)| status)
+            .collect();
+
+        assert_eq!(
+            statuses,
+            vec![&StatusCode::Code(409), &StatusCode::Range(4)]
+        );
+
+        route.responses.insert(StatusCode::Code(200), None);
+
+        let statuses: Vec<&StatusCode> = route
+            .error_responses()
+            .into_iter()
+            .map(|(status, _)| status)
+            .collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                &StatusCode::Code(409),
+                &StatusCode::Range(4),
+                &StatusCode::Default
+            ]
+        );
+    }
+
+    #[test]
+    fn default_servers_are_the_most_common() {
+        let mut list_pets = route("listPets");
+        list_pets.servers = vec![server("https://api.example.com")];
+        let mut get_pet = list_pets.clone();
+        get_pet.name = "getPet".into();
+        let mut upload_photo = route("uploadPhoto");
+        upload_photo.servers = vec![server("https://uploads.example.com")];
+
+        let versions = versions(vec![upload_photo, list_pets, get_pet]);
+
+        assert_eq!(
+            default_servers(&versions),
+            vec![server("https://api.example.com")]
+        );
+        assert_eq!(
+            versions[0].resources[0].routes[0].overridden_server(&default_servers(&versions)),
+            Some(&server("https://uploads.example.com"))
+        );
+    }
+
+    #[test]
+    fn default_servers_prefer_the_first_when_tied() {
+        let mut list_pets = route("listPets");
+        list_pets.servers = vec![server("https://api.example.com")];
+        let mut upload_photo = route("uploadPhoto");
+        upload_photo.servers = vec![server("https://uploads.example.com")];
+
+        assert_eq!(
+            default_servers(&versions(vec![list_pets, upload_photo])),
+            vec![server("https://api.example.com")]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+/// The format that the body of a request or response is sent in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaType {
+    Json,
+    /// `application/x-www-form-urlencoded`.
+    FormUrlEncoded,
+    /// `multipart/form-data`.
+    Multipart,
+    /// Plain text, given by its media type such as `text/plain`.
+    Text(String),
+    /// Binary data sent as it is, given by its media type such as `application/octet-stream`.
+    Binary(String),
+}
+
+impl MediaType {
+    /// Parses a media type, ignoring any parameters such as `charset`.
+    pub fn parse(media_type: &str) -> Self {
+        let essence = media_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        match essence.as_str() {
+            "application/json" => MediaType::Json,
+            "application/x-www-form-urlencoded" => MediaType::FormUrlEncoded,
+            "multipart/form-data" => MediaType::Multipart,
+            _ if essence.ends_with("+json") => MediaType::Json,
+            _ if essence.starts_with("text/") => MediaType::Text(essence),
+            _ => MediaType::Binary(essence),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaType::Json => "application/json",
+            MediaType::FormUrlEncoded => "application/x-www-form-urlencoded",
+            MediaType::Multipart => "multipart/form-data",
+            MediaType::Text(media_type) | MediaType::Binary(media_type) => media_type,
+        }
+    }
+}
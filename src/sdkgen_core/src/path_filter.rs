@@ -0,0 +1,126 @@
+/// Selects the paths of an API definition to generate routes for.
+///
+/// Patterns are matched against paths one segment at a time. `*` matches any characters within a
+/// segment and `**` matches any number of segments.
+#[derive(Debug, Clone, Default)]
+pub struct PathFilter {
+    /// The patterns for the paths to include. All paths are included when this is empty.
+    pub include: Vec<String>,
+    /// The patterns for the paths to exclude, even if they are included.
+    pub exclude: Vec<String>,
+}
+
+impl PathFilter {
+    pub fn is_match(&self, path: &str) -> bool {
+        let is_included = self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| matches_pattern(pattern, path));
+
+        is_included
+            && !self
+                .exclude
+                .iter()
+                .any(|pattern| matches_pattern(pattern, path))
+    }
+}
+
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let path_segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    matches_segments(&pattern_segments, &path_segments)
+}
+
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|index| matches_segments(rest, &path[index..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((path_segment, path_rest)) => {
+                matches_wildcard(segment, path_segment) && matches_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Returns whether the text matches the pattern, where `*` matches any characters.
+fn matches_wildcard(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let parts: Vec<&str> = parts.collect();
+
+    match parts.split_last() {
+        None => rest.is_empty(),
+        Some((last, middle)) => {
+            for part in middle {
+                match rest.find(part) {
+                    Some(index) => rest = &rest[index + part.len()..],
+                    None => return false,
+                }
+            }
+
+            rest.ends_with(last)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> PathFilter {
+        PathFilter {
+            include: include.iter().map(|pattern| pattern.to_string()).collect(),
+            exclude: exclude.iter().map(|pattern| pattern.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        assert!(PathFilter::default().is_match("/pets/{petId}"));
+    }
+
+    #[test]
+    fn single_wildcard_matches_within_a_segment() {
+        let filter = filter(&["/pets/*"], &[]);
+
+        assert!(filter.is_match("/pets/{petId}"));
+        assert!(!filter.is_match("/pets"));
+        assert!(!filter.is_match("/pets/{petId}/toys"));
+    }
+
+    #[test]
+    fn wildcards_match_part_of_a_segment() {
+        let filter = filter(&["/v*/pet*s"], &[]);
+
+        assert!(filter.is_match("/v1/pets"));
+        assert!(filter.is_match("/v2/petstores"));
+        assert!(!filter.is_match("/v1/owners"));
+    }
+
+    #[test]
+    fn double_wildcard_matches_any_number_of_segments() {
+        let filter = filter(&["/pets/**"], &[]);
+
+        assert!(filter.is_match("/pets"));
+        assert!(filter.is_match("/pets/{petId}/toys"));
+        assert!(!filter.is_match("/owners"));
+    }
+
+    #[test]
+    fn exclude_takes_precedence() {
+        let filter = filter(&["/pets/**"], &["/pets/*/internal"]);
+
+        assert!(filter.is_match("/pets/{petId}"));
+        assert!(!filter.is_match("/pets/{petId}/internal"));
+    }
+}
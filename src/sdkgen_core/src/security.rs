@@ -0,0 +1,65 @@
+use indexmap::IndexMap;
+
+/// A way of authorizing requests to the API, such as an API key or an OAuth 2.0 access token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityScheme {
+    /// The name that security requirements refer to the scheme by.
+    pub name: String,
+    pub kind: SecuritySchemeKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecuritySchemeKind {
+    /// An API key sent as the header, query parameter or cookie with the given name.
+    ApiKey {
+        location: ApiKeyLocation,
+        parameter_name: String,
+    },
+    /// A username and password sent in the `Authorization` header.
+    Basic,
+    /// A token sent in the `Authorization` header.
+    Bearer {
+        /// A hint to how the token is formatted, such as `JWT`.
+        format: Option<String>,
+    },
+    /// An access token obtained by one of the flows and sent in the `Authorization` header.
+    OAuth2 { flows: Vec<OAuth2Flow> },
+    /// An access token obtained from the OpenID Connect provider at the given discovery URL and
+    /// sent in the `Authorization` header.
+    OpenIdConnect { url: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
+
+/// A way of obtaining an OAuth 2.0 access token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuth2Flow {
+    pub kind: OAuth2FlowKind,
+    pub authorization_url: Option<String>,
+    pub token_url: Option<String>,
+    pub refresh_url: Option<String>,
+    /// The available scopes, along with their descriptions.
+    pub scopes: IndexMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OAuth2FlowKind {
+    Implicit,
+    Password,
+    ClientCredentials,
+    AuthorizationCode,
+}
+
+/// A set of security schemes that must all be satisfied to authorize a request.
+///
+/// A requirement without any schemes is satisfied by an anonymous request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityRequirement {
+    /// The schemes, along with the scopes that each requires.
+    pub schemes: Vec<(SecurityScheme, Vec<String>)>,
+}
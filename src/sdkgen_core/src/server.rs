@@ -0,0 +1,32 @@
+use indexmap::IndexMap;
+
+/// A server that hosts the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Server {
+    /// The URL of the server, in which variables are written as `{name}`.
+    ///
+    /// The URL may be relative to where the API definition is hosted.
+    pub url: String,
+    pub description: Option<String>,
+    pub variables: IndexMap<String, ServerVariable>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerVariable {
+    /// The value used when no other value is given.
+    pub default: String,
+    /// The values that the variable is limited to, or none if it may take any value.
+    pub allowed_values: Vec<String>,
+    pub description: Option<String>,
+}
+
+impl Server {
+    /// Returns the URL of the server, with each variable replaced by its default value.
+    pub fn default_url(&self) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.clone(), |url, (name, variable)| {
+                url.replace(&format!("{{{}}}", name), &variable.default)
+            })
+    }
+}
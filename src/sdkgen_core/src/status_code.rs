@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// The status codes that a documented response applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StatusCode {
+    Code(u16),
+    /// A range of status codes such as `4XX`, given by its first digit.
+    Range(u16),
+    /// The status codes that no other response applies to.
+    Default,
+}
+
+impl StatusCode {
+    /// Returns whether the status codes are successful ones.
+    pub fn is_success(&self) -> bool {
+        match self {
+            StatusCode::Code(code) => (200..300).contains(code),
+            StatusCode::Range(range) => *range == 2,
+            StatusCode::Default => false,
+        }
+    }
+
+    /// Returns the order in which responses are matched against a status code, from the most
+    /// specific to the least.
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            StatusCode::Code(_) => 0,
+            StatusCode::Range(_) => 1,
+            StatusCode::Default => 2,
+        }
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusCode::Code(code) => write!(f, "{}", code),
+            StatusCode::Range(range) => write!(f, "{}XX", range),
+            StatusCode::Default => write!(f, "default"),
+        }
+    }
+}
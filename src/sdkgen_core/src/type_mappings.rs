@@ -0,0 +1,29 @@
+use indexmap::IndexMap;
+
+/// Maps named types to existing types in the target language.
+///
+/// Mapped types are referred to using the given type instead of having a declaration generated.
+#[derive(Debug, Clone, Default)]
+pub struct TypeMappings {
+    mappings: IndexMap<String, String>,
+}
+
+impl TypeMappings {
+    pub fn new() -> Self {
+        Self {
+            mappings: IndexMap::new(),
+        }
+    }
+
+    pub fn insert<N: Into<String>, T: Into<String>>(&mut self, name: N, target_type: T) {
+        self.mappings.insert(name.into(), target_type.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.mappings.get(name).map(String::as_str)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.mappings.contains_key(name)
+    }
+}
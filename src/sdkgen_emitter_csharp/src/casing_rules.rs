@@ -1,18 +1,172 @@
-use heck::CamelCase;
-use sdkgen_core::CasingRules;
+use sdkgen_core::{
+    escape_reserved_word, to_valid_identifier, Case, CasingOverrides, CasingRules, IdentifierCases,
+};
 
-pub struct CsharpCasingRules;
+/// The keywords of C#, which are prefixed with `@` to be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// The names used by the body of emitted routes, which parameters cannot shadow.
+const RESERVED_PARAMETER_NAMES: &[&str] = &[
+    "client",
+    "content",
+    "cookies",
+    "fields",
+    "query",
+    "queryParameters",
+    "request",
+    "response",
+    "responseBody",
+    "statusCode",
+];
+
+#[derive(Debug, Clone)]
+pub struct CsharpCasingRules {
+    pub cases: IdentifierCases,
+    pub overrides: CasingOverrides,
+}
+
+impl CsharpCasingRules {
+    pub const DEFAULT_CASES: IdentifierCases = IdentifierCases {
+        type_name: Case::Pascal,
+        record_member: Case::Pascal,
+        function_name: Case::Pascal,
+        parameter: Case::Camel,
+        enum_variant: Case::Pascal,
+    };
+}
+
+impl Default for CsharpCasingRules {
+    fn default() -> Self {
+        Self {
+            cases: Self::DEFAULT_CASES,
+            overrides: CasingOverrides::new(),
+        }
+    }
+}
 
 impl CasingRules<String> for CsharpCasingRules {
     fn to_type_name_case(&self, value: String) -> String {
-        value.to_camel_case()
+        to_csharp_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.type_name.apply(value)),
+        )
     }
 
     fn to_record_member_case(&self, value: String) -> String {
-        value.to_camel_case()
+        to_csharp_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.record_member.apply(value)),
+        )
     }
 
     fn to_function_name_case(&self, value: String) -> String {
-        value.to_camel_case()
+        to_csharp_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.function_name.apply(value)),
+        )
+    }
+
+    fn to_parameter_case(&self, value: String) -> String {
+        to_csharp_identifier(escape_reserved_word(
+            self.overrides
+                .apply(value, |value| self.cases.parameter.apply(value)),
+            RESERVED_PARAMETER_NAMES,
+        ))
+    }
+
+    fn to_enum_variant_case(&self, value: String) -> String {
+        to_csharp_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.enum_variant.apply(value)),
+        )
+    }
+}
+
+/// Makes the identifier valid in C#, using a verbatim identifier if it is a keyword.
+fn to_csharp_identifier(value: String) -> String {
+    let value = to_valid_identifier(value);
+
+    if KEYWORDS.contains(&value.as_str()) {
+        format!("@{}", value)
+    } else {
+        value
     }
 }
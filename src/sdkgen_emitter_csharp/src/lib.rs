@@ -1,173 +1,1623 @@
 mod casing_rules;
 
-use std::convert::identity;
-
 use sdkgen_core::{
-    CasingRules, GenerateSdk, HttpMethod, Primitive, Route, SdkResource, SdkVersion, Type,
-    TypeDeclarations, UrlSegment,
+    default_servers, find_identifier_collisions, used_security_schemes, ApiKeyLocation,
+    CasingRules, Diagnostics, EnumValue, EnumVariant, FileTree, GenerateSdk, HttpMethod, MediaType,
+    Member, PackageMetadata, Parameter, Primitive, Route, SdkResource, SdkVersion, SecurityScheme,
+    SecuritySchemeKind, Server, StatusCode, Type, TypeDeclarations, TypeMappings, UnionCase,
+    UrlSegment,
 };
 
-use crate::casing_rules::CsharpCasingRules;
+pub use crate::casing_rules::CsharpCasingRules;
 
-pub struct CsharpSdk;
+#[derive(Debug, Default)]
+pub struct CsharpSdk {
+    pub package: PackageMetadata,
+    pub casing_rules: CsharpCasingRules,
+    pub type_mappings: TypeMappings,
+}
 
 impl GenerateSdk for CsharpSdk {
-    fn generate_sdk(&self, type_decls: TypeDeclarations, versions: Vec<SdkVersion>) -> String {
-        let mut buffer = String::new();
+    fn generate_sdk(
+        &self,
+        type_decls: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics> {
+        find_identifier_collisions(
+            "C#",
+            &self.casing_rules,
+            |member| self.casing_rules.to_record_member_case(member.name.clone()),
+            &type_decls,
+            &versions,
+        )
+        .into_result(())?;
+
+        let root_namespace = self
+            .package
+            .name
+            .split('.')
+            .map(|segment| self.casing_rules.to_type_name_case(segment.to_owned()))
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let mut files = FileTree::new();
+
+        files.add_file(
+            format!("{}.csproj", self.package.name),
+            emit_project_file(&self.package, &root_namespace),
+        );
+
+        files.add_file(
+            "ApiException.cs",
+            format!(
+                "{usings}\nnamespace {root_namespace};\n{api_exception}",
+                usings = "#nullable enable\n\nusing System;\n",
+                root_namespace = root_namespace,
+                api_exception = API_EXCEPTION
+            ),
+        );
+
+        let security_schemes = used_security_schemes(&versions);
+
+        if !security_schemes.is_empty() {
+            files.add_file(
+                "Authorization.cs",
+                format!(
+                    "{usings}\nnamespace {root_namespace};\n{authorization}",
+                    usings = AUTHORIZATION_USINGS,
+                    root_namespace = root_namespace,
+                    authorization = emit_authorization(&security_schemes)
+                ),
+            );
+        }
+
+        let default_servers = default_servers(&versions);
+        let has_servers = !default_servers.is_empty()
+            || versions
+                .iter()
+                .flat_map(|version| version.resources.iter())
+                .flat_map(|resource| resource.routes.iter())
+                .any(|route| route.overridden_server(&default_servers).is_some());
 
-        for (_name, ty) in type_decls.into_iter() {
-            buffer += &emit_type_decl(ty);
+        if has_servers {
+            files.add_file(
+                "Server.cs",
+                format!(
+                    "{usings}\nnamespace {root_namespace};\n{server}",
+                    usings =
+                        "#nullable enable\n\nusing System;\nusing System.Collections.Generic;\n",
+                    root_namespace = root_namespace,
+                    server = emit_server_class(&default_servers)
+                ),
+            );
+        }
+
+        files.add_file(
+            "ApiClient.cs",
+            format!(
+                "{usings}\nnamespace {root_namespace};\n{api_client}",
+                usings = API_CLIENT_USINGS,
+                root_namespace = root_namespace,
+                api_client = self.emit_api_client(
+                    &versions,
+                    !security_schemes.is_empty(),
+                    &default_servers,
+                    has_servers
+                )
+            ),
+        );
+
+        for (name, ty) in type_decls.iter() {
+            if self.type_mappings.contains(name) {
+                continue;
+            }
+
+            files.add_file(
+                format!(
+                    "Models/{}.cs",
+                    self.casing_rules.to_type_name_case(name.clone())
+                ),
+                format!(
+                    "{usings}\nnamespace {root_namespace}.Models;\n{type_decl}\n",
+                    usings = MODEL_USINGS,
+                    root_namespace = root_namespace,
+                    type_decl = self.emit_type_decl(ty.clone(), &type_decls).trim_end()
+                ),
+            );
         }
 
         for version in versions {
             for resource in version.resources {
-                buffer += &emit_sdk_resource(version.version.clone(), resource);
+                let resource_name = self
+                    .casing_rules
+                    .to_type_name_case(format!("{} {}", version.version, resource.resource));
+
+                files.add_file(
+                    format!("Resources/{}.cs", resource_name),
+                    format!(
+                        "{usings}using {root_namespace}.Models;\n{resource}\n",
+                        usings = RESOURCE_USINGS,
+                        root_namespace = root_namespace,
+                        resource = self
+                            .emit_sdk_resource(
+                                &root_namespace,
+                                version.version.clone(),
+                                resource,
+                                &default_servers,
+                                &type_decls
+                            )
+                            .trim_end()
+                    ),
+                );
             }
         }
 
-        buffer
+        Ok(files)
     }
 }
 
-fn emit_primitive(primitive: Primitive) -> &'static str {
-    match primitive {
-        Primitive::String => "string",
-        Primitive::Boolean => "bool",
-        Primitive::Integer => "int",
-        Primitive::Float => "float",
-        Primitive::Double => "double",
+impl CsharpSdk {
+    /// Returns the name of the client class for a resource and the name of the `ApiClient`
+    /// property that exposes it.
+    fn resource_client_names(&self, version: &str, resource: &str) -> (String, String) {
+        (
+            self.casing_rules
+                .to_type_name_case(format!("{} client", resource)),
+            self.casing_rules
+                .to_type_name_case(format!("{} {}", version, resource)),
+        )
     }
-}
 
-fn emit_type_name(ty: Type) -> String {
-    match ty {
-        Type::Primitive(primitive) => emit_primitive(primitive).into(),
-        Type::Array(ty) => format!("List<{}>", emit_type_name(*ty)),
-        Type::Map { key, value } => format!(
-            "Dictionary<{}, {}>",
-            emit_type_name(*key),
-            emit_type_name(*value)
-        ),
-        Type::Union { name, .. } | Type::Record { name, .. } => {
-            CsharpCasingRules.to_type_name_case(name)
+    /// Emits the options of the `ApiClient`, and the `ApiClient`, which exposes a client for each
+    /// resource.
+    ///
+    /// Clients of APIs with servers may leave out the base URL to use one of them instead.
+    fn emit_api_client(
+        &self,
+        versions: &[SdkVersion],
+        has_security_schemes: bool,
+        default_servers: &[Server],
+        has_servers: bool,
+    ) -> String {
+        let resource_clients: Vec<(String, String)> = versions
+            .iter()
+            .flat_map(|version| {
+                version.resources.iter().map(move |resource| {
+                    let (class_name, property_name) =
+                        self.resource_client_names(&version.version, &resource.resource);
+
+                    (
+                        format!("V{}.{}", version.version, class_name),
+                        property_name,
+                    )
+                })
+            })
+            .collect();
+
+        let (credentials_option, credentials_property, credentials_initializer) =
+            if has_security_schemes {
+                (
+                    r#"
+
+    /// <summary>
+    /// The provider of the credentials that requests are authorized with.
+    /// </summary>
+    public ICredentialsProvider? CredentialsProvider { get; set; }"#,
+                    "\n\n    internal ICredentialsProvider? CredentialsProvider { get; }",
+                    "\n        CredentialsProvider = options.CredentialsProvider;",
+                )
+            } else {
+                ("", "", "")
+            };
+
+        let (base_url_option, base_url_statement) = if default_servers.is_empty() {
+            (
+                r#"
+    /// <summary>
+    /// The base URL of the API.
+    /// </summary>
+    public Uri? BaseUrl { get; set; }"#,
+                r#"if (options.BaseUrl == null)
+        {
+            throw new ArgumentException("The base URL of the API is required.", nameof(options));
         }
-    }
-}
 
-fn emit_type_decl(ty: Type) -> String {
-    match ty {
-        Type::Record { name, members } => format!(
+        baseUrl = options.BaseUrl.OriginalString.TrimEnd('/');"#,
+            )
+        } else {
+            (
+                r#"
+    /// <summary>
+    /// The base URL of the API, or null to use one of the servers of the API.
+    /// </summary>
+    public Uri? BaseUrl { get; set; }
+
+    /// <summary>
+    /// The index in <see cref="Server.All"/> of the server that requests are sent to when no base
+    /// URL is given.
+    /// </summary>
+    public int ServerIndex { get; set; }"#,
+                r#"if (options.BaseUrl != null)
+        {
+            baseUrl = options.BaseUrl.OriginalString.TrimEnd('/');
+        }
+        else if (options.ServerIndex >= 0 && options.ServerIndex < Server.All.Count)
+        {
+            baseUrl = Server.All[options.ServerIndex].GetUrl(options.ServerVariables);
+        }
+        else
+        {
+            throw new ArgumentException("The base URL of the API is required when the server index is not that of one of its servers.", nameof(options));
+        }"#,
+            )
+        };
+
+        let (server_variables_option, server_variables_field, server_variables_initializer) =
+            if has_servers {
+                (
+                    r#"
+
+    /// <summary>
+    /// The values of the variables in the URLs of the servers, which otherwise take their defaults.
+    /// </summary>
+    public IDictionary<string, string> ServerVariables { get; } = new Dictionary<string, string>();"#,
+                    "\n    private readonly Dictionary<string, string> serverVariables;",
+                    "\n        serverVariables = new Dictionary<string, string>(options.ServerVariables);",
+                )
+            } else {
+                ("", "", "")
+            };
+
+        // Routes sent to their own server pass it to `CreateUri` in place of the base URL.
+        let create_uri = if has_servers {
+            r#"internal Uri CreateUri(string path, Server? server = null)
+    {
+        return new Uri((server == null ? baseUrl : server.GetUrl(serverVariables)) + path, UriKind.RelativeOrAbsolute);
+    }"#
+        } else {
+            r#"internal Uri CreateUri(string path)
+    {
+        return new Uri(baseUrl + path, UriKind.RelativeOrAbsolute);
+    }"#
+        };
+
+        format!(
             r#"
+/// <summary>
+/// Options for an <see cref="ApiClient"/>.
+/// </summary>
+public sealed class ApiClientOptions
+{{{base_url_option}{server_variables_option}
+
+    /// <summary>
+    /// The HTTP client that sends requests, or null to create one for the <see cref="ApiClient"/>.
+    /// </summary>
+    public HttpClient? HttpClient {{ get; set; }}
+
+    /// <summary>
+    /// Headers sent with every request, unless the request sets them itself.
+    /// </summary>
+    public IDictionary<string, string> DefaultHeaders {{ get; }} = new Dictionary<string, string>();
+
+    /// <summary>
+    /// How long to wait for a response, or null to only use the timeout of the HTTP client.
+    /// </summary>
+    public TimeSpan? Timeout {{ get; set; }}{credentials_option}
+}}
+
+/// <summary>
+/// A client for the API.
+/// </summary>
+/// <remarks>
+/// Relative base URLs are resolved against the base address of the HTTP client.
+/// </remarks>
+public sealed class ApiClient : IDisposable
+{{
+    private readonly HttpClient httpClient;
+    private readonly bool ownsHttpClient;
+    private readonly string baseUrl;{server_variables_field}
+    private readonly Dictionary<string, string> defaultHeaders;
+    private readonly TimeSpan? timeout;
+
+    public ApiClient(ApiClientOptions options)
+    {{
+        {base_url_statement}
+
+        httpClient = options.HttpClient ?? new HttpClient();
+        ownsHttpClient = options.HttpClient == null;{server_variables_initializer}
+        defaultHeaders = new Dictionary<string, string>(options.DefaultHeaders);
+        timeout = options.Timeout;{credentials_initializer}
+        {initializers}
+    }}
+
+    {properties}{credentials_property}
+
+    /// <summary>
+    /// Disposes the HTTP client, unless it was given in the options.
+    /// </summary>
+    public void Dispose()
+    {{
+        if (ownsHttpClient)
+        {{
+            httpClient.Dispose();
+        }}
+    }}
+
+    {create_uri}
+
+    internal async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request)
+    {{
+        foreach (var header in defaultHeaders)
+        {{
+            if (!request.Headers.Contains(header.Key))
+            {{
+                request.Headers.TryAddWithoutValidation(header.Key, header.Value);
+            }}
+        }}
+
+        if (timeout == null)
+        {{
+            return await httpClient.SendAsync(request).ConfigureAwait(false);
+        }}
+
+        using var cancellation = new CancellationTokenSource(timeout.Value);
+
+        return await httpClient.SendAsync(request, cancellation.Token).ConfigureAwait(false);
+    }}
+}}
+"#,
+            base_url_option = base_url_option,
+            server_variables_option = server_variables_option,
+            credentials_option = credentials_option,
+            server_variables_field = server_variables_field,
+            base_url_statement = base_url_statement,
+            server_variables_initializer = server_variables_initializer,
+            credentials_initializer = credentials_initializer,
+            credentials_property = credentials_property,
+            create_uri = create_uri,
+            initializers = resource_clients
+                .iter()
+                .map(|(class_name, property_name)| format!(
+                    "{} = new {}(this);",
+                    property_name, class_name
+                ))
+                .collect::<Vec<_>>()
+                .join("\n        "),
+            properties = resource_clients
+                .iter()
+                .map(|(class_name, property_name)| format!(
+                    "public {} {} {{ get; }}",
+                    class_name, property_name
+                ))
+                .collect::<Vec<_>>()
+                .join("\n\n    ")
+        )
+    }
+
+    fn emit_type_name(&self, ty: Type) -> String {
+        match ty {
+            Type::Primitive(primitive) => emit_primitive(primitive).into(),
+            Type::Array(ty) => format!("List<{}>", self.emit_type_name(*ty)),
+            // JSON.NET serializes value tuples as objects, so tuples whose items differ in type are
+            // left as JSON arrays.
+            Type::Tuple(item_types) => {
+                let mut item_type_names: Vec<String> = item_types
+                    .into_iter()
+                    .map(|ty| self.emit_type_name(ty))
+                    .collect();
+                item_type_names.dedup();
+
+                match item_type_names.as_slice() {
+                    [item_type_name] => format!("List<{}>", item_type_name),
+                    _ => "JArray".into(),
+                }
+            }
+            Type::Map { key, value } => format!(
+                "Dictionary<{}, {}>",
+                self.emit_type_name(*key),
+                self.emit_type_name(*value)
+            ),
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => match self.type_mappings.get(&name) {
+                Some(mapped_type) => mapped_type.to_owned(),
+                None => self.casing_rules.to_type_name_case(name),
+            },
+        }
+    }
+
+    fn emit_type_decl(&self, ty: Type, type_decls: &TypeDeclarations) -> String {
+        match ty {
+            Type::Record { name, members } => format!(
+                r#"
 public class {name}
 {{
     {members}
 }}
         "#,
-            name = CsharpCasingRules.to_type_name_case(name),
-            members = members
+                name = self.casing_rules.to_type_name_case(name),
+                members = members
+                    .into_iter()
+                    .map(|member| self.emit_member(member, type_decls))
+                    .collect::<Vec<_>>()
+                    .join("\n\n    ")
+            ),
+            Type::Union {
+                name,
+                discriminator,
+                cases,
+            } => self.emit_union_decl(name, discriminator, cases),
+            Type::Enum { name, variants } => self.emit_enum_decl(name, variants),
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => "".into(),
+        }
+    }
+
+    /// Emits a property for the member, annotated with its wire name when the property is named
+    /// differently.
+    ///
+    /// Members that may be absent or `null` are nullable, and optional members are left out when
+    /// they are `null` so that only nullable members are sent as `null`.
+    fn emit_member(&self, member: Member, type_decls: &TypeDeclarations) -> String {
+        let member_name = self.casing_rules.to_record_member_case(member.name);
+        let is_value_type = is_value_type(type_decls.resolve(&member.ty));
+        let type_name = self.emit_type_name(member.ty);
+
+        let property = if member.is_optional || member.is_nullable {
+            format!("public {}? {} {{ get; set; }}", type_name, member_name)
+        } else if is_value_type {
+            format!("public {} {} {{ get; set; }}", type_name, member_name)
+        } else {
+            // Required members are always set when deserialized.
+            format!(
+                "public {} {} {{ get; set; }} = null!;",
+                type_name, member_name
+            )
+        };
+
+        let mut property_args = Vec::new();
+
+        if member_name != member.wire_name {
+            property_args.push(format!("{:?}", member.wire_name));
+        }
+
+        if member.is_optional {
+            property_args.push("NullValueHandling = NullValueHandling.Ignore".to_string());
+        }
+
+        if property_args.is_empty() {
+            property
+        } else {
+            format!(
+                "[JsonProperty({})]\n    {}",
+                property_args.join(", "),
+                property
+            )
+        }
+    }
+
+    /// Emits an enum.
+    ///
+    /// String enums are serialized using the value in their `[EnumMember]` attributes, while integer
+    /// enums are serialized as their underlying value.
+    fn emit_enum_decl(&self, name: String, variants: Vec<EnumVariant>) -> String {
+        let is_string_enum = variants
+            .iter()
+            .any(|variant| matches!(variant.value, EnumValue::String(_)));
+
+        format!(
+            r#"
+{attributes}public enum {name}
+{{
+    {variants}
+}}
+        "#,
+            attributes = if is_string_enum {
+                "[JsonConverter(typeof(StringEnumConverter))]\n"
+            } else {
+                ""
+            },
+            name = self.casing_rules.to_type_name_case(name),
+            variants = variants
                 .into_iter()
-                .map(|member| format!(
-                    "public {} {} {{ get; set; }}",
-                    emit_type_name(member.ty),
-                    CsharpCasingRules.to_record_member_case(member.name)
-                ))
+                .map(|variant| {
+                    let variant_name = self.casing_rules.to_enum_variant_case(variant.name);
+
+                    match variant.value {
+                        EnumValue::String(value) => {
+                            format!("[EnumMember(Value = \"{}\")]\n    {},", value, variant_name)
+                        }
+                        EnumValue::Integer(value) => format!("{} = {},", variant_name, value),
+                    }
+                })
                 .collect::<Vec<_>>()
-                .join("\n")
-        ),
-        Type::Union { .. } => "".into(),
-        Type::Primitive(_) | Type::Array(_) | Type::Map { .. } => "".into(),
+                .join("\n    ")
+        )
     }
-}
 
-fn emit_sdk_resource(version: String, resource: SdkResource) -> String {
-    let type_decls: Vec<Type> = resource
-        .routes
-        .iter()
-        .flat_map(|route| vec![route.clone().payload_type.map(|ty| vec![ty])])
-        .filter_map(identity)
-        .flat_map(identity)
-        .collect();
-
-    let contents = resource
-        .routes
-        .into_iter()
-        .map(emit_route)
-        .collect::<Vec<String>>()
-        .join("\n");
+    /// Emits a union as an abstract class with a nested subclass wrapping the value of each case.
+    ///
+    /// The accompanying converter (de)serializes the union as the value of its case, using the
+    /// discriminator to select the case when one is present and otherwise trying each case in order.
+    fn emit_union_decl(
+        &self,
+        name: String,
+        discriminator: Option<String>,
+        cases: Vec<UnionCase>,
+    ) -> String {
+        let name = self.casing_rules.to_type_name_case(name);
 
-    format!(
-        r#"
-namespace Sdk.V{version}
+        let cases: Vec<(UnionCase, String, String)> = cases
+            .into_iter()
+            .map(|case| {
+                let case_class_name = self
+                    .casing_rules
+                    .to_type_name_case(format!("{} case", case.name));
+                let case_type = self.emit_type_name(case.ty.clone());
+
+                (case, case_class_name, case_type)
+            })
+            .collect();
+
+        let case_classes = cases
+            .iter()
+            .map(|(_, case_class_name, case_type)| {
+                format!(
+                    r#"
+    public sealed class {case_class_name} : {name}
+    {{
+        public {case_class_name}({case_type} value)
+        {{
+            Value = value;
+        }}
+
+        public {case_type} Value {{ get; }}
+
+        internal override object UntypedValue => Value;
+    }}"#,
+                    name = name,
+                    case_class_name = case_class_name,
+                    case_type = case_type
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let read_cases = match discriminator.as_ref() {
+            Some(discriminator) => format!(
+                r#"switch ((string?)token["{discriminator}"])
+        {{
+            {cases}
+            default:
+                throw new JsonSerializationException($"Unknown {discriminator} '{{token["{discriminator}"]}}' for {name}.");
+        }}"#,
+                discriminator = discriminator,
+                name = name,
+                cases = cases
+                    .iter()
+                    .map(|(case, case_class_name, case_type)| format!(
+                        r#"case "{tag}":
+                return new {name}.{case_class_name}(token.ToObject<{case_type}>(serializer)!);"#,
+                        tag = case.name,
+                        name = name,
+                        case_class_name = case_class_name,
+                        case_type = case_type
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n            ")
+            ),
+            None => format!(
+                r#"{cases}
+
+        throw new JsonSerializationException("Value does not match any case of {name}.");"#,
+                name = name,
+                cases = cases
+                    .iter()
+                    .map(|(_, case_class_name, case_type)| format!(
+                        r#"try
+        {{
+            return new {name}.{case_class_name}(token.ToObject<{case_type}>(serializer)!);
+        }}
+        catch (JsonException)
+        {{
+        }}"#,
+                        name = name,
+                        case_class_name = case_class_name,
+                        case_type = case_type
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n\n        ")
+            ),
+        };
+
+        format!(
+            r#"
+[JsonConverter(typeof({name}Converter))]
+public abstract class {name}
 {{
-    public static class Sdk
+    private {name}()
     {{
-        {class_body}
+    }}
+
+    internal abstract object UntypedValue {{ get; }}
+    {case_classes}
+}}
+
+public class {name}Converter : JsonConverter
+{{
+    public override bool CanConvert(Type objectType) => typeof({name}).IsAssignableFrom(objectType);
+
+    public override object ReadJson(JsonReader reader, Type objectType, object? existingValue, JsonSerializer serializer)
+    {{
+        var token = JToken.Load(reader);
+
+        {read_cases}
+    }}
+
+    public override void WriteJson(JsonWriter writer, object? value, JsonSerializer serializer)
+    {{
+        serializer.Serialize(writer, (({name})value!).UntypedValue);
     }}
 }}
         "#,
-        version = version,
-        class_body = contents
-    )
+            name = name,
+            case_classes = case_classes,
+            read_cases = read_cases
+        )
+    }
+
+    fn emit_sdk_resource(
+        &self,
+        root_namespace: &str,
+        version: String,
+        resource: SdkResource,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let (class_name, _) = self.resource_client_names(&version, &resource.resource);
+
+        let contents = resource
+            .routes
+            .into_iter()
+            .map(|value| indent(&self.emit_route(value, default_servers, type_decls), 2))
+            .collect::<Vec<String>>()
+            .join("\n\n");
+
+        format!(
+            r#"
+namespace {root_namespace}.V{version}
+{{
+    public sealed class {class_name}
+    {{
+        private readonly ApiClient client;
+
+        internal {class_name}(ApiClient client)
+        {{
+            this.client = client;
+        }}
+
+{class_body}
+    }}
+}}
+"#,
+            root_namespace = root_namespace,
+            version = version,
+            class_name = class_name,
+            class_body = contents
+        )
+    }
+
+    fn emit_parameter_name(&self, parameter: &str) -> String {
+        self.casing_rules.to_parameter_case(parameter.to_owned())
+    }
+
+    fn emit_parameter(&self, parameter: &Parameter) -> String {
+        let type_name = self.emit_type_name(parameter.ty.clone());
+        let name = self.emit_parameter_name(&parameter.name);
+
+        if parameter.is_optional {
+            format!("{}? {} = null", type_name, name)
+        } else if parameter.is_nullable {
+            format!("{}? {}", type_name, name)
+        } else {
+            format!("{} {}", type_name, name)
+        }
+    }
+
+    fn emit_route(
+        &self,
+        route: Route,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let throw_statements = self.emit_throw_statements(&route);
+
+        // Routes whose successful responses have no body return a plain task.
+        let task_type = match route.return_type.as_ref() {
+            Some(ty) => format!("Task<{}>", self.emit_type_name(ty.to_owned())),
+            None => "Task".to_string(),
+        };
+
+        let parameter_list = route
+            .all_parameters()
+            .iter()
+            .map(|value| self.emit_parameter(value))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let query = if route.query_parameters.is_empty() {
+            None
+        } else {
+            Some(format!(
+                r#"var queryParameters = new List<string>();
+    {query_parameters}
+    var query = queryParameters.Count > 0 ? "?" + string.Join("&", queryParameters) : "";
+"#,
+                query_parameters = route
+                    .query_parameters
+                    .iter()
+                    .map(
+                        |parameter| self.emit_parameter_values(parameter, |value| format!(
+                            r#"queryParameters.Add("{}=" + Uri.EscapeDataString({}));"#,
+                            parameter.name, value
+                        ))
+                    )
+                    .collect::<Vec<String>>()
+                    .join("\n    ")
+            ))
+        };
+
+        let mut headers: Vec<String> = route
+            .header_parameters
+            .iter()
+            .map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        r#"request.Headers.TryAddWithoutValidation("{}", {});"#,
+                        parameter.name, value
+                    )
+                })
+            })
+            .collect();
+
+        if !route.cookie_parameters.is_empty() {
+            headers.push(format!(
+                r#"var cookies = new List<string>();
+    {cookie_parameters}
+    if (cookies.Count > 0)
+    {{
+        request.Headers.TryAddWithoutValidation("Cookie", string.Join("; ", cookies));
+    }}"#,
+                cookie_parameters = route
+                    .cookie_parameters
+                    .iter()
+                    .map(
+                        |parameter| self.emit_parameter_values(parameter, |value| format!(
+                            r#"cookies.Add("{}=" + Uri.EscapeDataString({}));"#,
+                            parameter.name, value
+                        ))
+                    )
+                    .collect::<Vec<String>>()
+                    .join("\n    ")
+            ));
+        }
+
+        let url = route
+            .url_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                UrlSegment::Parameter(param) => format!(
+                    "{{Uri.EscapeDataString({})}}",
+                    self.emit_parameter_name(&param)
+                ),
+                UrlSegment::Literal(value) => value,
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+
+        let request_content = self.emit_request_content(&route, type_decls);
+
+        let authorize = if route.security.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "await Authorization.AuthorizeAsync(request, client.CredentialsProvider, {}).ConfigureAwait(false);",
+                route
+                    .security
+                    .iter()
+                    .map(|requirement| if requirement.schemes.is_empty() {
+                        "Array.Empty<(string, string[])>()".to_string()
+                    } else {
+                        format!(
+                            "new[] {{ {} }}",
+                            requirement
+                                .schemes
+                                .iter()
+                                .map(|(scheme, scopes)| format!(
+                                    "({:?}, {})",
+                                    scheme.name,
+                                    if scopes.is_empty() {
+                                        "Array.Empty<string>()".to_string()
+                                    } else {
+                                        format!(
+                                            "new[] {{ {} }}",
+                                            scopes
+                                                .iter()
+                                                .map(|scope| format!("{:?}", scope))
+                                                .collect::<Vec<_>>()
+                                                .join(", ")
+                                        )
+                                    }
+                                ))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+
+        // Routes sent to their own server pass it in place of the base URL of the client.
+        let server = route
+            .overridden_server(default_servers)
+            .map(|server| format!(", {}", emit_server(server)))
+            .unwrap_or_default();
+
+        let read_response_body =
+            "var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);";
+
+        // Binary responses, and responses without a body, are only read as text when they are
+        // unsuccessful.
+        let (read_body, read_error_body, return_statement) = match (
+            &route.return_media_type,
+            route.return_type.as_ref(),
+        ) {
+            (_, None) => (
+                String::new(),
+                format!("\n        {}", read_response_body),
+                String::new(),
+            ),
+            (MediaType::Binary(_), Some(_)) => (
+                String::new(),
+                format!("\n        {}", read_response_body),
+                "\n\n    return await response.Content.ReadAsStreamAsync().ConfigureAwait(false);"
+                    .to_string(),
+            ),
+            (MediaType::Json, Some(return_type)) => (
+                format!("\n    {}", read_response_body),
+                String::new(),
+                format!(
+                    "\n\n    return JsonConvert.DeserializeObject<{}>(responseBody)!;",
+                    self.emit_type_name(return_type.to_owned())
+                ),
+            ),
+            (_, Some(_)) => (
+                format!("\n    {}", read_response_body),
+                String::new(),
+                "\n\n    return responseBody;".to_string(),
+            ),
+        };
+
+        let summary = route
+            .description
+            .map(|description| {
+                format!(
+                    "/// <summary>\n/// {}\n/// </summary>\n",
+                    String::from(description).replace('\n', "\n/// ")
+                )
+            })
+            .unwrap_or_default();
+
+        format!(
+            r#"{summary}public async {task_type} {function_name}({parameter_list})
+{{
+    {query}var request = new HttpRequestMessage
+    {{
+        Method = HttpMethod.{http_method},
+        RequestUri = client.CreateUri($"{url}{query_string}"{server})
+    }};{request_setup}
+
+    var response = await client.SendAsync(request).ConfigureAwait(false);{read_body}
+
+    if (!response.IsSuccessStatusCode)
+    {{
+        var statusCode = (int)response.StatusCode;{read_error_body}
+
+        {throw_statements}
+    }}{return_statement}
+}}"#,
+            function_name = self.casing_rules.to_function_name_case(route.name),
+            parameter_list = parameter_list,
+            http_method = match route.method {
+                HttpMethod::Get => "Get",
+                HttpMethod::Post => "Post",
+                HttpMethod::Put => "Put",
+                HttpMethod::Patch => "Patch",
+                HttpMethod::Delete => "Delete",
+            },
+            url = url,
+            query_string = if query.is_some() { "{query}" } else { "" },
+            server = server,
+            query = query.map(|query| query + "\n    ").unwrap_or_default(),
+            request_setup = std::iter::once(authorize)
+                .chain(headers)
+                .chain(request_content)
+                .filter(|statement| !statement.is_empty())
+                .map(|statement| format!("\n    {}", statement))
+                .collect::<String>(),
+            read_body = read_body,
+            read_error_body = read_error_body,
+            throw_statements = throw_statements,
+            return_statement = return_statement,
+            task_type = task_type,
+            summary = summary
+        )
+    }
+
+    /// Emits the statements that set the content of the request to the payload, in the format that
+    /// the route accepts.
+    ///
+    /// Forms can only be built from records, so any other payload is sent as JSON.
+    fn emit_request_content(&self, route: &Route, type_decls: &TypeDeclarations) -> Option<String> {
+        let payload_type = type_decls.resolve(route.payload_type.as_ref()?);
+        let payload = self.emit_parameter_name("payload");
+
+        let statements = match (&route.payload_media_type, payload_type) {
+            (MediaType::FormUrlEncoded, Type::Record { members, .. }) => format!(
+                r#"var fields = new List<KeyValuePair<string, string>>();
+    {fields}
+    request.Content = new FormUrlEncodedContent(fields);"#,
+                fields = members
+                    .iter()
+                    .map(
+                        |member| self.emit_member_values(&payload, member, |value, ty| format!(
+                            "fields.Add(new KeyValuePair<string, string>({:?}, {}));",
+                            member.wire_name,
+                            emit_to_string(&value, ty)
+                        ))
+                    )
+                    .collect::<Vec<String>>()
+                    .join("\n    ")
+            ),
+            (MediaType::Multipart, Type::Record { members, .. }) => format!(
+                r#"var content = new MultipartFormDataContent();
+    {parts}
+    request.Content = content;"#,
+                parts = members
+                    .iter()
+                    .map(
+                        |member| self.emit_member_values(&payload, member, |value, ty| match ty {
+                            Type::Primitive(Primitive::Binary) => format!(
+                                "content.Add(new StreamContent({value}), {name:?}, {name:?});",
+                                value = value,
+                                name = member.wire_name
+                            ),
+                            _ => format!(
+                                "content.Add(new StringContent({}), {:?});",
+                                emit_to_string(&value, ty),
+                                member.wire_name
+                            ),
+                        })
+                    )
+                    .collect::<Vec<String>>()
+                    .join("\n    ")
+            ),
+            (MediaType::Text(media_type), _) => format!(
+                r#"request.Content = new StringContent({}, Encoding.UTF8, "{}");"#,
+                payload, media_type
+            ),
+            (MediaType::Binary(media_type), _) => format!(
+                r#"request.Content = new StreamContent({});
+    request.Content.Headers.ContentType = new MediaTypeHeaderValue("{}");"#,
+                payload, media_type
+            ),
+            _ => format!(
+                r#"request.Content = new StringContent(JsonConvert.SerializeObject({}), Encoding.UTF8, "application/json");"#,
+                payload
+            ),
+        };
+
+        Some(statements)
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the member of the record.
+    ///
+    /// Array members produce one statement per item, and members that may be null are skipped when
+    /// they are.
+    fn emit_member_values(
+        &self,
+        record: &str,
+        member: &Member,
+        emit_statement: impl Fn(String, &Type) -> String,
+    ) -> String {
+        let value = format!(
+            "{}.{}",
+            record,
+            self.casing_rules.to_record_member_case(member.name.clone())
+        );
+
+        let statement = match &member.ty {
+            Type::Array(item_type) => format!(
+                r#"foreach (var item in {value})
+    {{
+        {statement}
+    }}"#,
+                value = value,
+                statement = emit_statement("item".to_string(), item_type)
+            ),
+            ty => emit_statement(value.clone(), ty),
+        };
+
+        if member.is_optional || member.is_nullable {
+            format!(
+                r#"if ({value} != null)
+    {{
+        {statement}
+    }}"#,
+                value = value,
+                statement = statement
+            )
+        } else {
+            statement
+        }
+    }
+
+    /// Emits the statements that throw an exception for an unsuccessful response, holding its body
+    /// deserialized as the type documented for its status code when there is one.
+    fn emit_throw_statements(&self, route: &Route) -> String {
+        let mut statements = Vec::new();
+        let mut has_default = false;
+
+        for (status, ty) in route.error_responses() {
+            let error_type = self.emit_type_name(ty.clone());
+            let throw_statement = format!(
+                "throw new ApiException<{error_type}>(statusCode, responseBody, JsonConvert.DeserializeObject<{error_type}>(responseBody)!);",
+                error_type = error_type
+            );
+
+            let condition = match status {
+                StatusCode::Code(code) => format!("statusCode == {}", code),
+                StatusCode::Range(range) => format!("statusCode / 100 == {}", range),
+                StatusCode::Default => {
+                    statements.push(throw_statement);
+                    has_default = true;
+                    continue;
+                }
+            };
+
+            statements.push(format!(
+                r#"if ({condition})
+        {{
+            {throw_statement}
+        }}"#,
+                condition = condition,
+                throw_statement = throw_statement
+            ));
+        }
+
+        if !has_default {
+            statements.push("throw new ApiException(statusCode, responseBody);".to_string());
+        }
+
+        statements.join("\n\n        ")
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the parameter.
+    ///
+    /// Array parameters produce one statement per item, and optional parameters are skipped when null.
+    fn emit_parameter_values(
+        &self,
+        parameter: &Parameter,
+        emit_statement: impl Fn(String) -> String,
+    ) -> String {
+        let name = self.emit_parameter_name(&parameter.name);
+
+        let statement = match &parameter.ty {
+            Type::Array(item_type) => format!(
+                r#"foreach (var item in {name})
+    {{
+        {statement}
+    }}"#,
+                name = name,
+                statement = emit_statement(emit_to_string("item", item_type))
+            ),
+            ty => emit_statement(emit_to_string(&name, ty)),
+        };
+
+        if parameter.is_optional || parameter.is_nullable {
+            format!(
+                r#"if ({name} != null)
+    {{
+        {statement}
+    }}"#,
+                name = name,
+                statement = statement
+            )
+        } else {
+            statement
+        }
+    }
 }
 
-fn emit_route(route: Route) -> String {
-    let return_type = route
-        .return_type
-        .as_ref()
-        .unwrap_or(&Type::Primitive(Primitive::String));
+const API_EXCEPTION: &str = r#"
+/// <summary>
+/// Thrown when the API responds with an unsuccessful status code.
+/// </summary>
+public class ApiException : Exception
+{
+    public ApiException(int statusCode, string responseBody)
+        : base($"The API responded with status code {statusCode}.")
+    {
+        StatusCode = statusCode;
+        ResponseBody = responseBody;
+    }
+
+    public int StatusCode { get; }
+
+    public string ResponseBody { get; }
+}
 
-    let parameter_list = route
-        .all_parameters()
+/// <summary>
+/// Thrown when the API responds with an unsuccessful status code that it documents a response body for.
+/// </summary>
+public class ApiException<TError> : ApiException
+{
+    public ApiException(int statusCode, string responseBody, TError error)
+        : base(statusCode, responseBody)
+    {
+        Error = error;
+    }
+
+    public TError Error { get; }
+}
+"#;
+
+const AUTHORIZATION_USINGS: &str = r#"#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+"#;
+
+/// Emits the credentials provider interface, along with the helper that authorizes requests with
+/// the credentials for the security schemes.
+fn emit_authorization(schemes: &[SecurityScheme]) -> String {
+    let cases = schemes
         .iter()
-        .map(|(name, ty)| format!("{} {}", emit_type_name(ty.clone()), name))
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    let url = route
-        .url_segments()
-        .into_iter()
-        .map(|segment| match segment {
-            UrlSegment::Parameter(param) => format!("{{{}}}", param),
-            UrlSegment::Literal(value) => value,
+        .map(|scheme| {
+            let statement = match &scheme.kind {
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    parameter_name,
+                } => format!(
+                    "request.Headers.TryAddWithoutValidation({:?}, credential);",
+                    parameter_name
+                ),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Query,
+                    parameter_name,
+                } => format!(
+                    r#"var uri = request.RequestUri!.OriginalString;
+                request.RequestUri = new Uri(uri + (uri.Contains('?') ? "&" : "?") + "{}=" + Uri.EscapeDataString(credential), UriKind.RelativeOrAbsolute);"#,
+                    parameter_name
+                ),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Cookie,
+                    parameter_name,
+                } => format!(
+                    r#"request.Headers.TryAddWithoutValidation("Cookie", "{}=" + Uri.EscapeDataString(credential));"#,
+                    parameter_name
+                ),
+                SecuritySchemeKind::Basic => r#"request.Headers.Authorization = new AuthenticationHeaderValue("Basic", Convert.ToBase64String(Encoding.UTF8.GetBytes(credential)));"#.to_string(),
+                SecuritySchemeKind::Bearer { .. }
+                | SecuritySchemeKind::OAuth2 { .. }
+                | SecuritySchemeKind::OpenIdConnect { .. } => {
+                    r#"request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", credential);"#.to_string()
+                }
+            };
+
+            format!(
+                "case {:?}:\n            {{\n                {}\n                break;\n            }}",
+                scheme.name, statement
+            )
         })
-        .collect::<Vec<String>>()
-        .join("/");
+        .collect::<Vec<_>>()
+        .join("\n            ");
 
-    let request_content = route.payload_type.map(|_| format!(r#"request.Content = new StringContent(JsonConvert.SerializeObject(payload), Encoding.UTF8, "application/json");"#));
+    format!(
+        r#"
+/// <summary>
+/// Supplies the credentials for the security schemes of the API.
+/// </summary>
+public interface ICredentialsProvider
+{{
+    /// <summary>
+    /// Returns the credential for the security scheme, or null if there is none.
+    /// </summary>
+    /// <remarks>
+    /// The credential is the API key for API key schemes, <c>username:password</c> for HTTP basic
+    /// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+    /// </remarks>
+    Task<string?> GetCredentialAsync(string scheme, IReadOnlyList<string> scopes);
+}}
+
+internal static class Authorization
+{{
+    /// <summary>
+    /// Adds the credentials for the first of the security requirements that the provider can satisfy to the request.
+    /// </summary>
+    public static async Task AuthorizeAsync(HttpRequestMessage request, ICredentialsProvider? provider, params (string Scheme, string[] Scopes)[][] requirements)
+    {{
+        foreach (var requirement in requirements)
+        {{
+            var credentials = new List<string>();
+
+            foreach (var (scheme, scopes) in requirement)
+            {{
+                var credential = provider == null ? null : await provider.GetCredentialAsync(scheme, scopes).ConfigureAwait(false);
+                if (credential == null)
+                {{
+                    break;
+                }}
+
+                credentials.Add(credential);
+            }}
+
+            if (credentials.Count == requirement.Length)
+            {{
+                for (var i = 0; i < requirement.Length; i++)
+                {{
+                    ApplyCredential(request, requirement[i].Scheme, credentials[i]);
+                }}
+
+                return;
+            }}
+        }}
+    }}
+
+    private static void ApplyCredential(HttpRequestMessage request, string scheme, string credential)
+    {{
+        switch (scheme)
+        {{
+            {cases}
+        }}
+    }}
+}}
+"#,
+        cases = cases
+    )
+}
 
+/// Emits the class of the servers that host the API, which fills in the variables in their URLs.
+fn emit_server_class(servers: &[Server]) -> String {
     format!(
         r#"
 /// <summary>
-/// {summary}
+/// A server that hosts the API, whose URL may hold variables written as <c>{{name}}</c>.
 /// </summary>
-public static async Task<{return_type}> {function_name}({parameter_list})
+public sealed class Server
 {{
-    var request = new HttpRequestMessage
+    public Server(string url, IReadOnlyDictionary<string, string> variables)
     {{
-        Method = HttpMethod.{http_method},
-        RequestUri = new Uri(apiUrl, $"{url}")
-    }};
-    request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", accessToken);
-    {request_content}
+        Url = url;
+        Variables = variables;
+    }}
+
+    /// <summary>
+    /// The servers that host the API.
+    /// </summary>
+    public static IReadOnlyList<Server> All {{ get; }} = {servers};
+
+    public string Url {{ get; }}
+
+    /// <summary>
+    /// The default values of the variables.
+    /// </summary>
+    public IReadOnlyDictionary<string, string> Variables {{ get; }}
 
-    var response = await httpClient.SendAsync(request).ConfigureAwait(false);
-    var responseBody = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+    /// <summary>
+    /// Returns the URL of the server, with its variables replaced by the given values or otherwise
+    /// their defaults.
+    /// </summary>
+    public string GetUrl(IDictionary<string, string>? variables = null)
+    {{
+        var url = Url;
+
+        foreach (var variable in Variables)
+        {{
+            var value = variables != null && variables.TryGetValue(variable.Key, out var given) ? given : variable.Value;
+            url = url.Replace("{{" + variable.Key + "}}", value);
+        }}
 
-    return JsonConvert.DeserializeObject<{return_type}>(responseBody);
+        return url;
+    }}
 }}
-    "#,
-        function_name = CsharpCasingRules.to_function_name_case(route.name),
-        parameter_list = parameter_list,
-        http_method = match route.method {
-            HttpMethod::Get => "Get",
-            HttpMethod::Post => "Post",
-            HttpMethod::Put => "Put",
-            HttpMethod::Patch => "Patch",
-            HttpMethod::Delete => "Delete",
-        },
-        url = url,
-        request_content = request_content.unwrap_or_default(),
-        return_type = emit_type_name(return_type.to_owned()),
-        summary = route.description.map(String::from).unwrap_or_default()
+"#,
+        servers = if servers.is_empty() {
+            "Array.Empty<Server>()".to_string()
+        } else {
+            format!(
+                "new[]\n    {{\n        {}\n    }}",
+                servers
+                    .iter()
+                    .map(|server| format!("{},", emit_server(server)))
+                    .collect::<Vec<_>>()
+                    .join("\n        ")
+            )
+        }
+    )
+}
+
+/// Emits an expression creating a `Server`.
+///
+/// The trailing slash is left out of the URL so that paths can be appended to it.
+fn emit_server(server: &Server) -> String {
+    format!(
+        "new Server({:?}, new Dictionary<string, string>{})",
+        server.url.trim_end_matches('/'),
+        if server.variables.is_empty() {
+            "()".to_string()
+        } else {
+            format!(
+                " {{ {} }}",
+                server
+                    .variables
+                    .iter()
+                    .map(|(name, variable)| format!("[{:?}] = {:?}", name, variable.default))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    )
+}
+
+const API_CLIENT_USINGS: &str = r#"#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Net.Http;
+using System.Threading;
+using System.Threading.Tasks;
+"#;
+
+const MODEL_USINGS: &str = r#"#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using Newtonsoft.Json.Linq;
+"#;
+
+const RESOURCE_USINGS: &str = r#"#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Threading.Tasks;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+"#;
+
+fn emit_project_file(package: &PackageMetadata, root_namespace: &str) -> String {
+    format!(
+        r#"<Project Sdk="Microsoft.NET.Sdk">
+
+  <PropertyGroup>
+    <TargetFramework>net6.0</TargetFramework>
+    <PackageId>{name}</PackageId>
+    <Version>{version}</Version>
+    <RootNamespace>{root_namespace}</RootNamespace>
+  </PropertyGroup>
+
+  <ItemGroup>
+    <PackageReference Include="Newtonsoft.Json" Version="13.0.3" />
+  </ItemGroup>
+
+</Project>
+"#,
+        name = package.name,
+        version = package.version,
+        root_namespace = root_namespace
     )
 }
+
+fn emit_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::String => "string",
+        Primitive::Boolean => "bool",
+        Primitive::Integer => "int",
+        Primitive::Long => "long",
+        Primitive::Float => "float",
+        Primitive::Double => "double",
+        Primitive::Decimal => "decimal",
+        Primitive::DateTime => "DateTimeOffset",
+        Primitive::Date => "DateOnly",
+        Primitive::Time => "TimeOnly",
+        Primitive::Uuid => "Guid",
+        Primitive::Bytes => "byte[]",
+        Primitive::Binary => "Stream",
+        Primitive::Uri => "Uri",
+        Primitive::Any => "object",
+    }
+}
+
+/// Returns whether the type is represented as a value type in C#.
+///
+/// References are taken to be classes, so references to enums must be resolved first.
+fn is_value_type(ty: &Type) -> bool {
+    match ty {
+        Type::Primitive(Primitive::String)
+        | Type::Primitive(Primitive::Bytes)
+        | Type::Primitive(Primitive::Binary)
+        | Type::Primitive(Primitive::Uri)
+        | Type::Primitive(Primitive::Any) => false,
+        Type::Primitive(_) | Type::Enum { .. } => true,
+        Type::Array(_)
+        | Type::Tuple(_)
+        | Type::Map { .. }
+        | Type::Union { .. }
+        | Type::Record { .. }
+        | Type::Reference(_) => false,
+    }
+}
+
+/// Emits an expression that converts the value of the given type to the string sent over the wire.
+/// Indents each line of the code by the given number of levels, leaving blank lines empty.
+fn indent(code: &str, levels: usize) -> String {
+    code.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", "    ".repeat(levels), line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn emit_to_string(value: &str, ty: &Type) -> String {
+    match ty {
+        Type::Primitive(Primitive::String) => value.to_owned(),
+        _ => format!("JsonConvert.SerializeObject({}).Trim('\"')", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    use sdkgen_core::UrlParameter;
+
+    fn member(name: &str, ty: Type, is_optional: bool) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty,
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn pet() -> Type {
+        Type::Record {
+            name: "Pet".into(),
+            members: vec![
+                member("petId", Type::Primitive(Primitive::String), false),
+                member("born-at", Type::Primitive(Primitive::Date), true),
+            ],
+        }
+    }
+
+    fn get_pet() -> Route {
+        let mut route = Route {
+            name: "getPet".into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets/:petId".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: vec![UrlParameter {
+                name: "petId".into(),
+                ty: Primitive::String,
+            }],
+            query_parameters: Vec::new(),
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: Some(pet()),
+            return_media_type: MediaType::Json,
+            responses: Default::default(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        };
+        route.responses.insert(StatusCode::Code(200), Some(pet()));
+
+        route
+    }
+
+    fn generate(routes: Vec<Route>, path: &str) -> String {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes,
+            }],
+        }];
+
+        CsharpSdk::default()
+            .generate_sdk(type_decls, versions)
+            .unwrap()
+            .into_iter()
+            .find(|(file_path, _)| file_path == Path::new(path))
+            .map(|(_, contents)| contents)
+            .unwrap()
+    }
+
+    #[test]
+    fn records_keep_wire_names() {
+        let model = generate(vec![get_pet()], "Models/Pet.cs");
+
+        assert!(model.contains(
+            r#"[JsonProperty("born-at", NullValueHandling = NullValueHandling.Ignore)]
+    public DateOnly? BornAt { get; set; }"#
+        ));
+    }
+
+    #[test]
+    fn resources_are_clients_of_the_api_client() {
+        let api_client = generate(vec![get_pet()], "ApiClient.cs");
+        let resource = generate(vec![get_pet()], "Resources/Pets.cs");
+
+        assert!(api_client.contains("public V.PetsClient Pets { get; }"));
+        assert!(resource.contains("internal PetsClient(ApiClient client)"));
+        assert!(resource.contains("public async Task<Pet> GetPet(string petId)"));
+    }
+
+    #[test]
+    fn form_fields_are_escaped_and_do_not_shadow_parameters() {
+        let mut route = get_pet();
+        route.name = "updatePet".into();
+        route.method = HttpMethod::Post;
+        route.query_parameters.push(Parameter {
+            name: "fields".into(),
+            ty: Type::Primitive(Primitive::String),
+            is_optional: false,
+            is_nullable: false,
+        });
+        route.payload_type = Some(Type::Record {
+            name: "PetForm".into(),
+            members: vec![member(
+                "say \"hi\"",
+                Type::Primitive(Primitive::String),
+                false,
+            )],
+        });
+        route.payload_media_type = MediaType::FormUrlEncoded;
+
+        let resource = generate(vec![route], "Resources/Pets.cs");
+
+        assert!(resource.contains("string fields_"));
+        assert!(resource.contains(r#"new KeyValuePair<string, string>("say \"hi\"", "#));
+    }
+}
@@ -0,0 +1,154 @@
+use heck::SnakeCase;
+use sdkgen_core::{
+    escape_reserved_word, to_valid_identifier, Case, CasingOverrides, CasingRules, IdentifierCases,
+};
+
+/// The keywords of Go, which are suffixed with an underscore to be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// The names of the types declared by the emitted package, which types cannot shadow.
+const RESERVED_TYPE_NAMES: &[&str] = &["Client", "Error"];
+
+/// The names of the imported packages and the names used by the body of emitted routes, which
+/// parameters cannot shadow.
+const RESERVED_PARAMETER_NAMES: &[&str] = &[
+    "bytes", "context", "cookies", "ctx", "err", "files", "fmt", "form", "header", "http", "io",
+    "json", "query", "result", "s", "strings", "toString", "url",
+];
+
+/// The initialisms that Go keeps in a consistent case, as listed by `golint`.
+const INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL",
+    "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+#[derive(Debug, Clone)]
+pub struct GoCasingRules {
+    pub cases: IdentifierCases,
+    pub overrides: CasingOverrides,
+}
+
+impl GoCasingRules {
+    /// Exported names are in Pascal case and unexported names are in camel case.
+    pub const DEFAULT_CASES: IdentifierCases = IdentifierCases {
+        type_name: Case::Pascal,
+        record_member: Case::Pascal,
+        function_name: Case::Pascal,
+        parameter: Case::Camel,
+        enum_variant: Case::Pascal,
+    };
+
+    fn to_case(&self, value: String, case: Case) -> String {
+        to_go_identifier(self.overrides.apply(value, |value| match case {
+            Case::Pascal => to_go_case(value, true),
+            Case::Camel => to_go_case(value, false),
+            case => case.apply(value),
+        }))
+    }
+}
+
+impl Default for GoCasingRules {
+    fn default() -> Self {
+        Self {
+            cases: Self::DEFAULT_CASES,
+            overrides: CasingOverrides::new(),
+        }
+    }
+}
+
+impl CasingRules<String> for GoCasingRules {
+    fn to_type_name_case(&self, value: String) -> String {
+        escape_reserved_word(
+            self.to_case(value, self.cases.type_name),
+            RESERVED_TYPE_NAMES,
+        )
+    }
+
+    fn to_record_member_case(&self, value: String) -> String {
+        self.to_case(value, self.cases.record_member)
+    }
+
+    fn to_function_name_case(&self, value: String) -> String {
+        self.to_case(value, self.cases.function_name)
+    }
+
+    fn to_parameter_case(&self, value: String) -> String {
+        escape_reserved_word(
+            self.to_case(value, self.cases.parameter),
+            RESERVED_PARAMETER_NAMES,
+        )
+    }
+
+    fn to_enum_variant_case(&self, value: String) -> String {
+        self.to_case(value, self.cases.enum_variant)
+    }
+}
+
+/// Converts the identifier to camel case, writing initialisms entirely in upper case (or in lower
+/// case, when they start an unexported name).
+fn to_go_case(value: String, is_exported: bool) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_snake_case()
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .enumerate()
+        .map(|(index, word)| {
+            let is_first_word = index == 0;
+            let upper = word.to_uppercase();
+
+            if is_first_word && !is_exported {
+                word.to_owned()
+            } else if INITIALISMS.contains(&upper.as_str()) {
+                upper
+            } else {
+                let mut chars = word.chars();
+
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        })
+        .collect()
+}
+
+/// Makes the identifier valid in Go. Identifiers that start with a digit are prefixed with `X`,
+/// rather than an underscore, so that they remain exported.
+fn to_go_identifier(value: String) -> String {
+    let value = match value.chars().next() {
+        Some(first) if !first.is_numeric() => value,
+        _ => format!("X{}", value),
+    };
+
+    escape_reserved_word(to_valid_identifier(value), KEYWORDS)
+}
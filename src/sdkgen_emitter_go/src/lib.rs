@@ -0,0 +1,1430 @@
+mod casing_rules;
+
+use sdkgen_core::{
+    default_servers, find_identifier_collisions, used_primitives, used_security_schemes,
+    ApiKeyLocation, CasingRules, Diagnostics, EnumValue, EnumVariant, FileTree, GenerateSdk,
+    HttpMethod, MediaType, Member, PackageMetadata, Parameter, Primitive, Route, SdkResource,
+    SdkVersion, SecurityScheme, SecuritySchemeKind, Server, Type, TypeDeclarations, TypeMappings,
+    UnionCase, UrlSegment,
+};
+
+pub use crate::casing_rules::GoCasingRules;
+
+#[derive(Debug, Default)]
+pub struct GoSdk {
+    /// The package metadata, whose name is used as the module path.
+    pub package: PackageMetadata,
+    pub casing_rules: GoCasingRules,
+    pub type_mappings: TypeMappings,
+}
+
+impl GenerateSdk for GoSdk {
+    fn generate_sdk(
+        &self,
+        type_decls: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics> {
+        find_identifier_collisions(
+            "Go",
+            &self.casing_rules,
+            |member| self.casing_rules.to_record_member_case(member.name.clone()),
+            &type_decls,
+            &versions,
+        )
+        .into_result(())?;
+
+        let package_name = self.package_name();
+
+        let mut imports = IMPORTS.to_vec();
+
+        if used_primitives(&type_decls, &versions)
+            .iter()
+            .any(|primitive| matches!(primitive, Primitive::DateTime))
+        {
+            imports.push("time");
+        }
+
+        let security_schemes = used_security_schemes(&versions);
+
+        if security_schemes
+            .iter()
+            .any(|scheme| matches!(scheme.kind, SecuritySchemeKind::Basic))
+        {
+            imports.push("encoding/base64");
+        }
+
+        imports.sort_unstable();
+
+        let mut buffer = format!(
+            "package {}\n\nimport (\n{}\n)\n{}",
+            package_name,
+            imports
+                .iter()
+                .map(|import| format!("\t{}", emit_string_literal(import)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PRELUDE
+        );
+
+        for (name, ty) in type_decls.iter() {
+            if self.type_mappings.contains(name) {
+                continue;
+            }
+
+            buffer += &self.emit_type_decl(ty.clone(), &type_decls);
+        }
+
+        let default_servers = default_servers(&versions);
+        let has_overridden_servers = versions
+            .iter()
+            .flat_map(|version| version.resources.iter())
+            .flat_map(|resource| resource.routes.iter())
+            .any(|route| route.overridden_server(&default_servers).is_some());
+
+        buffer += &self.emit_client(&versions, has_overridden_servers);
+        buffer += &emit_apply_credential(&security_schemes);
+
+        if !default_servers.is_empty() || has_overridden_servers {
+            buffer += &emit_servers(&default_servers);
+        }
+
+        for version in versions {
+            for resource in version.resources {
+                buffer += &self.emit_sdk_resource(
+                    version.version.clone(),
+                    resource,
+                    &default_servers,
+                    &type_decls,
+                );
+            }
+        }
+
+        let mut files = FileTree::new();
+        files.add_file(
+            "go.mod",
+            format!("module {}\n\ngo 1.18\n", self.package.name),
+        );
+        files.add_file(format!("{}.go", package_name), buffer);
+
+        Ok(files)
+    }
+}
+
+impl GoSdk {
+    /// Returns the name of the Go package, which is the last element of the module path.
+    fn package_name(&self) -> String {
+        self.package
+            .name
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    fn emit_type_name(&self, ty: Type) -> String {
+        match ty {
+            Type::Primitive(primitive) => emit_primitive(primitive).into(),
+            Type::Array(ty) => format!("[]{}", self.emit_type_name(*ty)),
+            // Go has no tuples, so tuples are slices of their item type, if they all share one.
+            Type::Tuple(item_types) => {
+                let mut item_type_names: Vec<String> = item_types
+                    .into_iter()
+                    .map(|ty| self.emit_type_name(ty))
+                    .collect();
+                item_type_names.dedup();
+
+                match item_type_names.as_slice() {
+                    [item_type_name] => format!("[]{}", item_type_name),
+                    _ => "[]interface{}".into(),
+                }
+            }
+            Type::Map { key, value } => {
+                format!(
+                    "map[{}]{}",
+                    self.emit_type_name(*key),
+                    self.emit_type_name(*value)
+                )
+            }
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => match self.type_mappings.get(&name) {
+                Some(mapped_type) => mapped_type.to_owned(),
+                None => self.casing_rules.to_type_name_case(name),
+            },
+        }
+    }
+
+    /// Emits the name of the type used for an optional value.
+    ///
+    /// Optional values are pointers, except for those that can already be `nil`.
+    fn emit_optional_type_name(&self, ty: Type) -> String {
+        if is_nilable(&ty) {
+            self.emit_type_name(ty)
+        } else {
+            format!("*{}", self.emit_type_name(ty))
+        }
+    }
+
+    fn emit_type_decl(&self, ty: Type, type_decls: &TypeDeclarations) -> String {
+        match ty {
+            Type::Record { name, members } => format!(
+                r#"
+type {name} struct {{
+	{members}
+}}
+"#,
+                name = self.casing_rules.to_type_name_case(name.clone()),
+                members = emit_aligned(
+                    members
+                        .into_iter()
+                        .map(|value| self.emit_member(value, &name, type_decls))
+                        .collect()
+                )
+            ),
+            Type::Union {
+                name,
+                discriminator,
+                cases,
+            } => self.emit_union_decl(name, discriminator, cases),
+            Type::Enum { name, variants } => self.emit_enum_decl(name, variants),
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => "".into(),
+        }
+    }
+
+    /// Emits the name, type and tag of the struct field for the member of the named record.
+    fn emit_member(
+        &self,
+        member: Member,
+        record_name: &str,
+        type_decls: &TypeDeclarations,
+    ) -> Vec<String> {
+        let omit_empty = if member.is_optional { ",omitempty" } else { "" };
+
+        let type_name = if is_pointer_field(&member, record_name, type_decls) {
+            format!("*{}", self.emit_type_name(member.ty))
+        } else {
+            self.emit_type_name(member.ty)
+        };
+
+        vec![
+            self.casing_rules.to_record_member_case(member.name.clone()),
+            type_name,
+            format!("`json:\"{}{}\"`", member.wire_name, omit_empty),
+        ]
+    }
+
+    /// Emits an enum as a named type with a constant for each variant.
+    fn emit_enum_decl(&self, name: String, variants: Vec<EnumVariant>) -> String {
+        let is_string_enum = variants
+            .iter()
+            .any(|variant| matches!(variant.value, EnumValue::String(_)));
+
+        let name = self.casing_rules.to_type_name_case(name);
+
+        format!(
+            r#"
+type {name} {underlying_type}
+
+const (
+	{variants}
+)
+"#,
+            underlying_type = if is_string_enum {
+                emit_primitive(Primitive::String)
+            } else {
+                emit_primitive(Primitive::Integer)
+            },
+            variants = emit_aligned(
+                variants
+                    .into_iter()
+                    .map(|variant| vec![
+                        self.casing_rules
+                            .to_enum_variant_case(format!("{} {}", name, variant.name)),
+                        name.clone(),
+                        format!(
+                            "= {}",
+                            match variant.value {
+                                EnumValue::String(value) => emit_string_literal(&value),
+                                EnumValue::Integer(value) => value.to_string(),
+                            }
+                        ),
+                    ])
+                    .collect()
+            ),
+            name = name
+        )
+    }
+
+    /// Emits a union as a struct with a field for each case, of which only one is set.
+    ///
+    /// The union is (de)serialized as the value of its case, using the discriminator to select the
+    /// case when one is present and otherwise trying each case in order.
+    fn emit_union_decl(
+        &self,
+        name: String,
+        discriminator: Option<String>,
+        cases: Vec<UnionCase>,
+    ) -> String {
+        let name = self.casing_rules.to_type_name_case(name);
+
+        let cases: Vec<(UnionCase, String)> = cases
+            .into_iter()
+            .map(|case| {
+                let field_name = self.casing_rules.to_record_member_case(case.name.clone());
+
+                (case, field_name)
+            })
+            .collect();
+
+        let fields = emit_aligned(
+            cases
+                .iter()
+                .map(|(case, field_name)| {
+                    vec![
+                        field_name.clone(),
+                        self.emit_optional_type_name(case.ty.clone()),
+                    ]
+                })
+                .collect(),
+        );
+
+        let marshal_cases = cases
+            .iter()
+            .map(|(_, field_name)| {
+                format!(
+                    r#"case u.{field_name} != nil:
+		return json.Marshal(u.{field_name})"#,
+                    field_name = field_name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\t");
+
+        let unmarshal_cases = match discriminator {
+            Some(discriminator) => format!(
+                r#"var tag struct {{
+		Value string `json:"{discriminator}"`
+	}}
+	if err := json.Unmarshal(data, &tag); err != nil {{
+		return err
+	}}
+
+	switch tag.Value {{
+	{cases}
+	}}
+
+	return fmt.Errorf("unknown {discriminator} %q for {name}", tag.Value)"#,
+                discriminator = discriminator,
+                name = name,
+                cases = cases
+                    .iter()
+                    .map(|(case, field_name)| format!(
+                        r#"case {tag}:
+		return json.Unmarshal(data, &u.{field_name})"#,
+                        tag = emit_string_literal(&case.name),
+                        field_name = field_name
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n\t")
+            ),
+            None => format!(
+                r#"{cases}
+
+	return fmt.Errorf("value does not match any case of {name}")"#,
+                name = name,
+                cases = cases
+                    .iter()
+                    .map(|(case, field_name)| format!(
+                        r#"{{
+		var value {case_type}
+		if err := decodeStrict(data, &value); err == nil {{
+			u.{field_name} = {reference}value
+			return nil
+		}}
+	}}"#,
+                        case_type = self.emit_type_name(case.ty.clone()),
+                        field_name = field_name,
+                        reference = if is_nilable(&case.ty) { "" } else { "&" }
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n\t")
+            ),
+        };
+
+        format!(
+            r#"
+type {name} struct {{
+	{fields}
+}}
+
+func (u {name}) MarshalJSON() ([]byte, error) {{
+	switch {{
+	{marshal_cases}
+	}}
+
+	return []byte("null"), nil
+}}
+
+func (u *{name}) UnmarshalJSON(data []byte) error {{
+	{unmarshal_cases}
+}}
+"#,
+            name = name,
+            fields = fields,
+            marshal_cases = marshal_cases,
+            unmarshal_cases = unmarshal_cases
+        )
+    }
+
+    /// Returns the name of the service for the resource and the name of its field on the `Client`.
+    fn service_names(&self, version: &str, resource: &str) -> (String, String) {
+        let field_name = self
+            .casing_rules
+            .to_record_member_case(format!("{} {}", version, resource));
+
+        (format!("{}Service", field_name), field_name)
+    }
+
+    /// Emits the `Client`, which exposes a service for each resource.
+    ///
+    /// Clients of APIs with routes sent to their own servers hold the values of the variables in
+    /// the URLs of those servers.
+    fn emit_client(&self, versions: &[SdkVersion], has_overridden_servers: bool) -> String {
+        let services: Vec<(String, String)> = versions
+            .iter()
+            .flat_map(|version| {
+                version
+                    .resources
+                    .iter()
+                    .map(move |resource| self.service_names(&version.version, &resource.resource))
+            })
+            .collect();
+
+        let mut client_fields = vec![
+            vec!["httpClient".to_string(), "*http.Client".to_string()],
+            vec!["baseURL".to_string(), "string".to_string()],
+            vec!["credentials".to_string(), "CredentialsProvider".to_string()],
+        ];
+
+        if has_overridden_servers {
+            client_fields.push(vec![
+                "serverVariables".to_string(),
+                "map[string]string".to_string(),
+            ]);
+        }
+
+        format!(
+            r#"
+// Client is a client for the API.
+type Client struct {{
+	{client_fields}
+
+	{fields}
+}}
+
+// NewClient returns a client that sends requests to the API at the given base URL, using
+// http.DefaultClient when httpClient is nil.
+func NewClient(httpClient *http.Client, baseURL string) *Client {{
+	if httpClient == nil {{
+		httpClient = http.DefaultClient
+	}}
+
+	c := &Client{{httpClient: httpClient, baseURL: baseURL}}
+	{initializers}
+
+	return c
+}}
+
+// WithCredentials sets the provider of the credentials that requests are authorized with.
+func (c *Client) WithCredentials(provider CredentialsProvider) *Client {{
+	c.credentials = provider
+
+	return c
+}}
+{with_server_variables}"#,
+            client_fields = emit_aligned(client_fields),
+            with_server_variables = if has_overridden_servers {
+                r#"
+// WithServerVariables sets the values of the variables in the URLs of the servers that some routes
+// are sent to instead of the base URL, which otherwise take their defaults.
+func (c *Client) WithServerVariables(variables map[string]string) *Client {
+	c.serverVariables = variables
+
+	return c
+}
+"#
+            } else {
+                ""
+            },
+            fields = emit_aligned(
+                services
+                    .iter()
+                    .map(|(service_name, field_name)| vec![
+                        field_name.clone(),
+                        format!("*{}", service_name)
+                    ])
+                    .collect()
+            ),
+            initializers = services
+                .iter()
+                .map(|(service_name, field_name)| format!(
+                    "c.{} = &{}{{client: c}}",
+                    field_name, service_name
+                ))
+                .collect::<Vec<_>>()
+                .join("\n\t")
+        )
+    }
+
+    fn emit_sdk_resource(
+        &self,
+        version: String,
+        resource: SdkResource,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let (service_name, _) = self.service_names(&version, &resource.resource);
+
+        let methods = resource
+            .routes
+            .into_iter()
+            .map(|route| self.emit_route(&service_name, route, default_servers, type_decls))
+            .collect::<String>();
+
+        format!(
+            r#"
+type {service_name} struct {{
+	client *Client
+}}
+{methods}"#,
+            service_name = service_name,
+            methods = methods
+        )
+    }
+
+    fn emit_parameter(&self, parameter: &Parameter) -> String {
+        let type_name = if parameter.is_optional || parameter.is_nullable {
+            self.emit_optional_type_name(parameter.ty.clone())
+        } else {
+            self.emit_type_name(parameter.ty.clone())
+        };
+
+        format!(
+            "{} {}",
+            self.casing_rules.to_parameter_case(parameter.name.clone()),
+            type_name
+        )
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the parameter.
+    ///
+    /// Slice parameters produce one statement per item, and optional parameters are skipped when
+    /// `nil`.
+    fn emit_parameter_values(
+        &self,
+        parameter: &Parameter,
+        emit_statement: impl Fn(&str) -> String,
+    ) -> String {
+        let name = self.casing_rules.to_parameter_case(parameter.name.clone());
+
+        match parameter.ty {
+            Type::Array(_) => format!(
+                r#"for _, item := range {name} {{
+		{statement}
+	}}"#,
+                name = name,
+                statement = emit_statement("toString(item)")
+            ),
+            Type::Tuple(_) | Type::Map { .. } => emit_statement(&format!("toString({})", name)),
+            ref ty if (parameter.is_optional || parameter.is_nullable) && is_nilable(ty) => {
+                format!(
+                    r#"if {name} != nil {{
+		{statement}
+	}}"#,
+                    name = name,
+                    statement = emit_statement(&format!("toString({})", name))
+                )
+            }
+            _ if parameter.is_optional || parameter.is_nullable => format!(
+                r#"if {name} != nil {{
+		{statement}
+	}}"#,
+                name = name,
+                statement = emit_statement(&format!("toString(*{})", name))
+            ),
+            _ => emit_statement(&format!("toString({})", name)),
+        }
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the member of the record,
+    /// which holds a value of the named record type.
+    ///
+    /// Slice members produce one statement per item, and optional members are skipped when `nil`.
+    fn emit_member_values(
+        &self,
+        record: &str,
+        record_name: &str,
+        member: &Member,
+        type_decls: &TypeDeclarations,
+        emit_statement: impl Fn(&str, &Type) -> String,
+    ) -> String {
+        let value = format!(
+            "{}.{}",
+            record,
+            self.casing_rules.to_record_member_case(member.name.clone())
+        );
+
+        match &member.ty {
+            Type::Array(item_type) => format!(
+                r#"for _, item := range {value} {{
+		{statement}
+	}}"#,
+                value = value,
+                statement = emit_statement("item", item_type)
+            ),
+            ty @ Type::Tuple(_) | ty @ Type::Map { .. } => emit_statement(&value, ty),
+            ty if is_pointer_field(member, record_name, type_decls) => format!(
+                r#"if {value} != nil {{
+		{statement}
+	}}"#,
+                value = value,
+                statement = emit_statement(&format!("*{}", value), ty)
+            ),
+            ty if member.is_optional || member.is_nullable => format!(
+                r#"if {value} != nil {{
+		{statement}
+	}}"#,
+                value = value,
+                statement = emit_statement(&value, ty)
+            ),
+            ty => emit_statement(&value, ty),
+        }
+    }
+
+    /// Emits the statements that encode the payload in the format that the route accepts, returning
+    /// them along with the expression that is passed to `do` as the payload.
+    ///
+    /// Forms can only be built from records, so any other payload is sent as JSON.
+    fn emit_payload(&self, route: &Route, type_decls: &TypeDeclarations) -> (Vec<String>, String) {
+        let payload = self.casing_rules.to_parameter_case("payload".into());
+        let mut setup = Vec::new();
+
+        let payload_type = route
+            .payload_type
+            .as_ref()
+            .map(|payload_type| type_decls.resolve(payload_type));
+        let record_name = payload_type.and_then(Type::name).unwrap_or_default();
+
+        let value = match (&route.payload_media_type, payload_type) {
+            (_, None) => "nil".to_string(),
+            (MediaType::FormUrlEncoded, Some(Type::Record { members, .. })) => {
+                setup.push("form := url.Values{}".to_string());
+                setup.extend(members.iter().map(|member| {
+                    self.emit_member_values(
+                        &payload,
+                        record_name,
+                        member,
+                        type_decls,
+                        |value, _| {
+                            format!(
+                                "form.Add({}, toString({}))",
+                                emit_string_literal(&member.wire_name),
+                                value
+                            )
+                        },
+                    )
+                }));
+
+                r#"&rawBody{contentType: "application/x-www-form-urlencoded", data: strings.NewReader(form.Encode())}"#
+                    .to_string()
+            }
+            (MediaType::Multipart, Some(Type::Record { members, .. })) => {
+                setup.push("form := url.Values{}".to_string());
+                setup.push("files := map[string][][]byte{}".to_string());
+                setup.extend(members.iter().map(|member| {
+                    let name = emit_string_literal(&member.wire_name);
+
+                    self.emit_member_values(
+                        &payload,
+                        record_name,
+                        member,
+                        type_decls,
+                        |value, ty| match ty {
+                            Type::Primitive(Primitive::Binary) => format!(
+                                "files[{name}] = append(files[{name}], {value})",
+                                name = name,
+                                value = value
+                            ),
+                            _ => format!("form.Add({}, toString({}))", name, value),
+                        },
+                    )
+                }));
+
+                "multipartBody(form, files)".to_string()
+            }
+            (MediaType::Text(media_type), Some(_)) => format!(
+                "&rawBody{{contentType: {}, data: strings.NewReader({})}}",
+                emit_string_literal(media_type),
+                payload
+            ),
+            (MediaType::Binary(media_type), Some(_)) => format!(
+                "&rawBody{{contentType: {}, data: bytes.NewReader({})}}",
+                emit_string_literal(media_type),
+                payload
+            ),
+            (_, Some(_)) => payload,
+        };
+
+        (setup, value)
+    }
+
+    fn emit_route(
+        &self,
+        service_name: &str,
+        route: Route,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let parameter_list = route
+            .all_parameters()
+            .iter()
+            .map(|parameter| format!(", {}", self.emit_parameter(parameter)))
+            .collect::<String>();
+
+        let mut setup = Vec::new();
+
+        let query = if route.query_parameters.is_empty() {
+            "nil"
+        } else {
+            setup.push("query := url.Values{}".to_string());
+            setup.extend(route.query_parameters.iter().map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        "query.Add({}, {})",
+                        emit_string_literal(&parameter.name),
+                        value
+                    )
+                })
+            }));
+
+            "query"
+        };
+
+        let header = if route.header_parameters.is_empty() && route.cookie_parameters.is_empty() {
+            "nil"
+        } else {
+            setup.push("header := http.Header{}".to_string());
+            setup.extend(route.header_parameters.iter().map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        "header.Add({}, {})",
+                        emit_string_literal(&parameter.name),
+                        value
+                    )
+                })
+            }));
+
+            if !route.cookie_parameters.is_empty() {
+                setup.push("var cookies []string".to_string());
+                setup.extend(route.cookie_parameters.iter().map(|parameter| {
+                    self.emit_parameter_values(parameter, |value| {
+                        format!(
+                            "cookies = append(cookies, {}+url.QueryEscape({}))",
+                            emit_string_literal(&format!("{}=", parameter.name)),
+                            value
+                        )
+                    })
+                }));
+                setup.push(
+                    r#"if len(cookies) > 0 {
+		header.Set("Cookie", strings.Join(cookies, "; "))
+	}"#
+                    .to_string(),
+                );
+            }
+
+            "header"
+        };
+
+        let mut path_format = Vec::new();
+        let mut path_arguments = Vec::new();
+
+        for segment in route.url_segments() {
+            match segment {
+                UrlSegment::Parameter(param) => {
+                    path_format.push("%s".to_string());
+                    path_arguments.push(format!(
+                        ", url.PathEscape(toString({}))",
+                        self.casing_rules.to_parameter_case(param)
+                    ));
+                }
+                UrlSegment::Literal(value) => path_format.push(value.replace('%', "%%")),
+            }
+        }
+
+        let path = if path_arguments.is_empty() {
+            emit_string_literal(&path_format.join("/").replace("%%", "%"))
+        } else {
+            format!(
+                "fmt.Sprintf({}{})",
+                emit_string_literal(&path_format.join("/")),
+                path_arguments.concat()
+            )
+        };
+
+        let security = if route.security.is_empty() {
+            "nil".to_string()
+        } else {
+            format!(
+                "[][]securityScheme{{{}}}",
+                route
+                    .security
+                    .iter()
+                    .map(|requirement| format!(
+                        "{{{}}}",
+                        requirement
+                            .schemes
+                            .iter()
+                            .map(|(scheme, scopes)| format!(
+                                "{{{}, {}}}",
+                                emit_string_literal(&scheme.name),
+                                if scopes.is_empty() {
+                                    "nil".to_string()
+                                } else {
+                                    format!(
+                                        "[]string{{{}}}",
+                                        scopes
+                                            .iter()
+                                            .map(|scope| emit_string_literal(scope))
+                                            .collect::<Vec<_>>()
+                                            .join(", ")
+                                    )
+                                }
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+
+        let (payload_setup, payload) = self.emit_payload(&route, type_decls);
+        setup.extend(payload_setup);
+
+        // Routes sent to their own server use it in place of the base URL of the client.
+        let base_url = match route.overridden_server(default_servers) {
+            Some(server) => format!(
+                "Server{}.URLWith(s.client.serverVariables)",
+                emit_server(server)
+            ),
+            None => "s.client.baseURL".to_string(),
+        };
+
+        // Binary responses are read as they are, rather than as base64-encoded JSON.
+        let result = match route.return_media_type {
+            MediaType::Binary(_) if route.return_type.is_some() => "(*rawBytes)(&result)",
+            _ => "&result",
+        };
+
+        let return_type = route.return_type.map(|ty| self.emit_type_name(ty));
+
+        let function_name = self.casing_rules.to_function_name_case(route.name);
+
+        let description = route
+            .description
+            .map(|description| {
+                format!(
+                    "\n// {} {}",
+                    function_name,
+                    description.to_string().replace('\n', "\n// ")
+                )
+            })
+            .unwrap_or_default();
+
+        let call = format!(
+            "s.client.do(ctx, {http_method}, {base_url}, {path}, {query}, {header}, {security}, {payload}, {result})",
+            http_method = match route.method {
+                HttpMethod::Get => "http.MethodGet",
+                HttpMethod::Post => "http.MethodPost",
+                HttpMethod::Put => "http.MethodPut",
+                HttpMethod::Patch => "http.MethodPatch",
+                HttpMethod::Delete => "http.MethodDelete",
+            },
+            base_url = base_url,
+            path = path,
+            query = query,
+            header = header,
+            security = security,
+            payload = payload,
+            result = if return_type.is_some() { result } else { "nil" }
+        );
+
+        // Routes whose successful responses have no body only return an error.
+        let (results, body) = match return_type {
+            Some(return_type) => (
+                format!("({}, error)", return_type),
+                format!(
+                    "var result {}\n\terr := {}\n\n\treturn result, err",
+                    return_type, call
+                ),
+            ),
+            None => ("error".to_string(), format!("return {}", call)),
+        };
+
+        format!(
+            r#"{description}
+func (s *{service_name}) {function_name}(ctx context.Context{parameter_list}) {results} {{
+	{setup}{body}
+}}
+"#,
+            description = description,
+            service_name = service_name,
+            function_name = function_name,
+            parameter_list = parameter_list,
+            results = results,
+            setup = setup
+                .into_iter()
+                .map(|statement| statement + "\n\t")
+                .collect::<String>(),
+            body = body
+        )
+    }
+}
+
+/// The packages imported by every SDK.
+const IMPORTS: &[&str] = &[
+    "bytes",
+    "context",
+    "encoding/json",
+    "fmt",
+    "io",
+    "mime/multipart",
+    "net/http",
+    "net/url",
+    "strings",
+];
+
+const PRELUDE: &str = r#"
+// Error is returned when the API responds with an unsuccessful status code.
+type Error struct {
+	StatusCode int
+	Body       []byte
+}
+
+func (e *Error) Error() string {
+	return fmt.Sprintf("unexpected status code %d: %s", e.StatusCode, e.Body)
+}
+
+func (c *Client) do(ctx context.Context, method string, baseURL string, path string, query url.Values, header http.Header, security [][]securityScheme, payload interface{}, result interface{}) error {
+	if len(security) > 0 {
+		if query == nil {
+			query = url.Values{}
+		}
+		if header == nil {
+			header = http.Header{}
+		}
+		if err := c.authorize(ctx, security, query, header); err != nil {
+			return err
+		}
+	}
+
+	endpoint := strings.TrimSuffix(baseURL, "/") + path
+	if len(query) > 0 {
+		endpoint += "?" + query.Encode()
+	}
+
+	var body io.Reader
+	contentType := "application/json"
+	if raw, ok := payload.(*rawBody); ok {
+		body = raw.data
+		contentType = raw.contentType
+	} else if payload != nil {
+		data, err := json.Marshal(payload)
+		if err != nil {
+			return err
+		}
+		body = bytes.NewReader(data)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, endpoint, body)
+	if err != nil {
+		return err
+	}
+	for key, values := range header {
+		req.Header[key] = values
+	}
+	if payload != nil {
+		req.Header.Set("Content-Type", contentType)
+	}
+
+	resp, err := c.httpClient.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	data, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return err
+	}
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return &Error{StatusCode: resp.StatusCode, Body: data}
+	}
+
+	if result == nil {
+		return nil
+	}
+	if text, ok := result.(*string); ok {
+		*text = string(data)
+		return nil
+	}
+	if raw, ok := result.(*rawBytes); ok {
+		*raw = data
+		return nil
+	}
+	return json.Unmarshal(data, result)
+}
+
+// CredentialsProvider supplies the credentials for the security schemes of the API.
+type CredentialsProvider interface {
+	// Credential returns the credential for the security scheme, or false if there is none.
+	//
+	// The credential is the API key for API key schemes, "username:password" for HTTP basic
+	// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+	Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+}
+
+// CredentialsProviderFunc adapts a function to a CredentialsProvider.
+type CredentialsProviderFunc func(ctx context.Context, scheme string, scopes []string) (string, bool, error)
+
+// Credential calls f(ctx, scheme, scopes).
+func (f CredentialsProviderFunc) Credential(ctx context.Context, scheme string, scopes []string) (string, bool, error) {
+	return f(ctx, scheme, scopes)
+}
+
+// securityScheme is a security scheme that a request must satisfy, along with the scopes it
+// requires.
+type securityScheme struct {
+	name   string
+	scopes []string
+}
+
+// authorize adds the credentials for the first of the security requirements that the provider
+// can satisfy to the query and header of a request.
+func (c *Client) authorize(ctx context.Context, requirements [][]securityScheme, query url.Values, header http.Header) error {
+	for _, requirement := range requirements {
+		credentials := make([]string, 0, len(requirement))
+		for _, scheme := range requirement {
+			if c.credentials == nil {
+				break
+			}
+			credential, ok, err := c.credentials.Credential(ctx, scheme.name, scheme.scopes)
+			if err != nil {
+				return err
+			}
+			if !ok {
+				break
+			}
+			credentials = append(credentials, credential)
+		}
+		if len(credentials) == len(requirement) {
+			for i, scheme := range requirement {
+				applyCredential(scheme.name, credentials[i], query, header)
+			}
+			return nil
+		}
+	}
+	return nil
+}
+
+// rawBody is a payload that is sent as it is, rather than as JSON.
+type rawBody struct {
+	contentType string
+	data        io.Reader
+}
+
+// rawBytes receives a response body as it is, rather than as JSON.
+type rawBytes []byte
+
+// multipartBody encodes the fields and files as a multipart/form-data payload. Writing to a
+// buffer cannot fail, so neither can encoding.
+func multipartBody(fields url.Values, files map[string][][]byte) *rawBody {
+	var buffer bytes.Buffer
+	writer := multipart.NewWriter(&buffer)
+	for name, values := range fields {
+		for _, value := range values {
+			_ = writer.WriteField(name, value)
+		}
+	}
+	for name, contents := range files {
+		for _, content := range contents {
+			part, _ := writer.CreateFormFile(name, name)
+			_, _ = part.Write(content)
+		}
+	}
+	_ = writer.Close()
+	return &rawBody{contentType: writer.FormDataContentType(), data: &buffer}
+}
+
+func decodeStrict(data []byte, value interface{}) error {
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	decoder.DisallowUnknownFields()
+	return decoder.Decode(value)
+}
+
+func toString(value interface{}) string {
+	if text, ok := value.(string); ok {
+		return text
+	}
+	data, _ := json.Marshal(value)
+	return strings.Trim(string(data), `"`)
+}
+"#;
+
+fn emit_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::String => "string",
+        Primitive::Boolean => "bool",
+        Primitive::Integer | Primitive::Long => "int64",
+        Primitive::Float => "float32",
+        Primitive::Double | Primitive::Decimal => "float64",
+        Primitive::DateTime => "time.Time",
+        // The time package only parses full timestamps, so dates and times stay strings.
+        Primitive::Date | Primitive::Time | Primitive::Uuid | Primitive::Uri => "string",
+        Primitive::Bytes | Primitive::Binary => "[]byte",
+        Primitive::Any => "interface{}",
+    }
+}
+
+/// Emits the type of the servers that host the API, which fills in the variables in their URLs,
+/// along with the servers themselves.
+fn emit_servers(servers: &[Server]) -> String {
+    let mut buffer = String::from(
+        r#"
+// Server is a server that hosts the API, whose URL may hold variables written as {name}.
+type Server struct {
+	URL string
+	// Variables are the default values of the variables.
+	Variables map[string]string
+}
+
+// URLWith returns the URL of the server, with its variables replaced by the given values or
+// otherwise their defaults.
+func (s Server) URLWith(variables map[string]string) string {
+	result := s.URL
+	for name, value := range s.Variables {
+		if given, ok := variables[name]; ok {
+			value = given
+		}
+		result = strings.ReplaceAll(result, "{"+name+"}", value)
+	}
+
+	return result
+}
+"#,
+    );
+
+    if !servers.is_empty() {
+        buffer += &format!(
+            r#"
+// Servers are the servers that host the API.
+var Servers = []Server{{
+	{servers}
+}}
+"#,
+            servers = servers
+                .iter()
+                .map(|server| format!("{},", emit_server(server)))
+                .collect::<Vec<_>>()
+                .join("\n\t")
+        );
+    }
+
+    buffer
+}
+
+/// Emits a `Server` composite literal, without its type.
+fn emit_server(server: &Server) -> String {
+    if server.variables.is_empty() {
+        format!("{{URL: {}}}", emit_string_literal(&server.url))
+    } else {
+        format!(
+            "{{URL: {}, Variables: map[string]string{{{}}}}}",
+            emit_string_literal(&server.url),
+            server
+                .variables
+                .iter()
+                .map(|(name, variable)| format!(
+                    "{}: {}",
+                    emit_string_literal(name),
+                    emit_string_literal(&variable.default)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Emits the function that adds the credential for a security scheme to a request.
+fn emit_apply_credential(schemes: &[SecurityScheme]) -> String {
+    let cases = schemes
+        .iter()
+        .map(|scheme| {
+            let statement = match &scheme.kind {
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    parameter_name,
+                } => format!(
+                    "header.Set({}, credential)",
+                    emit_string_literal(parameter_name)
+                ),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Query,
+                    parameter_name,
+                } => format!(
+                    "query.Add({}, credential)",
+                    emit_string_literal(parameter_name)
+                ),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Cookie,
+                    parameter_name,
+                } => format!(
+                    r#"header.Set("Cookie", strings.TrimPrefix(header.Get("Cookie")+{}+url.QueryEscape(credential), "; "))"#,
+                    emit_string_literal(&format!("; {}=", parameter_name))
+                ),
+                SecuritySchemeKind::Basic => {
+                    r#"header.Set("Authorization", "Basic "+base64.StdEncoding.EncodeToString([]byte(credential)))"#
+                        .to_string()
+                }
+                SecuritySchemeKind::Bearer { .. }
+                | SecuritySchemeKind::OAuth2 { .. }
+                | SecuritySchemeKind::OpenIdConnect { .. } => {
+                    r#"header.Set("Authorization", "Bearer "+credential)"#.to_string()
+                }
+            };
+
+            format!(
+                "\n\tcase {}:\n\t\t{}",
+                emit_string_literal(&scheme.name),
+                statement
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"
+// applyCredential adds the credential for the security scheme to the query or header of a request.
+func applyCredential(scheme string, credential string, query url.Values, header http.Header) {{
+	switch scheme {{{cases}
+	}}
+}}
+"#,
+        cases = cases
+    )
+}
+
+/// Returns whether the struct field for the member of the named record is a pointer.
+///
+/// Optional members are pointers unless their values can already be `nil`, as are members that
+/// hold a value of the record, since a struct cannot contain itself.
+fn is_pointer_field(member: &Member, record_name: &str, type_decls: &TypeDeclarations) -> bool {
+    !is_nilable(&member.ty)
+        && (member.is_optional
+            || member.is_nullable
+            || type_decls.contains(&member.ty, record_name))
+}
+
+/// Returns whether values of the type can already be `nil`, so optional ones need no pointer.
+fn is_nilable(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Primitive(Primitive::Bytes)
+            | Type::Primitive(Primitive::Binary)
+            | Type::Primitive(Primitive::Any)
+    )
+}
+
+/// Emits the rows of cells, such as the names, types and tags of struct fields, each on their own
+/// line with their columns aligned as gofmt aligns them.
+fn emit_aligned(rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = Vec::new();
+
+    for row in rows.iter() {
+        // The last cell of a row is not padded, so it does not widen its column.
+        for (index, cell) in row.iter().enumerate().take(row.len().saturating_sub(1)) {
+            let width = cell.chars().count();
+
+            match widths.get_mut(index) {
+                Some(max_width) => *max_width = (*max_width).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            let last = row.len().saturating_sub(1);
+
+            row.into_iter()
+                .enumerate()
+                .map(|(index, cell)| {
+                    if index == last {
+                        cell
+                    } else {
+                        format!("{:width$} ", cell, width = widths[index])
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n\t")
+}
+
+/// Emits a Go string literal.
+fn emit_string_literal(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    use sdkgen_core::{StatusCode, UrlParameter};
+
+    fn member(name: &str, ty: Type, is_optional: bool) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty,
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn pet() -> Type {
+        Type::Record {
+            name: "Pet".into(),
+            members: vec![
+                member("petId", Type::Primitive(Primitive::Long), false),
+                member("photo", Type::Primitive(Primitive::Bytes), true),
+                member("extra", Type::Primitive(Primitive::Any), true),
+            ],
+        }
+    }
+
+    fn get_pet() -> Route {
+        let mut route = Route {
+            name: "getPet".into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets/:petId".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: vec![UrlParameter {
+                name: "petId".into(),
+                ty: Primitive::String,
+            }],
+            query_parameters: vec![Parameter {
+                name: "verbose".into(),
+                ty: Type::Primitive(Primitive::Boolean),
+                is_optional: true,
+                is_nullable: false,
+            }],
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: Some(pet()),
+            return_media_type: MediaType::Json,
+            responses: Default::default(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        };
+        route.responses.insert(StatusCode::Code(200), Some(pet()));
+
+        route
+    }
+
+    fn generate() -> String {
+        let sdk = GoSdk {
+            package: PackageMetadata {
+                name: "example.com/petstore".into(),
+                version: "1.0.0".into(),
+            },
+            ..Default::default()
+        };
+
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes: vec![get_pet()],
+            }],
+        }];
+
+        sdk.generate_sdk(type_decls, versions)
+            .unwrap()
+            .into_iter()
+            .find(|(path, _)| path == Path::new("petstore.go"))
+            .map(|(_, contents)| contents)
+            .unwrap()
+    }
+
+    #[test]
+    fn emits_struct_fields_aligned_as_gofmt_does() {
+        let source = generate();
+
+        assert!(source.contains(
+            "type Pet struct {\n\tPetID int64       `json:\"petId\"`\n\tPhoto []byte      `json:\"photo,omitempty\"`\n\tExtra interface{} `json:\"extra,omitempty\"`\n}\n"
+        ));
+        assert!(source.contains(
+            "type Client struct {\n\thttpClient  *http.Client\n\tbaseURL     string\n\tcredentials CredentialsProvider\n\n\tPets *PetsService\n}\n"
+        ));
+    }
+
+    #[test]
+    fn new_client_falls_back_to_the_default_http_client() {
+        assert!(generate()
+            .contains("\tif httpClient == nil {\n\t\thttpClient = http.DefaultClient\n\t}\n"));
+    }
+
+    #[test]
+    fn emits_service_methods() {
+        let source = generate();
+
+        assert!(source.contains(
+            "func (s *PetsService) GetPet(ctx context.Context, petID string, verbose *bool) (Pet, error) {\n"
+        ));
+        assert!(source.contains(
+            "\tif verbose != nil {\n\t\tquery.Add(\"verbose\", toString(*verbose))\n\t}\n"
+        ));
+    }
+
+    #[test]
+    fn aligns_the_columns_of_rows() {
+        assert_eq!(
+            emit_aligned(vec![
+                vec!["ID".into(), "string".into(), "`json:\"id\"`".into()],
+                vec!["Name".into(), "*string".into(), "`json:\"name\"`".into()],
+            ]),
+            "ID   string  `json:\"id\"`\n\tName *string `json:\"name\"`"
+        );
+    }
+}
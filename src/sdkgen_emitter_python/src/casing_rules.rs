@@ -0,0 +1,111 @@
+use sdkgen_core::{
+    escape_reserved_word, to_valid_identifier, Case, CasingOverrides, CasingRules, IdentifierCases,
+};
+
+/// The keywords of Python, which are suffixed with an underscore to be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// The names imported by the emitted modules, which types cannot shadow.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Annotated",
+    "Any",
+    "BaseModel",
+    "ConfigDict",
+    "Dict",
+    "Field",
+    "ForwardRef",
+    "List",
+    "Literal",
+    "Optional",
+    "Tuple",
+    "TypeAdapter",
+    "Union",
+];
+
+/// The names used within the body of emitted models, which members cannot shadow.
+const RESERVED_MEMBER_NAMES: &[&str] = &["field", "model_config"];
+
+/// The names used by the body of emitted routes, which parameters cannot shadow.
+const RESERVED_PARAMETER_NAMES: &[&str] = &[
+    "cookies", "headers", "httpx", "json", "params", "quote", "response", "self",
+];
+
+#[derive(Debug, Clone)]
+pub struct PythonCasingRules {
+    pub cases: IdentifierCases,
+    pub overrides: CasingOverrides,
+}
+
+impl PythonCasingRules {
+    pub const DEFAULT_CASES: IdentifierCases = IdentifierCases {
+        type_name: Case::Pascal,
+        record_member: Case::Snake,
+        function_name: Case::Snake,
+        parameter: Case::Snake,
+        enum_variant: Case::ScreamingSnake,
+    };
+}
+
+impl Default for PythonCasingRules {
+    fn default() -> Self {
+        Self {
+            cases: Self::DEFAULT_CASES,
+            overrides: CasingOverrides::new(),
+        }
+    }
+}
+
+impl CasingRules<String> for PythonCasingRules {
+    fn to_type_name_case(&self, value: String) -> String {
+        escape_reserved_word(
+            to_python_identifier(
+                self.overrides
+                    .apply(value, |value| self.cases.type_name.apply(value)),
+            ),
+            RESERVED_TYPE_NAMES,
+        )
+    }
+
+    fn to_record_member_case(&self, value: String) -> String {
+        escape_reserved_word(
+            to_python_identifier(
+                self.overrides
+                    .apply(value, |value| self.cases.record_member.apply(value)),
+            ),
+            RESERVED_MEMBER_NAMES,
+        )
+    }
+
+    fn to_function_name_case(&self, value: String) -> String {
+        to_python_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.function_name.apply(value)),
+        )
+    }
+
+    fn to_parameter_case(&self, value: String) -> String {
+        escape_reserved_word(
+            to_python_identifier(
+                self.overrides
+                    .apply(value, |value| self.cases.parameter.apply(value)),
+            ),
+            RESERVED_PARAMETER_NAMES,
+        )
+    }
+
+    fn to_enum_variant_case(&self, value: String) -> String {
+        to_python_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.enum_variant.apply(value)),
+        )
+    }
+}
+
+fn to_python_identifier(value: String) -> String {
+    escape_reserved_word(to_valid_identifier(value), KEYWORDS)
+}
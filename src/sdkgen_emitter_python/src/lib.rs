@@ -0,0 +1,1285 @@
+mod casing_rules;
+
+use sdkgen_core::{
+    default_servers, find_identifier_collisions, used_security_schemes, ApiKeyLocation,
+    CasingRules, Diagnostics, EnumValue, FileTree, GenerateSdk, HttpMethod, MediaType, Member,
+    PackageMetadata, Parameter, Primitive, Route, SdkResource, SdkVersion, SecurityScheme,
+    SecuritySchemeKind, Server, Type, TypeDeclarations, TypeMappings, UrlSegment,
+};
+
+pub use crate::casing_rules::PythonCasingRules;
+
+/// The kind of classes emitted for records.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum PythonModelStyle {
+    /// Plain `dataclasses`, (de)serialized by the helpers emitted alongside them.
+    #[default]
+    Dataclasses,
+    /// Pydantic models.
+    Pydantic,
+}
+
+#[derive(Debug, Default)]
+pub struct PythonSdk {
+    pub package: PackageMetadata,
+    pub model_style: PythonModelStyle,
+    pub casing_rules: PythonCasingRules,
+    pub type_mappings: TypeMappings,
+}
+
+impl GenerateSdk for PythonSdk {
+    fn generate_sdk(
+        &self,
+        type_decls: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics> {
+        find_identifier_collisions(
+            "Python",
+            &self.casing_rules,
+            |member| self.casing_rules.to_record_member_case(member.name.clone()),
+            &type_decls,
+            &versions,
+        )
+        .into_result(())?;
+
+        let mut buffer = String::from(match self.model_style {
+            PythonModelStyle::Dataclasses => DATACLASSES_PRELUDE,
+            PythonModelStyle::Pydantic => PYDANTIC_PRELUDE,
+        });
+
+        let mut model_names = Vec::new();
+        let mut annotations = Vec::new();
+
+        for (name, ty) in type_decls.iter() {
+            if self.type_mappings.contains(name) {
+                continue;
+            }
+
+            if let Type::Record { name, .. } = ty {
+                model_names.push(self.casing_rules.to_type_name_case(name.clone()));
+            }
+
+            for annotation in self.annotations(ty) {
+                if !annotations.contains(&annotation) {
+                    annotations.push(annotation);
+                }
+            }
+
+            buffer += &self.emit_type_decl(ty.clone());
+        }
+
+        // Dataclasses only hold the annotations of their fields as strings, which are looked up
+        // when decoding rather than evaluated.
+        if let PythonModelStyle::Dataclasses = self.model_style {
+            buffer += &emit_annotation_table(&annotations);
+        }
+
+        // Pydantic models can only be used once the types they reference have been declared.
+        if let PythonModelStyle::Pydantic = self.model_style {
+            buffer += "\n\n";
+            buffer += &model_names
+                .iter()
+                .map(|model_name| format!("{}.model_rebuild()\n", model_name))
+                .collect::<String>();
+        }
+
+        let security_schemes = used_security_schemes(&versions);
+
+        if !security_schemes.is_empty() {
+            buffer += &emit_authorization(&security_schemes);
+        }
+
+        let default_servers = default_servers(&versions);
+        let has_overridden_servers = versions
+            .iter()
+            .flat_map(|version| version.resources.iter())
+            .flat_map(|resource| resource.routes.iter())
+            .any(|route| route.overridden_server(&default_servers).is_some());
+
+        if !default_servers.is_empty() || has_overridden_servers {
+            buffer += &emit_servers(&default_servers);
+        }
+
+        for version in versions {
+            for resource in version.resources {
+                buffer += &self.emit_sdk_resource(
+                    version.version.clone(),
+                    resource,
+                    !security_schemes.is_empty(),
+                    &default_servers,
+                    &type_decls,
+                );
+            }
+        }
+
+        let mut files = FileTree::new();
+        files.add_file("pyproject.toml", self.emit_pyproject());
+        files.add_file(
+            format!(
+                "{}/__init__.py",
+                self.casing_rules
+                    .to_record_member_case(self.package.name.replace('.', " "))
+            ),
+            buffer,
+        );
+
+        Ok(files)
+    }
+}
+
+impl PythonSdk {
+    fn emit_pyproject(&self) -> String {
+        let mut dependencies = vec!["httpx>=0.23"];
+
+        if let PythonModelStyle::Pydantic = self.model_style {
+            dependencies.push("pydantic>=2");
+        }
+
+        format!(
+            r#"[project]
+name = {name:?}
+version = {version:?}
+requires-python = ">=3.9"
+dependencies = [{dependencies}]
+"#,
+            name = normalize_distribution_name(&self.package.name),
+            version = self.package.version,
+            dependencies = dependencies
+                .iter()
+                .map(|dependency| format!("{:?}", dependency))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn emit_type_name(&self, ty: Type) -> String {
+        match ty {
+            Type::Primitive(primitive) => self.emit_primitive(primitive).into(),
+            Type::Array(ty) => format!("List[{}]", self.emit_type_name(*ty)),
+            Type::Tuple(item_types) => format!(
+                "Tuple[{}]",
+                item_types
+                    .into_iter()
+                    .map(|ty| self.emit_type_name(ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Map { key, value } => {
+                format!(
+                    "Dict[{}, {}]",
+                    self.emit_type_name(*key),
+                    self.emit_type_name(*value)
+                )
+            }
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => match self.type_mappings.get(&name) {
+                Some(mapped_type) => mapped_type.to_owned(),
+                None => self.casing_rules.to_type_name_case(name),
+            },
+        }
+    }
+
+    fn emit_type_decl(&self, ty: Type) -> String {
+        match ty {
+            Type::Record { name, members } => self.emit_record_decl(name, members),
+            Type::Union {
+                name,
+                discriminator,
+                cases,
+            } => {
+                // The cases are forward references, as they may be declared after the union.
+                let case_types = cases
+                    .iter()
+                    .map(|case| emit_string_literal(&self.emit_type_name(case.ty.clone())))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let union = match (self.model_style, discriminator) {
+                    (PythonModelStyle::Dataclasses, Some(discriminator)) => format!(
+                        "Annotated[Union[{case_types}], _Tagged({discriminator}, {{{cases}}})]",
+                        case_types = case_types,
+                        discriminator = emit_string_literal(&discriminator),
+                        cases = cases
+                            .into_iter()
+                            .map(|case| format!(
+                                "{}: {}",
+                                emit_string_literal(&case.name),
+                                emit_string_literal(&self.emit_type_name(case.ty))
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    _ => format!("Union[{}]", case_types),
+                };
+
+                format!(
+                    r#"
+
+{name} = {union}
+"#,
+                    name = self.casing_rules.to_type_name_case(name),
+                    union = union
+                )
+            }
+            Type::Enum { name, variants } => format!(
+                r#"
+
+{name} = Literal[{values}]
+"#,
+                name = self.casing_rules.to_type_name_case(name),
+                values = variants
+                    .into_iter()
+                    .map(|variant| match variant.value {
+                        EnumValue::String(value) => emit_string_literal(&value),
+                        EnumValue::Integer(value) => value.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => "".into(),
+        }
+    }
+
+    fn emit_member_type_name(&self, member: &Member) -> String {
+        if member.is_optional || member.is_nullable {
+            format!("Optional[{}]", self.emit_type_name(member.ty.clone()))
+        } else {
+            self.emit_type_name(member.ty.clone())
+        }
+    }
+
+    /// Returns the annotations that the declaration of the type refers to as strings: the types of
+    /// the members of a record and of the cases of a union.
+    fn annotations(&self, ty: &Type) -> Vec<String> {
+        match ty {
+            Type::Record { members, .. } => members
+                .iter()
+                .map(|member| self.emit_member_type_name(member))
+                .collect(),
+            Type::Union { cases, .. } => cases
+                .iter()
+                .map(|case| self.emit_type_name(case.ty.clone()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Emits a record as a dataclass or Pydantic model.
+    ///
+    /// Members whose Python name differs from their name on the wire keep track of the latter, and
+    /// optional members are moved after the required ones so that they can default to `None`.
+    fn emit_record_decl(&self, name: String, members: Vec<Member>) -> String {
+        let (optional_members, required_members): (Vec<Member>, Vec<Member>) =
+            members.into_iter().partition(|member| member.is_optional);
+
+        let mut lines = Vec::new();
+
+        if let PythonModelStyle::Pydantic = self.model_style {
+            lines.push("model_config = ConfigDict(populate_by_name=True)\n".to_string());
+        }
+
+        for member in required_members.into_iter().chain(optional_members) {
+            let member_name = self.casing_rules.to_record_member_case(member.name.clone());
+            let type_name = self.emit_member_type_name(&member);
+
+            let mut field_args = Vec::new();
+
+            if member.is_optional {
+                field_args.push("default=None".to_string());
+            }
+
+            // Dataclasses record which members are sent as `null`, rather than left out, when
+            // they are `None`.
+            let mut metadata = Vec::new();
+
+            if member_name != member.wire_name {
+                match self.model_style {
+                    PythonModelStyle::Dataclasses => metadata.push(format!(
+                        "\"json\": {}",
+                        emit_string_literal(&member.wire_name)
+                    )),
+                    PythonModelStyle::Pydantic => {
+                        field_args.push(format!("alias={}", emit_string_literal(&member.wire_name)))
+                    }
+                }
+            }
+
+            if let PythonModelStyle::Dataclasses = self.model_style {
+                if member.is_nullable && !member.is_optional {
+                    metadata.push("\"nullable\": True".to_string());
+                }
+            }
+
+            if !metadata.is_empty() {
+                field_args.push(format!("metadata={{{}}}", metadata.join(", ")));
+            }
+
+            let default = match field_args.as_slice() {
+                [] => "".to_string(),
+                [default] if default == "default=None" => " = None".to_string(),
+                _ => format!(
+                    " = {}({})",
+                    match self.model_style {
+                        PythonModelStyle::Dataclasses => "field",
+                        PythonModelStyle::Pydantic => "Field",
+                    },
+                    field_args.join(", ")
+                ),
+            };
+
+            lines.push(format!("{}: {}{}", member_name, type_name, default));
+        }
+
+        if lines.is_empty() {
+            lines.push("pass".to_string());
+        }
+
+        format!(
+            r#"
+
+{decorator}class {name}{base}:
+    {members}
+"#,
+            decorator = match self.model_style {
+                PythonModelStyle::Dataclasses => "@dataclass\n",
+                PythonModelStyle::Pydantic => "",
+            },
+            name = self.casing_rules.to_type_name_case(name),
+            base = match self.model_style {
+                PythonModelStyle::Dataclasses => "",
+                PythonModelStyle::Pydantic => "(BaseModel)",
+            },
+            members = lines.join("\n    ")
+        )
+    }
+
+    /// Emits a sync and an async class for the resource, each wrapping the corresponding `httpx`
+    /// client, along with the provider of credentials if the API has security schemes.
+    fn emit_sdk_resource(
+        &self,
+        _version: String,
+        resource: SdkResource,
+        has_security_schemes: bool,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let resource_name = self
+            .casing_rules
+            .to_type_name_case(format!("{} resource", resource.resource));
+        let has_overridden_servers = resource
+            .routes
+            .iter()
+            .any(|route| route.overridden_server(default_servers).is_some());
+
+        [false, true]
+            .iter()
+            .map(|&is_async| {
+                format!(
+                    r#"
+
+class {prefix}{resource_name}:
+    def __init__(self, client: httpx.{prefix}Client{credentials_parameter}{server_variables_parameter}) -> None:
+        self._client = client{credentials_field}{server_variables_field}
+{routes}"#,
+                    prefix = if is_async { "Async" } else { "" },
+                    credentials_parameter = if has_security_schemes {
+                        ", credentials: Optional[CredentialsProvider] = None"
+                    } else {
+                        ""
+                    },
+                    credentials_field = if has_security_schemes {
+                        "\n        self._credentials = credentials"
+                    } else {
+                        ""
+                    },
+                    server_variables_parameter = if has_overridden_servers {
+                        ", server_variables: Optional[Dict[str, str]] = None"
+                    } else {
+                        ""
+                    },
+                    server_variables_field = if has_overridden_servers {
+                        "\n        self._server_variables = server_variables"
+                    } else {
+                        ""
+                    },
+                    resource_name = resource_name,
+                    routes = resource
+                        .routes
+                        .iter()
+                        .map(|route| self.emit_route(route, is_async, default_servers, type_decls))
+                        .collect::<String>()
+                )
+            })
+            .collect()
+    }
+
+    fn emit_parameter_name(&self, parameter: &str) -> String {
+        self.casing_rules.to_parameter_case(parameter.to_owned())
+    }
+
+    fn emit_parameter(&self, parameter: &Parameter) -> String {
+        let name = self.emit_parameter_name(&parameter.name);
+        let type_name = self.emit_type_name(parameter.ty.clone());
+
+        if parameter.is_optional {
+            format!("{}: Optional[{}] = None", name, type_name)
+        } else if parameter.is_nullable {
+            format!("{}: Optional[{}]", name, type_name)
+        } else {
+            format!("{}: {}", name, type_name)
+        }
+    }
+
+    /// Emits the lines of the statement built by `emit_statement` for each value of the parameter.
+    ///
+    /// Array parameters produce one statement per item, and optional parameters are skipped when
+    /// `None`.
+    fn emit_parameter_values(
+        &self,
+        parameter: &Parameter,
+        emit_statement: impl Fn(&str) -> String,
+    ) -> Vec<String> {
+        let name = self.emit_parameter_name(&parameter.name);
+
+        let mut lines = match parameter.ty {
+            Type::Array(_) => vec![
+                format!("for item in {}:", name),
+                format!("    {}", emit_statement("_to_string(item)")),
+            ],
+            _ => vec![emit_statement(&format!("_to_string({})", name))],
+        };
+
+        if parameter.is_optional || parameter.is_nullable {
+            lines = std::iter::once(format!("if {} is not None:", name))
+                .chain(lines.into_iter().map(|line| format!("    {}", line)))
+                .collect();
+        }
+
+        lines
+    }
+
+    /// Emits the lines of the statement built by `emit_statement` for each value of the member of
+    /// the record.
+    ///
+    /// Array members produce one statement per item, and members that may be absent or null are
+    /// skipped when `None`.
+    fn emit_member_values(
+        &self,
+        record: &str,
+        member: &Member,
+        emit_statement: impl Fn(&str, &Type) -> String,
+    ) -> Vec<String> {
+        let value = format!(
+            "{}.{}",
+            record,
+            self.casing_rules.to_record_member_case(member.name.clone())
+        );
+
+        let mut lines = match &member.ty {
+            Type::Array(item_type) => vec![
+                format!("for item in {}:", value),
+                format!("    {}", emit_statement("item", item_type)),
+            ],
+            ty => vec![emit_statement(&value, ty)],
+        };
+
+        if member.is_optional || member.is_nullable {
+            lines = std::iter::once(format!("if {} is not None:", value))
+                .chain(lines.into_iter().map(|line| format!("    {}", line)))
+                .collect();
+        }
+
+        lines
+    }
+
+    fn emit_route(
+        &self,
+        route: &Route,
+        is_async: bool,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let parameter_list = route
+            .all_parameters()
+            .iter()
+            .map(|parameter| format!(", {}", self.emit_parameter(parameter)))
+            .collect::<String>();
+
+        let mut setup = Vec::new();
+        let mut request_args = Vec::new();
+
+        let is_secured = !route.security.is_empty();
+
+        if !route.query_parameters.is_empty() || is_secured {
+            setup.push("params: List[Tuple[str, str]] = []".to_string());
+            for parameter in route.query_parameters.iter() {
+                setup.extend(self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        "params.append(({}, {}))",
+                        emit_string_literal(&parameter.name),
+                        value
+                    )
+                }));
+            }
+
+            request_args.push("params=params,".to_string());
+        }
+
+        let content_type = match &route.payload_media_type {
+            MediaType::Text(media_type) | MediaType::Binary(media_type)
+                if route.payload_type.is_some() =>
+            {
+                Some(media_type)
+            }
+            _ => None,
+        };
+
+        if !route.header_parameters.is_empty()
+            || !route.cookie_parameters.is_empty()
+            || content_type.is_some()
+            || is_secured
+        {
+            setup.push("headers: Dict[str, str] = {}".to_string());
+            for parameter in route.header_parameters.iter() {
+                setup.extend(self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        "headers[{}] = {}",
+                        emit_string_literal(&parameter.name),
+                        value
+                    )
+                }));
+            }
+
+            if !route.cookie_parameters.is_empty() {
+                setup.push("cookies: List[str] = []".to_string());
+                for parameter in route.cookie_parameters.iter() {
+                    setup.extend(self.emit_parameter_values(parameter, |value| {
+                        format!(
+                            "cookies.append({} + quote({}))",
+                            emit_string_literal(&format!("{}=", parameter.name)),
+                            value
+                        )
+                    }));
+                }
+                setup.push("if cookies:".to_string());
+                setup.push("    headers[\"Cookie\"] = \"; \".join(cookies)".to_string());
+            }
+
+            if let Some(content_type) = content_type {
+                setup.push(format!(
+                    "headers[\"Content-Type\"] = {}",
+                    emit_string_literal(content_type)
+                ));
+            }
+
+            if is_secured {
+                setup.push(format!(
+                    "_authorize(self._credentials, [{}], headers, params)",
+                    route
+                        .security
+                        .iter()
+                        .map(|requirement| format!(
+                            "[{}]",
+                            requirement
+                                .schemes
+                                .iter()
+                                .map(|(scheme, scopes)| format!(
+                                    "({}, [{}])",
+                                    emit_string_literal(&scheme.name),
+                                    scopes
+                                        .iter()
+                                        .map(|scope| emit_string_literal(scope))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+
+            request_args.push("headers=headers,".to_string());
+        }
+
+        let payload = self.emit_parameter_name("payload");
+
+        let payload_type = route
+            .payload_type
+            .as_ref()
+            .map(|payload_type| type_decls.resolve(payload_type));
+
+        // Forms can only be built from records, so any other payload is sent as JSON.
+        match (&route.payload_media_type, payload_type) {
+            (MediaType::FormUrlEncoded, Some(Type::Record { members, .. })) => {
+                setup.push("form: Dict[str, List[str]] = {}".to_string());
+                for member in members {
+                    setup.extend(self.emit_member_values(&payload, member, |value, _| {
+                        format!(
+                            "form.setdefault({}, []).append(_to_string({}))",
+                            emit_string_literal(&member.wire_name),
+                            value
+                        )
+                    }));
+                }
+
+                request_args.push("data=form,".to_string());
+            }
+            (MediaType::Multipart, Some(Type::Record { members, .. })) => {
+                // Every part is sent as a file, so that the body is multipart even without any.
+                setup.push("files: List[Tuple[str, Any]] = []".to_string());
+                for member in members {
+                    let name = emit_string_literal(&member.wire_name);
+
+                    setup.extend(
+                        self.emit_member_values(&payload, member, |value, ty| match ty {
+                            Type::Primitive(Primitive::Binary) => {
+                                format!("files.append(({name}, ({name}, {})))", value, name = name)
+                            }
+                            _ => format!("files.append(({}, (None, _to_string({}))))", name, value),
+                        }),
+                    );
+                }
+
+                request_args.push("files=files,".to_string());
+            }
+            (MediaType::Text(_), Some(_)) | (MediaType::Binary(_), Some(_)) => {
+                request_args.push(format!("content={},", payload))
+            }
+            (_, Some(_)) => request_args.push(format!("json=_encode({}),", payload)),
+            (_, None) => {}
+        }
+
+        let url = route
+            .url_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                UrlSegment::Parameter(param) => {
+                    format!("{{quote({}, safe='')}}", self.emit_parameter_name(&param))
+                }
+                UrlSegment::Literal(value) => value.replace('{', "{{").replace('}', "}}"),
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+
+        // httpx does not resolve absolute URLs against the base URL of the client.
+        let url = match route.overridden_server(default_servers) {
+            Some(server) => {
+                setup.push(format!(
+                    "base_url = {}.url_with(self._server_variables)",
+                    emit_server(server)
+                ));
+                format!("{{base_url}}{}", url)
+            }
+            None => url,
+        };
+
+        let (return_type, return_value) = match route.return_type.clone() {
+            Some(_) if matches!(route.return_media_type, MediaType::Binary(_)) => (
+                self.emit_primitive(Primitive::Binary).to_string(),
+                "response.content".to_string(),
+            ),
+            Some(_) if matches!(route.return_media_type, MediaType::Text(_)) => (
+                self.emit_primitive(Primitive::String).to_string(),
+                "response.text".to_string(),
+            ),
+            Some(return_type) => {
+                let return_type = self.emit_type_name(return_type);
+                let return_value = format!("_decode({}, response.json())", return_type);
+
+                (return_type, return_value)
+            }
+            None => ("None".to_string(), String::new()),
+        };
+
+        let docstring = route
+            .description
+            .as_ref()
+            .map(|description| {
+                format!(
+                    "\"\"\"{}\"\"\"\n        ",
+                    description
+                        .to_string()
+                        .replace("\"\"\"", "\\\"\\\"\\\"")
+                        .replace('\n', "\n        ")
+                )
+            })
+            .unwrap_or_default();
+
+        format!(
+            r#"
+    {async_}def {function_name}(self{parameter_list}) -> {return_type}:
+        {docstring}{setup}response = {await_}self._client.request(
+            "{http_method}",
+            f"{url}",{request_args}
+        )
+        response.raise_for_status(){return_statement}
+"#,
+            async_ = if is_async { "async " } else { "" },
+            await_ = if is_async { "await " } else { "" },
+            function_name = self.casing_rules.to_function_name_case(route.name.clone()),
+            parameter_list = parameter_list,
+            return_type = return_type,
+            docstring = docstring,
+            setup = setup
+                .into_iter()
+                .map(|line| line + "\n        ")
+                .collect::<String>(),
+            http_method = match route.method {
+                HttpMethod::Get => "GET",
+                HttpMethod::Post => "POST",
+                HttpMethod::Put => "PUT",
+                HttpMethod::Patch => "PATCH",
+                HttpMethod::Delete => "DELETE",
+            },
+            url = url,
+            request_args = request_args
+                .iter()
+                .map(|arg| format!("\n            {}", arg))
+                .collect::<String>(),
+            return_statement = if return_value.is_empty() {
+                String::new()
+            } else {
+                format!("\n\n        return {}", return_value)
+            }
+        )
+    }
+
+    fn emit_primitive(&self, primitive: Primitive) -> &'static str {
+        match primitive {
+            Primitive::String | Primitive::Uri => "str",
+            Primitive::Boolean => "bool",
+            Primitive::Integer | Primitive::Long => "int",
+            Primitive::Float | Primitive::Double => "float",
+            Primitive::Decimal => "decimal.Decimal",
+            Primitive::DateTime => "datetime.datetime",
+            Primitive::Date => "datetime.date",
+            Primitive::Time => "datetime.time",
+            Primitive::Uuid => "uuid.UUID",
+            // Pydantic reads `bytes` from the text of a string, rather than decoding it.
+            Primitive::Bytes => match self.model_style {
+                PythonModelStyle::Dataclasses => "bytes",
+                PythonModelStyle::Pydantic => "Base64Bytes",
+            },
+            Primitive::Binary => "bytes",
+            Primitive::Any => "Any",
+        }
+    }
+}
+
+const DATACLASSES_PRELUDE: &str = r#"from __future__ import annotations
+
+import base64
+import dataclasses
+import datetime
+import decimal
+import json
+import uuid
+from dataclasses import dataclass, field
+from typing import Annotated, Any, Callable, Dict, ForwardRef, List, Literal, Optional, Tuple, Union, get_args, get_origin
+from urllib.parse import quote
+
+import httpx
+
+
+@dataclass(frozen=True)
+class _Tagged:
+    discriminator: str
+    cases: Dict[str, str]
+
+
+def _resolve(tp: Any) -> Any:
+    if isinstance(tp, ForwardRef):
+        tp = tp.__forward_arg__
+    if isinstance(tp, str):
+        return _TYPES[tp]
+    return tp
+
+
+_STRING_DECODERS = {
+    datetime.datetime: lambda data: datetime.datetime.fromisoformat(data.replace("Z", "+00:00")),
+    datetime.date: datetime.date.fromisoformat,
+    datetime.time: lambda data: datetime.time.fromisoformat(data.replace("Z", "+00:00")),
+    uuid.UUID: uuid.UUID,
+    bytes: base64.b64decode,
+}
+
+
+def _decode(tp: Any, data: Any) -> Any:
+    tp = _resolve(tp)
+    origin = get_origin(tp)
+    args = get_args(tp)
+
+    if data is None:
+        return None
+    if origin is Annotated:
+        tagged = tp.__metadata__[0]
+        return _decode(tagged.cases[data[tagged.discriminator]], data)
+    if origin is Union:
+        for case in args:
+            try:
+                return _decode(case, data)
+            except (TypeError, ValueError, KeyError):
+                continue
+        raise ValueError(f"{data!r} does not match any case of {tp}.")
+    if origin is list:
+        return [_decode(args[0], item) for item in data]
+    if origin is tuple:
+        return tuple(_decode(item_tp, item) for item_tp, item in zip(args, data))
+    if origin is dict:
+        return {key: _decode(args[1], value) for key, value in data.items()}
+    if origin is Literal:
+        if data not in args:
+            raise ValueError(f"{data!r} is not one of {args}.")
+        return data
+    if dataclasses.is_dataclass(tp):
+        if not isinstance(data, dict):
+            raise TypeError(f"Expected an object for {tp.__name__}.")
+        values = {}
+        for tp_field in dataclasses.fields(tp):
+            key = tp_field.metadata.get("json", tp_field.name)
+            if key in data:
+                values[tp_field.name] = _decode(tp_field.type, data[key])
+        return tp(**values)
+    if tp in _STRING_DECODERS:
+        if not isinstance(data, str):
+            raise TypeError(f"Expected a string for {tp.__name__}.")
+        return _STRING_DECODERS[tp](data)
+    if tp is decimal.Decimal and isinstance(data, (int, float, str)) and not isinstance(data, bool):
+        return decimal.Decimal(str(data))
+    if tp is float and isinstance(data, int):
+        return float(data)
+    if isinstance(tp, type) and not isinstance(data, tp):
+        raise TypeError(f"Expected {tp.__name__}, got {type(data).__name__}.")
+    return data
+
+
+def _encode(value: Any) -> Any:
+    if dataclasses.is_dataclass(value):
+        return {
+            tp_field.metadata.get("json", tp_field.name): _encode(getattr(value, tp_field.name))
+            for tp_field in dataclasses.fields(value)
+            if getattr(value, tp_field.name) is not None or tp_field.metadata.get("nullable")
+        }
+    if isinstance(value, (list, tuple)):
+        return [_encode(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _encode(item) for key, item in value.items()}
+    if isinstance(value, (datetime.datetime, datetime.date, datetime.time)):
+        return value.isoformat()
+    if isinstance(value, decimal.Decimal):
+        return str(value)
+    if isinstance(value, uuid.UUID):
+        return str(value)
+    if isinstance(value, bytes):
+        return base64.b64encode(value).decode("ascii")
+    return value
+
+
+def _to_string(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    return json.dumps(_encode(value)).strip('"')
+"#;
+
+const PYDANTIC_PRELUDE: &str = r#"from __future__ import annotations
+
+import base64
+import datetime
+import decimal
+import json
+import uuid
+from typing import Any, Callable, Dict, List, Literal, Optional, Tuple, Union
+from urllib.parse import quote
+
+import httpx
+from pydantic import Base64Bytes, BaseModel, ConfigDict, Field, TypeAdapter
+
+
+def _decode(tp: Any, data: Any) -> Any:
+    return TypeAdapter(tp).validate_python(data)
+
+
+def _encode(value: Any) -> Any:
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json", by_alias=True, exclude_unset=True)
+    if isinstance(value, (list, tuple)):
+        return [_encode(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _encode(item) for key, item in value.items()}
+    if isinstance(value, (datetime.datetime, datetime.date, datetime.time)):
+        return value.isoformat()
+    if isinstance(value, decimal.Decimal):
+        return str(value)
+    if isinstance(value, uuid.UUID):
+        return str(value)
+    if isinstance(value, bytes):
+        return base64.b64encode(value).decode("ascii")
+    return value
+
+
+def _to_string(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    return json.dumps(_encode(value)).strip('"')
+"#;
+
+/// Emits the type of the credentials provider, along with the helpers that authorize requests with
+/// the credentials for the security schemes.
+fn emit_authorization(schemes: &[SecurityScheme]) -> String {
+    let branches = schemes
+        .iter()
+        .enumerate()
+        .map(|(index, scheme)| {
+            let statement = match &scheme.kind {
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    parameter_name,
+                } => format!("headers[{}] = credential", emit_string_literal(parameter_name)),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Query,
+                    parameter_name,
+                } => format!(
+                    "params.append(({}, credential))",
+                    emit_string_literal(parameter_name)
+                ),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Cookie,
+                    parameter_name,
+                } => format!(
+                    "headers[\"Cookie\"] = \"; \".join(filter(None, [headers.get(\"Cookie\"), {} + quote(credential)]))",
+                    emit_string_literal(&format!("{}=", parameter_name))
+                ),
+                SecuritySchemeKind::Basic => "headers[\"Authorization\"] = \"Basic \" + base64.b64encode(credential.encode()).decode(\"ascii\")".to_string(),
+                SecuritySchemeKind::Bearer { .. }
+                | SecuritySchemeKind::OAuth2 { .. }
+                | SecuritySchemeKind::OpenIdConnect { .. } => {
+                    "headers[\"Authorization\"] = \"Bearer \" + credential".to_string()
+                }
+            };
+
+            format!(
+                "    {} scheme == {}:\n        {}",
+                if index == 0 { "if" } else { "elif" },
+                emit_string_literal(&scheme.name),
+                statement
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+
+CredentialsProvider = Callable[[str, List[str]], Optional[str]]
+"""Supplies the credential for a security scheme of the API, or `None` if there is none.
+
+The credential is the API key for API key schemes, `username:password` for HTTP basic
+authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+"""
+
+
+def _authorize(
+    credentials: Optional[CredentialsProvider],
+    requirements: List[List[Tuple[str, List[str]]]],
+    headers: Dict[str, str],
+    params: List[Tuple[str, str]],
+) -> None:
+    for requirement in requirements:
+        values: List[str] = []
+        for scheme, scopes in requirement:
+            value = credentials(scheme, scopes) if credentials else None
+            if value is None:
+                break
+            values.append(value)
+        else:
+            for (scheme, _), value in zip(requirement, values):
+                _apply_credential(scheme, value, headers, params)
+            return
+
+
+def _apply_credential(
+    scheme: str, credential: str, headers: Dict[str, str], params: List[Tuple[str, str]]
+) -> None:
+{branches}
+"#,
+        branches = branches
+    )
+}
+
+/// Emits the type of the servers that host the API, along with the servers themselves.
+fn emit_servers(servers: &[Server]) -> String {
+    let mut buffer = String::from(
+        r#"
+
+class Server:
+    """A server that hosts the API, whose URL may hold variables written as `{name}`."""
+
+    def __init__(self, url: str, variables: Optional[Dict[str, str]] = None) -> None:
+        self.url = url
+        self.variables = variables or {}
+        """The default values of the variables."""
+
+    def url_with(self, variables: Optional[Dict[str, str]] = None) -> str:
+        """Returns the URL of the server, with its variables replaced by the given values or
+        otherwise their defaults.
+        """
+        url = self.url
+        for name, value in self.variables.items():
+            url = url.replace("{" + name + "}", (variables or {}).get(name, value))
+        return url
+"#,
+    );
+
+    if !servers.is_empty() {
+        buffer += &format!(
+            r#"
+
+SERVERS: List[Server] = [
+    {servers}
+]
+"""The servers that host the API, the first of which is preferred."""
+"#,
+            servers = servers
+                .iter()
+                .map(|server| format!("{},", emit_server(server)))
+                .collect::<Vec<_>>()
+                .join("\n    ")
+        );
+    }
+
+    buffer
+}
+
+/// Emits the construction of a `Server`.
+fn emit_server(server: &Server) -> String {
+    let url = emit_string_literal(server.url.trim_end_matches('/'));
+
+    if server.variables.is_empty() {
+        format!("Server({})", url)
+    } else {
+        format!(
+            "Server({}, {{{}}})",
+            url,
+            server
+                .variables
+                .iter()
+                .map(|(name, variable)| format!(
+                    "{}: {}",
+                    emit_string_literal(name),
+                    emit_string_literal(&variable.default)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Normalizes the name of the distribution as PEP 503 does, lowercasing it and replacing each run
+/// of `-`, `_` and `.` with a single `-`.
+fn normalize_distribution_name(name: &str) -> String {
+    let mut normalized = String::new();
+
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.extend(c.to_lowercase());
+        }
+    }
+
+    normalized
+}
+
+/// Emits a Python string literal.
+fn emit_string_literal(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+/// Emits the table that `_resolve` looks the annotations up in, once the types they refer to have
+/// been declared.
+fn emit_annotation_table(annotations: &[String]) -> String {
+    format!(
+        r#"
+
+_TYPES: Dict[str, Any] = {{
+{entries}}}
+"#,
+        entries = annotations
+            .iter()
+            .map(|annotation| format!("    {}: {},\n", emit_string_literal(annotation), annotation))
+            .collect::<String>()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    use sdkgen_core::{Case, StatusCode, UrlParameter};
+
+    fn member(name: &str, ty: Type, is_optional: bool) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty,
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn pet() -> Type {
+        Type::Record {
+            name: "Pet".into(),
+            members: vec![
+                member("petId", Type::Primitive(Primitive::Long), false),
+                member("class", Type::Primitive(Primitive::String), true),
+            ],
+        }
+    }
+
+    fn get_pet() -> Route {
+        let mut route = Route {
+            name: "getPet".into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets/:petId".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: vec![UrlParameter {
+                name: "petId".into(),
+                ty: Primitive::String,
+            }],
+            query_parameters: vec![Parameter {
+                name: "verbose".into(),
+                ty: Type::Primitive(Primitive::Boolean),
+                is_optional: true,
+                is_nullable: false,
+            }],
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: Some(pet()),
+            return_media_type: MediaType::Json,
+            responses: Default::default(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        };
+        route.responses.insert(StatusCode::Code(200), Some(pet()));
+
+        route
+    }
+
+    fn generate(sdk: &PythonSdk) -> String {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes: vec![get_pet()],
+            }],
+        }];
+
+        sdk.generate_sdk(type_decls, versions)
+            .unwrap()
+            .into_iter()
+            .find(|(path, _)| path == Path::new("sdk/__init__.py"))
+            .map(|(_, contents)| contents)
+            .unwrap()
+    }
+
+    #[test]
+    fn emits_dataclasses_that_keep_wire_names() {
+        let module = generate(&PythonSdk::default());
+
+        assert!(module.contains(
+            "@dataclass\nclass Pet:\n    pet_id: int = field(metadata={\"json\": \"petId\"})\n    class_: Optional[str] = field(default=None, metadata={\"json\": \"class\"})\n"
+        ));
+    }
+
+    #[test]
+    fn emits_identifiers_in_the_configured_cases() {
+        let mut casing_rules = PythonCasingRules::default();
+        casing_rules.cases.record_member = Case::Camel;
+        casing_rules.overrides.insert("class", "kind");
+
+        let module = generate(&PythonSdk {
+            casing_rules,
+            ..Default::default()
+        });
+
+        assert!(module.contains(
+            "class Pet:\n    petId: int\n    kind: Optional[str] = field(default=None, metadata={\"json\": \"class\"})\n"
+        ));
+    }
+
+    #[test]
+    fn emits_pydantic_models() {
+        let module = generate(&PythonSdk {
+            model_style: PythonModelStyle::Pydantic,
+            ..Default::default()
+        });
+
+        assert!(module.contains("class Pet(BaseModel):"));
+        assert!(!module.contains("@dataclass"));
+    }
+
+    #[test]
+    fn emits_resource_methods() {
+        let module = generate(&PythonSdk::default());
+
+        assert!(module.contains(
+            "    def get_pet(self, pet_id: str, verbose: Optional[bool] = None) -> Pet:\n"
+        ));
+        assert!(module.contains("f\"/pets/{quote(pet_id, safe='')}\""));
+        assert!(module.contains(
+            "    async def get_pet(self, pet_id: str, verbose: Optional[bool] = None) -> Pet:\n"
+        ));
+    }
+
+    #[test]
+    fn emits_package_metadata() {
+        let sdk = PythonSdk {
+            package: PackageMetadata {
+                name: "petstore".into(),
+                version: "1.2.3".into(),
+            },
+            ..Default::default()
+        };
+
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+        let paths: Vec<PathBuf> = sdk
+            .generate_sdk(type_decls, Vec::new())
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+
+        assert!(paths.contains(&PathBuf::from("pyproject.toml")));
+        assert!(paths.contains(&PathBuf::from("petstore/__init__.py")));
+    }
+
+    #[test]
+    fn distribution_names_are_normalized() {
+        assert_eq!(normalize_distribution_name("Acme.Sdk"), "acme-sdk");
+        assert_eq!(
+            normalize_distribution_name("acme__pet-.store"),
+            "acme-pet-store"
+        );
+    }
+}
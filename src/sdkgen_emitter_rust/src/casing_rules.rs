@@ -0,0 +1,108 @@
+use sdkgen_core::{
+    escape_reserved_word, to_valid_identifier, Case, CasingOverrides, CasingRules, IdentifierCases,
+};
+
+/// The keywords of Rust, which are emitted as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// The keywords of Rust that cannot be raw identifiers, which are suffixed with an underscore
+/// instead.
+const NON_RAW_KEYWORDS: &[&str] = &["Self", "_", "crate", "self", "super"];
+
+/// The names of the types in the emitted crate and the standard prelude, which types cannot
+/// shadow.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Box",
+    "Client",
+    "Deserialize",
+    "Error",
+    "Option",
+    "Result",
+    "Serialize",
+    "String",
+    "Vec",
+];
+
+/// The names used by the body of emitted routes, which parameters cannot shadow.
+const RESERVED_PARAMETER_NAMES: &[&str] = &["cookies", "encode_component", "request", "to_string"];
+
+#[derive(Debug, Clone)]
+pub struct RustCasingRules {
+    pub cases: IdentifierCases,
+    pub overrides: CasingOverrides,
+}
+
+impl RustCasingRules {
+    pub const DEFAULT_CASES: IdentifierCases = IdentifierCases {
+        type_name: Case::Pascal,
+        record_member: Case::Snake,
+        function_name: Case::Snake,
+        parameter: Case::Snake,
+        enum_variant: Case::Pascal,
+    };
+}
+
+impl Default for RustCasingRules {
+    fn default() -> Self {
+        Self {
+            cases: Self::DEFAULT_CASES,
+            overrides: CasingOverrides::new(),
+        }
+    }
+}
+
+impl CasingRules<String> for RustCasingRules {
+    fn to_type_name_case(&self, value: String) -> String {
+        to_rust_identifier(escape_reserved_word(
+            self.overrides
+                .apply(value, |value| self.cases.type_name.apply(value)),
+            RESERVED_TYPE_NAMES,
+        ))
+    }
+
+    fn to_record_member_case(&self, value: String) -> String {
+        to_rust_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.record_member.apply(value)),
+        )
+    }
+
+    fn to_function_name_case(&self, value: String) -> String {
+        to_rust_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.function_name.apply(value)),
+        )
+    }
+
+    fn to_parameter_case(&self, value: String) -> String {
+        to_rust_identifier(escape_reserved_word(
+            self.overrides
+                .apply(value, |value| self.cases.parameter.apply(value)),
+            RESERVED_PARAMETER_NAMES,
+        ))
+    }
+
+    fn to_enum_variant_case(&self, value: String) -> String {
+        to_rust_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.enum_variant.apply(value)),
+        )
+    }
+}
+
+/// Makes the identifier valid in Rust, using a raw identifier if it is a keyword.
+fn to_rust_identifier(value: String) -> String {
+    let value = escape_reserved_word(to_valid_identifier(value), NON_RAW_KEYWORDS);
+
+    if KEYWORDS.contains(&value.as_str()) {
+        format!("r#{}", value)
+    } else {
+        value
+    }
+}
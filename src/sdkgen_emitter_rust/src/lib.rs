@@ -0,0 +1,1262 @@
+mod casing_rules;
+
+use sdkgen_core::{
+    default_servers, find_identifier_collisions, used_primitives, used_security_schemes,
+    ApiKeyLocation, CasingRules, Diagnostics, EnumValue, EnumVariant, FileTree, GenerateSdk,
+    HttpMethod, MediaType, Member, PackageMetadata, Parameter, Primitive, Route, SdkResource,
+    SdkVersion, SecurityScheme, SecuritySchemeKind, Server, Type, TypeDeclarations, TypeMappings,
+    UnionCase, UrlSegment,
+};
+
+use heck::KebabCase;
+
+pub use crate::casing_rules::RustCasingRules;
+
+/// The type used for maps.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RustMapType {
+    #[default]
+    HashMap,
+    /// `indexmap::IndexMap`, which preserves the order of the entries.
+    IndexMap,
+}
+
+#[derive(Debug, Default)]
+pub struct RustSdk {
+    pub package: PackageMetadata,
+    pub map_type: RustMapType,
+    pub casing_rules: RustCasingRules,
+    pub type_mappings: TypeMappings,
+}
+
+impl GenerateSdk for RustSdk {
+    fn generate_sdk(
+        &self,
+        type_decls: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics> {
+        find_identifier_collisions(
+            "Rust",
+            &self.casing_rules,
+            |member| self.casing_rules.to_record_member_case(member.name.clone()),
+            &type_decls,
+            &versions,
+        )
+        .into_result(())?;
+
+        let primitives = used_primitives(&type_decls, &versions);
+        let uses_multipart = versions
+            .iter()
+            .flat_map(|version| version.resources.iter())
+            .flat_map(|resource| resource.routes.iter())
+            .any(|route| route.payload_media_type == MediaType::Multipart);
+
+        let default_servers = default_servers(&versions);
+        let has_overridden_servers = versions
+            .iter()
+            .flat_map(|version| version.resources.iter())
+            .flat_map(|resource| resource.routes.iter())
+            .any(|route| route.overridden_server(&default_servers).is_some());
+
+        let mut buffer = String::from(PRELUDE);
+        buffer += &emit_client(has_overridden_servers);
+        buffer += CLIENT_HELPERS;
+        buffer += &emit_apply_credential(&used_security_schemes(&versions));
+
+        if !default_servers.is_empty() || has_overridden_servers {
+            buffer += &emit_servers(&default_servers);
+        }
+
+        for (name, ty) in type_decls.iter() {
+            if self.type_mappings.contains(name) {
+                continue;
+            }
+
+            buffer += &self.emit_type_decl(ty.clone(), &type_decls);
+        }
+
+        buffer += "\nimpl Client {";
+
+        for version in versions {
+            for resource in version.resources {
+                buffer += &self.emit_sdk_resource(
+                    version.version.clone(),
+                    resource,
+                    &default_servers,
+                    &type_decls,
+                );
+            }
+        }
+
+        buffer += "}\n";
+
+        let mut files = FileTree::new();
+        files.add_file(
+            "Cargo.toml",
+            self.emit_manifest(&primitives, uses_multipart),
+        );
+        files.add_file("src/lib.rs", buffer);
+
+        Ok(files)
+    }
+}
+
+const PRELUDE: &str = r#"use serde::{Deserialize, Serialize};
+
+/// An error returned by the [`Client`].
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    Request(reqwest::Error),
+    /// The API responded with an unsuccessful status code.
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// The response body could not be deserialized.
+    Deserialize(serde_json::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request failed: {}", err),
+            Error::Status { status, body } => write!(f, "unexpected status code {}: {}", status, body),
+            Error::Deserialize(err) => write!(f, "invalid response body: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Deserialize(err)
+    }
+}
+
+/// Supplies the credentials for the security schemes of the API.
+///
+/// The credential is the API key for API key schemes, `username:password` for HTTP basic
+/// authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+pub trait CredentialsProvider: std::fmt::Debug + Send + Sync {
+    /// Returns the credential for the security scheme, if there is one.
+    fn credential(&self, scheme: &str, scopes: &[&str]) -> Option<String>;
+}
+"#;
+
+/// The helpers that the routes of the `Client` send requests with.
+const CLIENT_HELPERS: &str = r#"
+impl Client {
+    /// Adds the credentials for the first of the security requirements that the provider can
+    /// satisfy to the request.
+    #[allow(dead_code)]
+    fn authorize(
+        &self,
+        mut request: reqwest::RequestBuilder,
+        requirements: &[&[(&str, &[&str])]],
+    ) -> reqwest::RequestBuilder {
+        let provider = match &self.credentials {
+            Some(provider) => provider,
+            None => return request,
+        };
+
+        for requirement in requirements {
+            let credentials = requirement
+                .iter()
+                .map(|(scheme, scopes)| provider.credential(scheme, scopes))
+                .collect::<Option<Vec<_>>>();
+
+            if let Some(credentials) = credentials {
+                for ((scheme, _), credential) in requirement.iter().zip(credentials) {
+                    request = apply_credential(request, scheme, credential);
+                }
+                break;
+            }
+        }
+
+        request
+    }
+
+    /// Sends the request, returning the body of a successful response.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(body)
+    }
+
+    /// Sends the request, returning the body of a successful response as it is.
+    #[allow(dead_code)]
+    async fn send_bytes(&self, request: reqwest::RequestBuilder) -> Result<Vec<u8>, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Status { status, body });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+#[allow(dead_code)]
+fn to_string<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+#[allow(dead_code)]
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+"#;
+
+/// Emits the function that adds the credential for a security scheme to a request.
+fn emit_apply_credential(schemes: &[SecurityScheme]) -> String {
+    let body = if schemes.is_empty() {
+        "let _ = (scheme, credential);\n    request".to_string()
+    } else {
+        let arms = schemes
+            .iter()
+            .map(|scheme| {
+                let expression = match &scheme.kind {
+                    SecuritySchemeKind::ApiKey {
+                        location: ApiKeyLocation::Header,
+                        parameter_name,
+                    } => format!(
+                        "request.header({}, credential)",
+                        emit_string_literal(parameter_name)
+                    ),
+                    SecuritySchemeKind::ApiKey {
+                        location: ApiKeyLocation::Query,
+                        parameter_name,
+                    } => format!(
+                        "request.query(&[({}, credential)])",
+                        emit_string_literal(parameter_name)
+                    ),
+                    SecuritySchemeKind::ApiKey {
+                        location: ApiKeyLocation::Cookie,
+                        parameter_name,
+                    } => format!(
+                        "request.header(\"Cookie\", format!(\"{}={{}}\", encode_component(&credential)))",
+                        parameter_name
+                    ),
+                    SecuritySchemeKind::Basic => r#"{
+            let mut parts = credential.splitn(2, ':');
+            request.basic_auth(parts.next().unwrap_or_default(), parts.next())
+        }"#
+                    .to_string(),
+                    SecuritySchemeKind::Bearer { .. }
+                    | SecuritySchemeKind::OAuth2 { .. }
+                    | SecuritySchemeKind::OpenIdConnect { .. } => {
+                        "request.bearer_auth(credential)".to_string()
+                    }
+                };
+
+                format!(
+                    "\n        {} => {}{}",
+                    emit_string_literal(&scheme.name),
+                    expression,
+                    if expression.ends_with('}') { "" } else { "," }
+                )
+            })
+            .collect::<String>();
+
+        format!("match scheme {{{}\n        _ => request,\n    }}", arms)
+    };
+
+    format!(
+        r#"
+/// Adds the credential for the security scheme to the request.
+#[allow(dead_code)]
+fn apply_credential(
+    request: reqwest::RequestBuilder,
+    scheme: &str,
+    credential: String,
+) -> reqwest::RequestBuilder {{
+    {body}
+}}
+"#,
+        body = body
+    )
+}
+
+/// Emits the `Client`, along with its constructor and the methods that configure it.
+///
+/// Clients of APIs with routes sent to their own servers hold the values of the variables in the
+/// URLs of those servers.
+fn emit_client(has_overridden_servers: bool) -> String {
+    let (server_variables_field, server_variables_initializer, with_server_variables) =
+        if has_overridden_servers {
+            (
+                "\n    server_variables: Vec<(String, String)>,",
+                "\n            server_variables: Vec::new(),",
+                r#"
+
+    /// Sets the values of the variables in the URLs of the servers that some routes are sent to
+    /// instead of the base URL, which otherwise take their defaults.
+    pub fn with_server_variables(mut self, variables: &[(&str, &str)]) -> Self {
+        self.server_variables = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        self
+    }"#,
+            )
+        } else {
+            ("", "", "")
+        };
+
+    format!(
+        r#"
+/// A client for the API.
+#[derive(Debug, Clone)]
+pub struct Client {{
+    http_client: reqwest::Client,
+    base_url: String,
+    credentials: Option<std::sync::Arc<dyn CredentialsProvider>>,{server_variables_field}
+}}
+
+impl Client {{
+    /// Returns a client that sends requests to the API at the given base URL.
+    pub fn new(http_client: reqwest::Client, base_url: impl Into<String>) -> Self {{
+        Self {{
+            http_client,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            credentials: None,{server_variables_initializer}
+        }}
+    }}
+
+    /// Authorizes requests with the credentials of the given provider.
+    pub fn with_credentials(mut self, provider: impl CredentialsProvider + 'static) -> Self {{
+        self.credentials = Some(std::sync::Arc::new(provider));
+        self
+    }}{with_server_variables}
+}}
+"#,
+        server_variables_field = server_variables_field,
+        server_variables_initializer = server_variables_initializer,
+        with_server_variables = with_server_variables
+    )
+}
+
+/// Emits the type of the servers that host the API, which fills in the variables in their URLs,
+/// along with the servers themselves.
+fn emit_servers(servers: &[Server]) -> String {
+    let mut buffer = String::from(
+        r#"
+/// A server that hosts the API, whose URL may hold variables written as `{name}`.
+#[derive(Debug, Clone, Copy)]
+pub struct Server {
+    pub url: &'static str,
+    /// The default values of the variables.
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+impl Server {
+    /// Returns the URL of the server, with its variables replaced by the given values or
+    /// otherwise their defaults.
+    pub fn url_with<S: AsRef<str>>(&self, variables: &[(S, S)]) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.to_owned(), |url, (name, default)| {
+                let value = variables
+                    .iter()
+                    .find(|(given, _)| given.as_ref() == *name)
+                    .map_or(*default, |(_, value)| value.as_ref());
+
+                url.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+"#,
+    );
+
+    if !servers.is_empty() {
+        buffer += &format!(
+            r#"
+/// The servers that host the API.
+pub const SERVERS: &[Server] = &[
+    {servers}
+];
+"#,
+            servers = servers
+                .iter()
+                .map(|server| format!("{},", emit_server(server)))
+                .collect::<Vec<_>>()
+                .join("\n    ")
+        );
+    }
+
+    buffer
+}
+
+/// Emits a `Server` struct expression.
+///
+/// The trailing slash is left out of the URL so that paths can be appended to it.
+fn emit_server(server: &Server) -> String {
+    format!(
+        "Server {{ url: {}, variables: &[{}] }}",
+        emit_string_literal(server.url.trim_end_matches('/')),
+        server
+            .variables
+            .iter()
+            .map(|(name, variable)| format!(
+                "({}, {})",
+                emit_string_literal(name),
+                emit_string_literal(&variable.default)
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Emits a Rust string literal.
+fn emit_string_literal(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+fn emit_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::String => "String",
+        Primitive::Boolean => "bool",
+        Primitive::Integer | Primitive::Long => "i64",
+        Primitive::Float => "f32",
+        Primitive::Double | Primitive::Decimal => "f64",
+        Primitive::DateTime => "chrono::DateTime<chrono::FixedOffset>",
+        Primitive::Date => "chrono::NaiveDate",
+        Primitive::Time => "chrono::NaiveTime",
+        Primitive::Uuid => "uuid::Uuid",
+        // Serde has no standard encoding for bytes, so base64 strings are left encoded.
+        Primitive::Bytes | Primitive::Uri => "String",
+        Primitive::Binary => "Vec<u8>",
+        Primitive::Any => "serde_json::Value",
+    }
+}
+
+impl RustSdk {
+    fn emit_manifest(&self, primitives: &[Primitive], uses_multipart: bool) -> String {
+        let mut dependencies = vec![
+            if uses_multipart {
+                r#"reqwest = { version = "0.11", default-features = false, features = ["json", "multipart"] }"#
+            } else {
+                r#"reqwest = { version = "0.11", default-features = false, features = ["json"] }"#
+            },
+            r#"serde = { version = "1.0", features = ["derive"] }"#,
+            r#"serde_json = "1.0""#,
+            r#"serde_repr = "0.1""#,
+        ];
+
+        if let RustMapType::IndexMap = self.map_type {
+            dependencies.push(r#"indexmap = { version = "1.6", features = ["serde-1"] }"#);
+        }
+
+        if primitives.iter().any(|primitive| {
+            matches!(
+                primitive,
+                Primitive::DateTime | Primitive::Date | Primitive::Time
+            )
+        }) {
+            dependencies.push(r#"chrono = { version = "0.4", features = ["serde"] }"#);
+        }
+
+        if primitives
+            .iter()
+            .any(|primitive| matches!(primitive, Primitive::Uuid))
+        {
+            dependencies.push(r#"uuid = { version = "1.0", features = ["serde"] }"#);
+        }
+
+        format!(
+            r#"[package]
+name = {name:?}
+version = {version:?}
+edition = "2018"
+
+[dependencies]
+{dependencies}
+"#,
+            name = self.package.name.replace('.', " ").to_kebab_case(),
+            version = self.package.version,
+            dependencies = dependencies.join("\n")
+        )
+    }
+
+    fn emit_type_name(&self, ty: Type) -> String {
+        match ty {
+            Type::Primitive(primitive) => emit_primitive(primitive).into(),
+            Type::Array(ty) => format!("Vec<{}>", self.emit_type_name(*ty)),
+            Type::Tuple(item_types) => {
+                let item_type_names: Vec<String> = item_types
+                    .into_iter()
+                    .map(|ty| self.emit_type_name(ty))
+                    .collect();
+
+                match item_type_names.as_slice() {
+                    [item_type_name] => format!("({},)", item_type_name),
+                    item_type_names => format!("({})", item_type_names.join(", ")),
+                }
+            }
+            Type::Map { key, value } => format!(
+                "{}<{}, {}>",
+                match self.map_type {
+                    RustMapType::HashMap => "std::collections::HashMap",
+                    RustMapType::IndexMap => "indexmap::IndexMap",
+                },
+                self.emit_type_name(*key),
+                self.emit_type_name(*value)
+            ),
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => match self.type_mappings.get(&name) {
+                Some(mapped_type) => mapped_type.to_owned(),
+                None => self.casing_rules.to_type_name_case(name),
+            },
+        }
+    }
+
+    /// Emits the name of the type of a member or union case of the named type, which is boxed if
+    /// it holds a value of the named type, since a type cannot contain itself.
+    fn emit_field_type_name(
+        &self,
+        ty: Type,
+        container_name: &str,
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        if type_decls.contains(&ty, container_name) {
+            format!("Box<{}>", self.emit_type_name(ty))
+        } else {
+            self.emit_type_name(ty)
+        }
+    }
+
+    fn emit_type_decl(&self, ty: Type, type_decls: &TypeDeclarations) -> String {
+        match ty {
+            Type::Record { name, members } => format!(
+                r#"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct {name} {{
+    {members}
+}}
+"#,
+                name = self.casing_rules.to_type_name_case(name.clone()),
+                members = members
+                    .into_iter()
+                    .map(|member| self.emit_member(member, &name, type_decls))
+                    .collect::<Vec<_>>()
+                    .join("\n    ")
+            ),
+            Type::Union {
+                name,
+                discriminator,
+                cases,
+            } => self.emit_union_decl(name, discriminator, cases, type_decls),
+            Type::Enum { name, variants } => self.emit_enum_decl(name, variants),
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => "".into(),
+        }
+    }
+
+    fn emit_member(
+        &self,
+        member: Member,
+        record_name: &str,
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let member_name = self.casing_rules.to_record_member_case(member.name.clone());
+
+        let mut serde_args = Vec::new();
+
+        if member_name.trim_start_matches("r#") != member.wire_name {
+            serde_args.push(format!(
+                "rename = {}",
+                emit_string_literal(&member.wire_name)
+            ));
+        }
+
+        if member.is_optional {
+            serde_args.push("default".to_string());
+            serde_args.push("skip_serializing_if = \"Option::is_none\"".to_string());
+        }
+
+        let type_name = self.emit_field_type_name(member.ty, record_name, type_decls);
+
+        let type_name = if member.is_optional || member.is_nullable {
+            format!("Option<{}>", type_name)
+        } else {
+            type_name
+        };
+
+        let attribute = if serde_args.is_empty() {
+            "".to_string()
+        } else {
+            format!("#[serde({})]\n    ", serde_args.join(", "))
+        };
+
+        format!("{}pub {}: {},", attribute, member_name, type_name)
+    }
+
+    /// Emits a union as an enum with a variant wrapping the value of each case.
+    ///
+    /// The union is serialized as the value of its case. Tagged unions are deserialized by
+    /// selecting the case using the discriminator, which is left in place as the cases usually
+    /// declare it as well, while untagged unions try each case in order.
+    fn emit_union_decl(
+        &self,
+        union_name: String,
+        discriminator: Option<String>,
+        cases: Vec<UnionCase>,
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let name = self.casing_rules.to_type_name_case(union_name.clone());
+
+        let cases: Vec<(UnionCase, String)> = cases
+            .into_iter()
+            .map(|case| {
+                let variant_name = self.casing_rules.to_enum_variant_case(case.name.clone());
+
+                (case, variant_name)
+            })
+            .collect();
+
+        let variants = cases
+            .iter()
+            .map(|(case, variant_name)| {
+                format!(
+                    "{}({}),",
+                    variant_name,
+                    self.emit_field_type_name(case.ty.clone(), &union_name, type_decls)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n    ");
+
+        let discriminator = match discriminator {
+            Some(discriminator) => discriminator,
+            None => {
+                return format!(
+                    r#"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum {name} {{
+    {variants}
+}}
+"#,
+                    name = name,
+                    variants = variants
+                )
+            }
+        };
+
+        format!(
+            r#"
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum {name} {{
+    {variants}
+}}
+
+impl<'de> Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let tag = value
+            .get({discriminator})
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_owned);
+
+        let case = match tag.as_deref() {{
+            {cases}
+            tag => Err(format!("unknown {discriminator_name} {{:?}} for {name}", tag)),
+        }};
+
+        case.map_err(serde::de::Error::custom)
+    }}
+}}
+"#,
+            name = name,
+            variants = variants,
+            discriminator = emit_string_literal(&discriminator),
+            discriminator_name = discriminator,
+            cases = cases
+                .iter()
+                .map(|(case, variant_name)| format!(
+                    "Some({tag}) => serde_json::from_value(value).map({name}::{variant_name}).map_err(|err| err.to_string()),",
+                    tag = emit_string_literal(&case.name),
+                    name = name,
+                    variant_name = variant_name
+                ))
+                .collect::<Vec<_>>()
+                .join("\n            ")
+        )
+    }
+
+    fn emit_sdk_resource(
+        &self,
+        _version: String,
+        resource: SdkResource,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        resource
+            .routes
+            .into_iter()
+            .map(|route| self.emit_route(route, default_servers, type_decls))
+            .collect()
+    }
+
+    fn emit_parameter(&self, parameter: &Parameter) -> String {
+        let type_name = self.emit_type_name(parameter.ty.clone());
+
+        format!(
+            "{}: {}",
+            self.emit_parameter_name(&parameter.name),
+            if parameter.is_optional || parameter.is_nullable {
+                format!("Option<{}>", type_name)
+            } else {
+                type_name
+            }
+        )
+    }
+
+    fn emit_route(
+        &self,
+        route: Route,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+    ) -> String {
+        let parameter_list = route
+            .all_parameters()
+            .iter()
+            .map(|parameter| format!(", {}", self.emit_parameter(parameter)))
+            .collect::<String>();
+
+        let mut url_format = Vec::new();
+        let mut url_arguments = Vec::new();
+
+        for segment in route.url_segments() {
+            match segment {
+                UrlSegment::Parameter(param) => {
+                    url_format.push("{}".to_string());
+                    url_arguments.push(format!(
+                        ", encode_component(&to_string(&{}))",
+                        self.emit_parameter_name(&param)
+                    ));
+                }
+                UrlSegment::Literal(value) => {
+                    url_format.push(value.replace('{', "{{").replace('}', "}}"))
+                }
+            }
+        }
+
+        let mut request = Vec::new();
+
+        request.extend(route.query_parameters.iter().map(|parameter| {
+            self.emit_parameter_values(parameter, |value| {
+                format!(
+                    "request = request.query(&[({}, {})]);",
+                    emit_string_literal(&parameter.name),
+                    value
+                )
+            })
+        }));
+
+        request.extend(route.header_parameters.iter().map(|parameter| {
+            self.emit_parameter_values(parameter, |value| {
+                format!(
+                    "request = request.header({}, {});",
+                    emit_string_literal(&parameter.name),
+                    value
+                )
+            })
+        }));
+
+        if !route.cookie_parameters.is_empty() {
+            request.push("let mut cookies = Vec::new();".to_string());
+            request.extend(route.cookie_parameters.iter().map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!(
+                        "cookies.push(format!(\"{}={{}}\", encode_component(&{})));",
+                        parameter.name, value
+                    )
+                })
+            }));
+            request.push(
+                r#"if !cookies.is_empty() {
+            request = request.header("Cookie", cookies.join("; "));
+        }"#
+                .to_string(),
+            );
+        }
+
+        let payload = self.emit_parameter_name("payload");
+
+        // Forms can only be built from records, so any other payload is sent as JSON.
+        let payload_type = route
+            .payload_type
+            .as_ref()
+            .map(|payload_type| type_decls.resolve(payload_type));
+
+        match (&route.payload_media_type, payload_type) {
+            (_, None) => (),
+            (MediaType::FormUrlEncoded, Some(Type::Record { members, .. })) => {
+                request.push("let mut form = Vec::new();".to_string());
+                request.extend(members.iter().map(|member| {
+                    self.emit_member_values(&payload, member, |value, _| {
+                        format!(
+                            "form.push(({}, to_string(&{})));",
+                            emit_string_literal(&member.wire_name),
+                            value
+                        )
+                    })
+                }));
+                request.push("request = request.form(&form);".to_string());
+            }
+            (MediaType::Multipart, Some(Type::Record { members, .. })) => {
+                request.push("let mut form = reqwest::multipart::Form::new();".to_string());
+                request.extend(members.iter().map(|member| {
+                    let name = emit_string_literal(&member.wire_name);
+
+                    self.emit_member_values(&payload, member, |value, ty| match ty {
+                        Type::Primitive(Primitive::Binary) => format!(
+                            "form = form.part({name}, reqwest::multipart::Part::bytes({value}).file_name({name}));",
+                            name = name,
+                            value = value
+                        ),
+                        _ => format!("form = form.text({}, to_string(&{}));", name, value),
+                    })
+                }));
+                request.push("request = request.multipart(form);".to_string());
+            }
+            (MediaType::Text(media_type), Some(_)) | (MediaType::Binary(media_type), Some(_)) => {
+                request.push(format!(
+                    "request = request.header(\"Content-Type\", {}).body({});",
+                    emit_string_literal(media_type),
+                    payload
+                ));
+            }
+            (_, Some(_)) => request.push(format!("request = request.json(&{});", payload)),
+        }
+
+        if !route.security.is_empty() {
+            request.push(format!(
+                "request = self.authorize(request, &[{}]);",
+                route
+                    .security
+                    .iter()
+                    .map(|requirement| format!(
+                        "&[{}]",
+                        requirement
+                            .schemes
+                            .iter()
+                            .map(|(scheme, scopes)| format!(
+                                "({}, &[{}])",
+                                emit_string_literal(&scheme.name),
+                                scopes
+                                    .iter()
+                                    .map(|scope| emit_string_literal(scope))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        let create_request = format!(
+            "let {mutability}request = self.http_client.request(reqwest::Method::{http_method}, format!({url_format}, {base_url}{url_arguments}));",
+            http_method = match route.method {
+                HttpMethod::Get => "GET",
+                HttpMethod::Post => "POST",
+                HttpMethod::Put => "PUT",
+                HttpMethod::Patch => "PATCH",
+                HttpMethod::Delete => "DELETE",
+            },
+            url_format = emit_string_literal(&format!("{{}}{}", url_format.join("/"))),
+            url_arguments = url_arguments.concat(),
+            // Routes sent to their own server use it in place of the base URL of the client.
+            base_url = match route.overridden_server(default_servers) {
+                Some(server) => format!(
+                    "{}.url_with(&self.server_variables)",
+                    emit_server(server)
+                ),
+                None => "self.base_url".to_string(),
+            },
+            mutability = if request.is_empty() { "" } else { "mut " }
+        );
+
+        request.insert(0, create_request);
+
+        let (return_type, return_value) = match route.return_type {
+            Some(_) if matches!(route.return_media_type, MediaType::Binary(_)) => (
+                emit_primitive(Primitive::Binary).to_string(),
+                "self.send_bytes(request).await",
+            ),
+            Some(_) if matches!(route.return_media_type, MediaType::Text(_)) => (
+                emit_primitive(Primitive::String).to_string(),
+                "self.send(request).await",
+            ),
+            Some(return_type) => (
+                self.emit_type_name(return_type),
+                "Ok(serde_json::from_str(&self.send(request).await?)?)",
+            ),
+            None => ("()".to_string(), "self.send(request).await.map(|_| ())"),
+        };
+
+        let description = route
+            .description
+            .map(|description| {
+                format!(
+                    "/// {}\n    ",
+                    description.to_string().replace('\n', "\n    /// ")
+                )
+            })
+            .unwrap_or_default();
+
+        format!(
+            r#"
+    {description}pub async fn {function_name}(&self{parameter_list}) -> Result<{return_type}, Error> {{
+        {request}
+
+        {return_value}
+    }}
+"#,
+            description = description,
+            function_name = self.casing_rules.to_function_name_case(route.name),
+            parameter_list = parameter_list,
+            return_type = return_type,
+            request = request.join("\n        "),
+            return_value = return_value
+        )
+    }
+
+    /// Emits an enum, serialized using the value of each variant.
+    fn emit_enum_decl(&self, name: String, variants: Vec<EnumVariant>) -> String {
+        let is_string_enum = variants
+            .iter()
+            .any(|variant| matches!(variant.value, EnumValue::String(_)));
+
+        format!(
+            r#"
+{attributes}
+pub enum {name} {{
+    {variants}
+}}
+"#,
+            attributes = if is_string_enum {
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]"
+            } else {
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]\n#[repr(i64)]"
+            },
+            name = self.casing_rules.to_type_name_case(name),
+            variants = variants
+                .into_iter()
+                .map(|variant| {
+                    let variant_name = self.casing_rules.to_enum_variant_case(variant.name);
+
+                    match variant.value {
+                        EnumValue::String(value) => format!(
+                            "#[serde(rename = {})]\n    {},",
+                            emit_string_literal(&value),
+                            variant_name
+                        ),
+                        EnumValue::Integer(value) => format!("{} = {},", variant_name, value),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n    ")
+        )
+    }
+
+    fn emit_parameter_name(&self, parameter: &str) -> String {
+        self.casing_rules.to_parameter_case(parameter.to_owned())
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the member of the record,
+    /// moving the values out of it.
+    ///
+    /// Array members produce one statement per item, and optional members are skipped when `None`.
+    fn emit_member_values(
+        &self,
+        record: &str,
+        member: &Member,
+        emit_statement: impl Fn(&str, &Type) -> String,
+    ) -> String {
+        let value = format!(
+            "{}.{}",
+            record,
+            self.casing_rules.to_record_member_case(member.name.clone())
+        );
+        let is_option = member.is_optional || member.is_nullable;
+
+        match &member.ty {
+            Type::Array(item_type) => format!(
+                r#"for item in {value}{iter} {{
+            {statement}
+        }}"#,
+                value = value,
+                iter = if is_option {
+                    ".into_iter().flatten()"
+                } else {
+                    ""
+                },
+                statement = emit_statement("item", item_type)
+            ),
+            ty if is_option => format!(
+                r#"if let Some(value) = {value} {{
+            {statement}
+        }}"#,
+                value = value,
+                statement = emit_statement("value", ty)
+            ),
+            ty => emit_statement(&value, ty),
+        }
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the parameter.
+    ///
+    /// Array parameters produce one statement per item, and optional parameters are skipped when
+    /// `None`.
+    fn emit_parameter_values(
+        &self,
+        parameter: &Parameter,
+        emit_statement: impl Fn(&str) -> String,
+    ) -> String {
+        let name = self.emit_parameter_name(&parameter.name);
+
+        match parameter.ty {
+            Type::Array(_) => format!(
+                r#"for item in {name}{iter} {{
+            {statement}
+        }}"#,
+                name = name,
+                iter = if parameter.is_optional || parameter.is_nullable {
+                    ".iter().flatten()"
+                } else {
+                    ".iter()"
+                },
+                statement = emit_statement("to_string(item)")
+            ),
+            _ if parameter.is_optional || parameter.is_nullable => format!(
+                r#"if let Some({name}) = {name}.as_ref() {{
+            {statement}
+        }}"#,
+                name = name,
+                statement = emit_statement(&format!("to_string({})", name))
+            ),
+            _ => emit_statement(&format!("to_string(&{})", name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    use sdkgen_core::{StatusCode, UrlParameter};
+
+    fn member(name: &str, ty: Type, is_optional: bool) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty,
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn pet() -> Type {
+        Type::Record {
+            name: "Pet".into(),
+            members: vec![
+                member("petId", Type::Primitive(Primitive::Long), false),
+                member(
+                    "labels",
+                    Type::Map {
+                        key: Box::new(Type::Primitive(Primitive::String)),
+                        value: Box::new(Type::Primitive(Primitive::String)),
+                    },
+                    true,
+                ),
+            ],
+        }
+    }
+
+    fn get_pet() -> Route {
+        let mut route = Route {
+            name: "getPet".into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets/:petId".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: vec![UrlParameter {
+                name: "petId".into(),
+                ty: Primitive::String,
+            }],
+            query_parameters: Vec::new(),
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: Some(pet()),
+            return_media_type: MediaType::Json,
+            responses: Default::default(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        };
+        route.responses.insert(StatusCode::Code(200), Some(pet()));
+
+        route
+    }
+
+    fn generate(sdk: &RustSdk, path: &str) -> String {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes: vec![get_pet()],
+            }],
+        }];
+
+        sdk.generate_sdk(type_decls, versions)
+            .unwrap()
+            .into_iter()
+            .find(|(file_path, _)| file_path == Path::new(path))
+            .map(|(_, contents)| contents)
+            .unwrap()
+    }
+
+    #[test]
+    fn emits_structs_that_keep_wire_names() {
+        let source = generate(&RustSdk::default(), "src/lib.rs");
+
+        assert!(source.contains("    #[serde(rename = \"petId\")]\n    pub pet_id: i64,\n"));
+        assert!(source.contains(
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub labels: Option<std::collections::HashMap<String, String>>,\n"
+        ));
+    }
+
+    #[test]
+    fn escapes_keywords() {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(Type::Record {
+            name: "Self".into(),
+            members: vec![member("type", Type::Primitive(Primitive::String), false)],
+        });
+
+        let source = RustSdk::default()
+            .generate_sdk(type_decls, Vec::new())
+            .unwrap()
+            .into_iter()
+            .find(|(path, _)| path == Path::new("src/lib.rs"))
+            .map(|(_, contents)| contents)
+            .unwrap();
+
+        assert!(source.contains("pub struct Self_ {\n    pub r#type: String,\n}"));
+    }
+
+    #[test]
+    fn boxes_only_the_references_that_hold_their_own_type() {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(Type::Record {
+            name: "Node".into(),
+            members: vec![
+                member("parent", Type::Reference("Node".into()), true),
+                member(
+                    "children",
+                    Type::Array(Box::new(Type::Reference("Node".into()))),
+                    false,
+                ),
+                member("owner", Type::Reference("Owner".into()), false),
+            ],
+        });
+        type_decls.register(Type::Record {
+            name: "Owner".into(),
+            members: vec![member("name", Type::Primitive(Primitive::String), false)],
+        });
+
+        let source = RustSdk::default()
+            .generate_sdk(type_decls, Vec::new())
+            .unwrap()
+            .into_iter()
+            .find(|(path, _)| path == Path::new("src/lib.rs"))
+            .map(|(_, contents)| contents)
+            .unwrap();
+
+        assert!(source.contains("pub parent: Option<Box<Node>>,"));
+        assert!(source.contains("pub children: Vec<Node>,"));
+        assert!(source.contains("pub owner: Owner,"));
+    }
+
+    #[test]
+    fn emits_index_maps_when_configured() {
+        let sdk = RustSdk {
+            map_type: RustMapType::IndexMap,
+            ..Default::default()
+        };
+
+        assert!(generate(&sdk, "src/lib.rs")
+            .contains("pub labels: Option<indexmap::IndexMap<String, String>>,"));
+        assert!(generate(&sdk, "Cargo.toml").contains("indexmap = "));
+    }
+
+    #[test]
+    fn emits_client_methods() {
+        let source = generate(&RustSdk::default(), "src/lib.rs");
+
+        assert!(
+            source.contains("pub async fn get_pet(&self, pet_id: String) -> Result<Pet, Error> {")
+        );
+    }
+
+    #[test]
+    fn emits_the_manifest_with_the_package_metadata() {
+        let sdk = RustSdk {
+            package: PackageMetadata {
+                name: "Acme.Petstore".into(),
+                version: "1.2.3".into(),
+            },
+            ..Default::default()
+        };
+
+        assert!(generate(&sdk, "Cargo.toml")
+            .starts_with("[package]\nname = \"acme-petstore\"\nversion = \"1.2.3\"\n"));
+    }
+}
@@ -1,18 +1,148 @@
-use heck::{CamelCase, MixedCase};
-use sdkgen_core::CasingRules;
+use sdkgen_core::{
+    escape_reserved_word, to_valid_identifier, Case, CasingOverrides, CasingRules, IdentifierCases,
+};
 
-pub struct TypeScriptCasingRules;
+/// The reserved words of TypeScript, which are suffixed with an underscore to be used as
+/// identifiers.
+const RESERVED_WORDS: &[&str] = &[
+    "any",
+    "arguments",
+    "as",
+    "await",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "never",
+    "new",
+    "null",
+    "number",
+    "object",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "symbol",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// The names used by the body of emitted routes, which parameters cannot shadow.
+const RESERVED_PARAMETER_NAMES: &[&str] = &[
+    "axios",
+    "cookies",
+    "data",
+    "errorBody",
+    "form",
+    "headers",
+    "params",
+    "response",
+];
+
+#[derive(Debug, Clone)]
+pub struct TypeScriptCasingRules {
+    pub cases: IdentifierCases,
+    pub overrides: CasingOverrides,
+}
+
+impl TypeScriptCasingRules {
+    pub const DEFAULT_CASES: IdentifierCases = IdentifierCases {
+        type_name: Case::Pascal,
+        record_member: Case::Preserve,
+        function_name: Case::Camel,
+        parameter: Case::Camel,
+        enum_variant: Case::Pascal,
+    };
+}
+
+impl Default for TypeScriptCasingRules {
+    fn default() -> Self {
+        Self {
+            cases: Self::DEFAULT_CASES,
+            overrides: CasingOverrides::new(),
+        }
+    }
+}
 
 impl CasingRules<String> for TypeScriptCasingRules {
     fn to_type_name_case(&self, value: String) -> String {
-        value.to_camel_case()
+        to_typescript_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.type_name.apply(value)),
+        )
     }
 
     fn to_record_member_case(&self, value: String) -> String {
-        value.to_mixed_case()
+        to_typescript_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.record_member.apply(value)),
+        )
     }
 
     fn to_function_name_case(&self, value: String) -> String {
-        value.to_mixed_case()
+        to_typescript_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.function_name.apply(value)),
+        )
+    }
+
+    fn to_parameter_case(&self, value: String) -> String {
+        escape_reserved_word(
+            to_typescript_identifier(
+                self.overrides
+                    .apply(value, |value| self.cases.parameter.apply(value)),
+            ),
+            RESERVED_PARAMETER_NAMES,
+        )
     }
+
+    fn to_enum_variant_case(&self, value: String) -> String {
+        to_typescript_identifier(
+            self.overrides
+                .apply(value, |value| self.cases.enum_variant.apply(value)),
+        )
+    }
+}
+
+fn to_typescript_identifier(value: String) -> String {
+    escape_reserved_word(to_valid_identifier(value), RESERVED_WORDS)
 }
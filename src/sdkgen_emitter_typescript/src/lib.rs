@@ -1,152 +1,1831 @@
 mod casing_rules;
 
-use std::convert::identity;
+use std::collections::HashSet;
 
 use sdkgen_core::{
-    CasingRules, GenerateSdk, HttpMethod, Primitive, Route, SdkResource, SdkVersion, Type,
-    TypeDeclarations, UrlSegment,
+    default_servers, find_identifier_collisions, used_security_schemes, ApiKeyLocation, Case,
+    CasingRules, Diagnostic, Diagnostics, EnumValue, FileTree, GenerateSdk, HttpMethod, MediaType,
+    Member, PackageMetadata, Parameter, Primitive, Route, SdkResource, SdkVersion, SecurityScheme,
+    SecuritySchemeKind, Server, StatusCode, Type, TypeDeclarations, TypeMappings, UrlSegment,
 };
 
-use crate::casing_rules::TypeScriptCasingRules;
+use heck::KebabCase;
 
-pub struct TypeScriptSdk;
+pub use crate::casing_rules::TypeScriptCasingRules;
+
+/// The type used for 64-bit integers, which a `number` only represents exactly up to 2^53.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TypeScriptLongType {
+    Number,
+    /// `bigint`, which is parsed from the response without losing digits.
+    #[default]
+    Bigint,
+    /// `string`, holding the digits of the integer as they were sent.
+    String,
+}
+
+/// The type used for date-times.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TypeScriptDateType {
+    /// `string`, holding the RFC 3339 date-time as it was sent.
+    #[default]
+    String,
+    Date,
+}
+
+#[derive(Debug, Default)]
+pub struct TypeScriptSdk {
+    pub package: PackageMetadata,
+    pub long_type: TypeScriptLongType,
+    pub date_type: TypeScriptDateType,
+    pub casing_rules: TypeScriptCasingRules,
+    pub type_mappings: TypeMappings,
+}
 
 impl GenerateSdk for TypeScriptSdk {
-    fn generate_sdk(&self, type_decls: TypeDeclarations, versions: Vec<SdkVersion>) -> String {
-        let mut buffer = String::new();
+    fn generate_sdk(
+        &self,
+        type_decls: TypeDeclarations,
+        versions: Vec<SdkVersion>,
+    ) -> Result<FileTree, Diagnostics> {
+        // Records are the objects that are sent and received, so their members keep their wire
+        // names.
+        if self.casing_rules.cases.record_member != Case::Preserve {
+            return Err(Diagnostic::error(
+                "The case of record members cannot be configured for TypeScript, whose records keep the names that their members are sent with",
+            )
+            .into());
+        }
+
+        find_identifier_collisions(
+            "TypeScript",
+            &self.casing_rules,
+            |member| member.wire_name.clone(),
+            &type_decls,
+            &versions,
+        )
+        .into_result(())?;
+
+        let conversions = Conversions {
+            decoded: self.converted_types(&type_decls, Conversion::Decode),
+            encoded: self.converted_types(&type_decls, Conversion::Encode),
+        };
+
+        let mut files = FileTree::new();
+        let mut exports = Vec::new();
+
+        for (name, ty) in type_decls.iter() {
+            if self.type_mappings.contains(name) {
+                continue;
+            }
+
+            let mut referenced_names = Vec::new();
+            for referenced_type in child_types(ty) {
+                self.collect_referenced_type_names(referenced_type, &mut referenced_names);
+            }
+            referenced_names.retain(|referenced_name| referenced_name != name);
 
-        for (_name, ty) in type_decls.into_iter() {
-            buffer += &emit_type_decl(ty);
+            let conversion_functions = self.emit_conversion_functions(ty, &conversions);
+            let name = self.casing_rules.to_type_name_case(name.clone());
+
+            files.add_file(
+                format!("models/{}.ts", name),
+                self.emit_imports("./", &referenced_names, &conversions)
+                    + self.emit_type_decl(ty.clone()).trim_end()
+                    + &conversion_functions
+                    + "\n",
+            );
+            exports.push(format!("./models/{}", name));
         }
 
+        let security_schemes = used_security_schemes(&versions);
+        let default_servers = default_servers(&versions);
+        let mut resource_clients = Vec::new();
+        let mut has_overridden_servers = false;
+
         for version in versions {
             for resource in version.resources {
-                buffer += &emit_sdk_resource(version.version.clone(), resource);
+                let (resource_name, class_name) =
+                    self.resource_client_names(&version.version, &resource.resource);
+
+                let overrides_servers = resource
+                    .routes
+                    .iter()
+                    .any(|route| route.overridden_server(&default_servers).is_some());
+                has_overridden_servers |= overrides_servers;
+
+                let mut referenced_names = Vec::new();
+                for route in resource.routes.iter() {
+                    for parameter in route.all_parameters() {
+                        self.collect_referenced_type_names(&parameter.ty, &mut referenced_names);
+                    }
+
+                    for ty in route.responses.values().flatten() {
+                        self.collect_referenced_type_names(ty, &mut referenced_names);
+                    }
+                }
+
+                files.add_file(
+                    format!("resources/{}.ts", resource_name),
+                    format!(
+                        "import type {{ ApiClient }} from '../client';\n{auth}import {{ {errors} }} from '../errors';\n{runtime}{servers}{imports}{resource}\n",
+                        auth = if resource.routes.iter().any(|route| !route.security.is_empty()) {
+                            "import { authorize } from '../auth';\n"
+                        } else {
+                            ""
+                        },
+                        errors = if resource.routes.iter().any(reads_raw_body) {
+                            "ApiError, decodeErrorBody"
+                        } else {
+                            "ApiError"
+                        },
+                        runtime = if self.parses_long() {
+                            "import { parseJson, stringifyJson } from '../runtime';\n"
+                        } else {
+                            ""
+                        },
+                        servers = if overrides_servers {
+                            "import { serverUrl } from '../servers';\n"
+                        } else {
+                            ""
+                        },
+                        imports = self.emit_imports("../models/", &referenced_names, &conversions),
+                        resource = self
+                            .emit_sdk_resource(
+                                version.version.clone(),
+                                resource,
+                                &default_servers,
+                                &type_decls,
+                                &conversions
+                            )
+                            .trim_end()
+                    ),
+                );
+                exports.push(format!("./resources/{}", resource_name));
+                resource_clients.push((resource_name, class_name));
             }
         }
 
-        buffer
+        let has_servers = !default_servers.is_empty() || has_overridden_servers;
+
+        files.add_file(
+            "client.ts",
+            emit_client(
+                &resource_clients,
+                !security_schemes.is_empty(),
+                &default_servers,
+                has_servers,
+            ),
+        );
+        exports.push("./client".to_string());
+
+        if has_servers {
+            files.add_file("servers.ts", emit_servers(&default_servers));
+            exports.push("./servers".to_string());
+        }
+
+        if self.parses_long() {
+            files.add_file("runtime.ts", RUNTIME.trim_start());
+        }
+
+        files.add_file("errors.ts", ERRORS.trim_start());
+        exports.push("./errors".to_string());
+
+        if !security_schemes.is_empty() {
+            files.add_file("auth.ts", emit_auth(&security_schemes));
+            exports.push("./auth".to_string());
+        }
+
+        files.add_file("package.json", emit_package_json(&self.package));
+
+        files.add_file(
+            "index.ts",
+            exports
+                .into_iter()
+                .map(|export| format!("export * from '{}';\n", export))
+                .collect::<String>(),
+        );
+
+        Ok(files)
     }
 }
 
-fn emit_primitive(primitive: Primitive) -> &'static str {
-    match primitive {
-        Primitive::String => "string",
-        Primitive::Boolean => "boolean",
-        Primitive::Integer | Primitive::Float | Primitive::Double => "number",
+const ERRORS: &str = r#"
+/**
+ * The status code and body of an unsuccessful response.
+ */
+export interface ErrorResponse {
+    status: number;
+    body: unknown;
+}
+
+/**
+ * Thrown when the API responds with an unsuccessful status code.
+ *
+ * For routes that document their error responses, the response is one of them, discriminated by
+ * its status code.
+ */
+export class ApiError<TResponse extends ErrorResponse = ErrorResponse> extends Error {
+    constructor(readonly response: TResponse) {
+        super(`The API responded with status code ${response.status}.`);
+        this.name = 'ApiError';
+    }
+
+    get status(): TResponse['status'] {
+        return this.response.status;
+    }
+
+    get body(): TResponse['body'] {
+        return this.response.body;
     }
 }
 
-fn emit_type_name(ty: Type) -> String {
-    match ty {
-        Type::Primitive(primitive) => emit_primitive(primitive).into(),
-        Type::Array(ty) => format!("{}[]", emit_type_name(*ty)),
-        Type::Map { key, value } => format!(
-            "Record<{}, {}>",
-            emit_type_name(*key),
-            emit_type_name(*value)
-        ),
-        Type::Union { name, .. } | Type::Record { name, .. } => {
-            TypeScriptCasingRules.to_type_name_case(name)
+/**
+ * Reads the body of an unsuccessful response that was received as text or binary data, parsing
+ * it as JSON when it is.
+ */
+export function decodeErrorBody(data: ArrayBuffer | string): unknown {
+    const text = typeof data === 'string' ? data : new TextDecoder().decode(data);
+
+    try {
+        return JSON.parse(text);
+    } catch {
+        return text;
+    }
+}
+"#;
+
+/// Emits the module that authorizes requests with the credentials for the security schemes.
+fn emit_auth(schemes: &[SecurityScheme]) -> String {
+    let cases = schemes
+        .iter()
+        .map(|scheme| {
+            let statement = match &scheme.kind {
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    parameter_name,
+                } => format!("headers['{}'] = credential;", parameter_name),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Query,
+                    parameter_name,
+                } => format!("params.append('{}', credential);", parameter_name),
+                SecuritySchemeKind::ApiKey {
+                    location: ApiKeyLocation::Cookie,
+                    parameter_name,
+                } => format!(
+                    "headers['Cookie'] = [headers['Cookie'], `{}=${{encodeURIComponent(credential)}}`].filter(Boolean).join('; ');",
+                    parameter_name
+                ),
+                SecuritySchemeKind::Basic => "headers['Authorization'] = `Basic ${btoa(String.fromCharCode(...new TextEncoder().encode(credential)))}`;".to_string(),
+                SecuritySchemeKind::Bearer { .. }
+                | SecuritySchemeKind::OAuth2 { .. }
+                | SecuritySchemeKind::OpenIdConnect { .. } => {
+                    "headers['Authorization'] = `Bearer ${credential}`;".to_string()
+                }
+            };
+
+            format!(
+                "        case '{}':\n            {}\n            break;",
+                scheme.name, statement
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"/**
+ * The names of the security schemes of the API.
+ */
+export type SecuritySchemeName = {scheme_names};
+
+/**
+ * Supplies the credential for a security scheme of the API, or `undefined` if there is none.
+ *
+ * The credential is the API key for API key schemes, `username:password` for HTTP basic
+ * authentication, and the token for bearer, OAuth 2.0 and OpenID Connect schemes.
+ */
+export type CredentialsProvider = (
+    scheme: SecuritySchemeName,
+    scopes: string[],
+) => string | undefined | Promise<string | undefined>;
+
+/**
+ * The security schemes that must all be satisfied to authorize a request, along with the scopes
+ * that each requires.
+ */
+export type SecurityRequirement = [SecuritySchemeName, string[]][];
+
+/**
+ * Adds the credentials for the first of the security requirements that the provider can satisfy
+ * to the headers and query parameters of a request.
+ *
+ * @internal
+ */
+export async function authorize(
+    credentialsProvider: CredentialsProvider | undefined,
+    requirements: SecurityRequirement[],
+    headers: Record<string, string>,
+    params: URLSearchParams,
+): Promise<void> {{
+    for (const requirement of requirements) {{
+        const credentials = await Promise.all(
+            requirement.map(([scheme, scopes]) => credentialsProvider?.(scheme, scopes)),
+        );
+
+        if (credentials.every(credential => credential !== undefined)) {{
+            requirement.forEach(([scheme], index) => {{
+                applyCredential(scheme, credentials[index] as string, headers, params);
+            }});
+
+            return;
+        }}
+    }}
+}}
+
+function applyCredential(
+    scheme: SecuritySchemeName,
+    credential: string,
+    headers: Record<string, string>,
+    params: URLSearchParams,
+): void {{
+    switch (scheme) {{
+{cases}
+    }}
+}}
+"#,
+        scheme_names = schemes
+            .iter()
+            .map(|scheme| format!("'{}'", scheme.name))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        cases = cases
+    )
+}
+
+/// The helpers for reading and writing JSON that keep every digit of 64-bit integers.
+const RUNTIME: &str = r#"
+/**
+ * Parses JSON, keeping the integers that a `number` cannot represent exactly as strings, so that
+ * no digits are lost.
+ *
+ * Like axios, text that is not JSON is returned as it is.
+ */
+export function parseJson(text: string): any {
+    if (text === '') {
+        return undefined;
+    }
+
+    try {
+        return JSON.parse(
+                text.replace(/"(?:[^"\\]|\\.)*"|(?<![\d.eE+-])-?\d{16,}(?![\d.eE])/g, token =>
+                token.startsWith('"') || Number.isSafeInteger(Number(token)) ? token : `"${token}"`
+            )
+        );
+    } catch {
+        return text;
+    }
+}
+
+/**
+ * Serializes a value as JSON, writing `bigint`s as integers.
+ */
+export function stringifyJson(value: unknown): string {
+    if (typeof value === 'bigint') {
+        return value.toString();
+    }
+
+    if (Array.isArray(value)) {
+        return `[${value.map(item => stringifyJson(item === undefined ? null : item)).join(',')}]`;
+    }
+
+    if (value !== null && typeof value === 'object' && !(value instanceof Date)) {
+        return `{${Object.entries(value)
+            .filter(([, item]) => item !== undefined)
+            .map(([key, item]) => `${JSON.stringify(key)}:${stringifyJson(item)}`)
+            .join(',')}}`;
+    }
+
+    return JSON.stringify(value);
+}
+"#;
+
+/// The direction in which values are converted between the types of the SDK and JSON.
+#[derive(Clone, Copy)]
+enum Conversion {
+    /// From the values parsed from JSON to the types of the SDK.
+    Decode,
+    /// From the types of the SDK to the values serialized as JSON.
+    Encode,
+}
+
+/// The names of the named types whose values must be converted.
+struct Conversions {
+    decoded: HashSet<String>,
+    encoded: HashSet<String>,
+}
+
+impl Conversions {
+    fn contains(&self, name: &str, conversion: Conversion) -> bool {
+        match conversion {
+            Conversion::Decode => self.decoded.contains(name),
+            Conversion::Encode => self.encoded.contains(name),
         }
     }
 }
 
-fn emit_type_decl(ty: Type) -> String {
-    match ty {
-        Type::Record { name, members } => format!(
-            r#"
+impl TypeScriptSdk {
+    /// Collects the names of the named types that the type refers to, without descending into the
+    /// named types themselves.
+    fn collect_referenced_type_names(&self, ty: &Type, names: &mut Vec<String>) {
+        match ty.name() {
+            Some(name) if self.type_mappings.contains(name) => (),
+            Some(name) => {
+                if !names.iter().any(|existing| existing == name) {
+                    names.push(name.to_owned());
+                }
+            }
+            None => {
+                for child_type in child_types(ty) {
+                    self.collect_referenced_type_names(child_type, names);
+                }
+            }
+        }
+    }
+
+    fn emit_type_name(&self, ty: Type) -> String {
+        match ty {
+            Type::Primitive(primitive) => self.emit_primitive(primitive).into(),
+            Type::Array(ty) => format!("{}[]", self.emit_type_name(*ty)),
+            Type::Tuple(item_types) => format!(
+                "[{}]",
+                item_types
+                    .into_iter()
+                    .map(|ty| self.emit_type_name(ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Map { key, value } => format!(
+                "Record<{}, {}>",
+                self.emit_type_name(*key),
+                self.emit_type_name(*value)
+            ),
+            Type::Union { name, .. }
+            | Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::Reference(name) => match self.type_mappings.get(&name) {
+                Some(mapped_type) => mapped_type.to_owned(),
+                None => self.casing_rules.to_type_name_case(name),
+            },
+        }
+    }
+
+    fn emit_type_decl(&self, ty: Type) -> String {
+        match ty {
+            Type::Record { name, members } => format!(
+                r#"
 export interface {name} {{
     {members}
 }}
         "#,
-            name = TypeScriptCasingRules.to_type_name_case(name),
-            members = members
+                name = self.casing_rules.to_type_name_case(name),
+                members = members
+                    .into_iter()
+                    .map(|member| format!(
+                        "{}{}: {}{};",
+                        emit_property_key(&member.wire_name),
+                        if member.is_optional { "?" } else { "" },
+                        self.emit_type_name(member.ty),
+                        if member.is_nullable { " | null" } else { "" },
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n    ")
+            ),
+            Type::Union {
+                name,
+                discriminator,
+                cases,
+            } => format!(
+                r#"
+export type {name} = {cases};
+        "#,
+                name = self.casing_rules.to_type_name_case(name),
+                cases = cases
+                    .into_iter()
+                    .map(|case| match discriminator.as_ref() {
+                        Some(discriminator) => format!(
+                            "({{ {}: '{}' }} & {})",
+                            discriminator,
+                            case.name,
+                            self.emit_type_name(case.ty)
+                        ),
+                        None => self.emit_type_name(case.ty),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            Type::Enum { name, variants } => {
+                let name = self.casing_rules.to_type_name_case(name);
+
+                let variants: Vec<(String, String)> = variants
+                    .into_iter()
+                    .map(|variant| {
+                        let value = match variant.value {
+                            EnumValue::String(value) => format!("'{}'", value),
+                            EnumValue::Integer(value) => value.to_string(),
+                        };
+
+                        (self.casing_rules.to_enum_variant_case(variant.name), value)
+                    })
+                    .collect();
+
+                format!(
+                    r#"
+export type {name} = {values};
+
+export const {name} = {{
+    {variants}
+}} as const;
+        "#,
+                    name = name,
+                    values = variants
+                        .iter()
+                        .map(|(_, value)| value.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                    variants = variants
+                        .iter()
+                        .map(|(variant_name, value)| format!("{}: {},", variant_name, value))
+                        .collect::<Vec<_>>()
+                        .join("\n    ")
+                )
+            }
+            Type::Primitive(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Map { .. }
+            | Type::Reference(_) => "".into(),
+        }
+    }
+
+    /// Returns the name of the module for a resource, which is also the name of the `ApiClient`
+    /// property that exposes it, and the name of its client class.
+    fn resource_client_names(&self, version: &str, resource: &str) -> (String, String) {
+        (
+            self.casing_rules
+                .to_function_name_case(format!("{} {}", version, resource)),
+            self.casing_rules
+                .to_type_name_case(format!("{} {} client", version, resource)),
+        )
+    }
+
+    /// Emits a client class holding the routes of the resource, preceded by the types of their
+    /// error responses.
+    fn emit_sdk_resource(
+        &self,
+        version: String,
+        resource: SdkResource,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+        conversions: &Conversions,
+    ) -> String {
+        let (resource_name, class_name) = self.resource_client_names(&version, &resource.resource);
+
+        let (error_response_types, methods): (Vec<String>, Vec<String>) = resource
+            .routes
+            .into_iter()
+            .map(|value| self.emit_route(value, default_servers, type_decls, conversions))
+            .unzip();
+
+        format!(
+            r#"{error_response_types}
+/**
+ * The routes of the `{resource_name}` resource.
+ */
+export class {class_name} {{
+    constructor(private readonly client: ApiClient) {{}}
+{methods}}}
+"#,
+            error_response_types = error_response_types.concat(),
+            resource_name = resource_name,
+            class_name = class_name,
+            methods = methods
+                .iter()
+                .map(|method| {
+                    method
+                        .lines()
+                        .map(|line| {
+                            if line.trim().is_empty() {
+                                "\n".to_string()
+                            } else {
+                                format!("    {}\n", line)
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<String>()
+        )
+    }
+
+    fn emit_parameter_name(&self, parameter: &str) -> String {
+        self.casing_rules.to_parameter_case(parameter.to_owned())
+    }
+
+    fn emit_parameter(&self, parameter: &Parameter) -> String {
+        format!(
+            "{}{}: {}{}",
+            self.emit_parameter_name(&parameter.name),
+            if parameter.is_optional { "?" } else { "" },
+            self.emit_type_name(parameter.ty.clone()),
+            if parameter.is_nullable { " | null" } else { "" }
+        )
+    }
+
+    /// Emits the method for the route, along with the type of its error responses.
+    fn emit_route(
+        &self,
+        route: Route,
+        default_servers: &[Server],
+        type_decls: &TypeDeclarations,
+        conversions: &Conversions,
+    ) -> (String, String) {
+        // Routes whose successful responses have no body resolve to nothing.
+        let return_type = match route.return_type.as_ref() {
+            Some(ty) => self.emit_type_name(ty.to_owned()),
+            None => "void".to_string(),
+        };
+
+        let error_responses = route.error_responses();
+
+        let decodes_response = route
+            .return_type
+            .as_ref()
+            .is_some_and(|ty| self.is_converted(ty, Conversion::Decode, conversions));
+
+        let decodes_error_responses = error_responses
+            .iter()
+            .any(|(_, ty)| self.is_converted(ty, Conversion::Decode, conversions));
+
+        let reads_raw_body = reads_raw_body(&route);
+
+        // Bodies that may hold 64-bit integers are parsed by `parseJson` rather than by axios.
+        let parses_body =
+            self.parses_long() && !reads_raw_body && (decodes_response || decodes_error_responses);
+
+        let sends_json = route.payload_media_type == MediaType::Json;
+
+        // Payloads that may hold a `bigint` cannot be serialized by `JSON.stringify`.
+        let stringifies_payload = self.parses_long()
+            && sends_json
+            && route
+                .payload_type
+                .as_ref()
+                .is_some_and(|ty| self.is_converted(ty, Conversion::Decode, conversions));
+
+        let parameter_list = route
+            .all_parameters()
+            .iter()
+            .map(|value| self.emit_parameter(value))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let mut setup = Vec::new();
+        let mut request_config = Vec::new();
+
+        let is_secured = !route.security.is_empty();
+
+        if !route.query_parameters.is_empty() || is_secured {
+            setup.push("const params = new URLSearchParams();".to_string());
+            setup.extend(route.query_parameters.iter().map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!("params.append('{}', {});", parameter.name, value)
+                })
+            }));
+
+            request_config.push("params,".to_string());
+        }
+
+        // The content type is set explicitly where axios would not infer it from the payload.
+        let content_type = match &route.payload_media_type {
+            MediaType::Json if stringifies_payload => Some("application/json"),
+            MediaType::Text(media_type) | MediaType::Binary(media_type)
+                if route.payload_type.is_some() =>
+            {
+                Some(media_type.as_str())
+            }
+            _ => None,
+        };
+
+        if !route.header_parameters.is_empty()
+            || !route.cookie_parameters.is_empty()
+            || content_type.is_some()
+            || is_secured
+        {
+            setup.push("const headers: Record<string, string> = {};".to_string());
+            setup.extend(route.header_parameters.iter().map(|parameter| {
+                self.emit_parameter_values(parameter, |value| {
+                    format!("headers['{}'] = {};", parameter.name, value)
+                })
+            }));
+
+            if !route.cookie_parameters.is_empty() {
+                setup.push("const cookies: string[] = [];".to_string());
+                setup.extend(route.cookie_parameters.iter().map(|parameter| {
+                    self.emit_parameter_values(parameter, |value| {
+                        format!(
+                            "cookies.push(`{}=${{encodeURIComponent({})}}`);",
+                            parameter.name, value
+                        )
+                    })
+                }));
+                setup.push(
+                    r#"if (cookies.length > 0) {
+        headers['Cookie'] = cookies.join('; ');
+    }"#
+                    .to_string(),
+                );
+            }
+
+            if let Some(content_type) = content_type {
+                setup.push(format!("headers['Content-Type'] = '{}';", content_type));
+            }
+
+            request_config.push("headers,".to_string());
+        }
+
+        if is_secured {
+            setup.push(format!(
+                "await authorize(this.client.options.credentialsProvider, [{}], headers, params);",
+                route
+                    .security
+                    .iter()
+                    .map(|requirement| format!(
+                        "[{}]",
+                        requirement
+                            .schemes
+                            .iter()
+                            .map(|(scheme, scopes)| format!(
+                                "['{}', [{}]]",
+                                scheme.name,
+                                scopes
+                                    .iter()
+                                    .map(|scope| format!("'{}'", scope))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        let url = route
+            .url_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                UrlSegment::Parameter(param) => format!(
+                    "${{encodeURIComponent({})}}",
+                    self.emit_parameter_name(&param)
+                ),
+                UrlSegment::Literal(value) => value,
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+
+        // Routes sent to their own server use an absolute URL, which axios does not prefix with the
+        // base URL of the client.
+        let url = match route.overridden_server(default_servers) {
+            Some(server) => format!(
+                "${{serverUrl({}, this.client.options.serverVariables)}}{}",
+                emit_server(server),
+                url
+            ),
+            None => url,
+        };
+
+        let payload_type = route
+            .payload_type
+            .as_ref()
+            .map(|payload_type| type_decls.resolve(payload_type));
+
+        let form = match (&route.payload_media_type, payload_type) {
+            (MediaType::FormUrlEncoded, Some(Type::Record { members, .. })) => {
+                Some(("URLSearchParams", members))
+            }
+            (MediaType::Multipart, Some(Type::Record { members, .. })) => {
+                Some(("FormData", members))
+            }
+            _ => None,
+        };
+
+        if let Some((form_type, members)) = form {
+            let payload = self.emit_parameter_name("payload");
+
+            setup.push(format!("const form = new {}();", form_type));
+            setup.extend(members.iter().map(|member| {
+                self.emit_member_values(&payload, member, |value, ty| match ty {
+                    Type::Primitive(Primitive::Binary) => format!(
+                        "form.append({}, {});",
+                        emit_string_literal(&member.wire_name),
+                        value
+                    ),
+                    _ => format!(
+                        "form.append({}, {});",
+                        emit_string_literal(&member.wire_name),
+                        self.emit_string_value(ty, value)
+                    ),
+                })
+            }));
+
+            request_config.push("data: form,".to_string());
+        } else if let Some(payload_type) = route.payload_type.as_ref() {
+            let mut payload = self.emit_parameter_name("payload");
+
+            if sends_json && self.is_converted(payload_type, Conversion::Encode, conversions) {
+                payload =
+                    self.emit_conversion(payload_type, &payload, Conversion::Encode, conversions);
+            }
+
+            if stringifies_payload {
+                payload = format!("stringifyJson({})", payload);
+            }
+
+            request_config.push(format!("data: {},", payload));
+        }
+
+        match route.return_media_type {
+            MediaType::Binary(_) if reads_raw_body => {
+                request_config.push("responseType: 'arraybuffer',".to_string());
+            }
+            _ if parses_body || reads_raw_body => {
+                request_config.push("responseType: 'text',".to_string());
+                request_config.push("transformResponse: (data: string) => data,".to_string());
+            }
+            _ => (),
+        }
+
+        // Unsuccessful responses are thrown as an `ApiError` below, rather than by axios.
+        request_config.push("validateStatus: () => true,".to_string());
+
+        let data = if parses_body { "data" } else { "response.data" };
+
+        // Raw bodies are only read as JSON when they are unsuccessful.
+        let error_data = if reads_raw_body { "errorBody" } else { data };
+
+        let error_response_type_name = if error_responses.is_empty() {
+            None
+        } else {
+            Some(
+                self.casing_rules
+                    .to_type_name_case(format!("{} error response", route.name)),
+            )
+        };
+
+        let error_response_type = match error_response_type_name.as_ref() {
+            Some(type_name) => format!(
+                "\nexport type {} = {};\n",
+                type_name,
+                error_responses
+                    .iter()
+                    .map(|(status, ty)| format!(
+                        "{{ status: {}; body: {} }}",
+                        match status {
+                            StatusCode::Code(code) => code.to_string(),
+                            StatusCode::Range(_) | StatusCode::Default => "number".to_string(),
+                        },
+                        self.emit_type_name((*ty).clone())
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            None => String::new(),
+        };
+
+        // The body of an error response is converted as the type documented for the most
+        // specific status code that matches.
+        let error_body = if decodes_error_responses {
+            error_responses
+                .iter()
+                .rev()
+                .fold(error_data.to_string(), |otherwise, (status, ty)| {
+                    let body = if self.is_converted(ty, Conversion::Decode, conversions) {
+                        self.emit_conversion(ty, error_data, Conversion::Decode, conversions)
+                    } else {
+                        error_data.to_string()
+                    };
+
+                    match status {
+                        StatusCode::Code(code) => {
+                            format!("response.status === {} ? {} : {}", code, body, otherwise)
+                        }
+                        StatusCode::Range(range) => format!(
+                            "Math.floor(response.status / 100) === {} ? {} : {}",
+                            range, body, otherwise
+                        ),
+                        StatusCode::Default => body,
+                    }
+                })
+        } else {
+            error_data.to_string()
+        };
+
+        let return_value = match route.return_type.as_ref() {
+            Some(_) if matches!(route.return_media_type, MediaType::Binary(_)) => Some(
+                "new Blob([response.data], { type: response.headers['content-type'] })".to_string(),
+            ),
+            Some(_) if reads_raw_body => Some("response.data".to_string()),
+            Some(ty) if decodes_response => {
+                Some(self.emit_conversion(ty, data, Conversion::Decode, conversions))
+            }
+            Some(_) => Some(data.to_string()),
+            None => None,
+        };
+
+        let description = route
+            .description
+            .map(|description| {
+                format!(
+                    " * {}\n *\n",
+                    String::from(description)
+                        .replace("*/", "*\\/")
+                        .replace('\n', "\n * ")
+                )
+            })
+            .unwrap_or_default();
+
+        let method = format!(
+            r#"
+/**
+{description} * @throws {{{api_error}}} If the API responds with an unsuccessful status code.
+ */
+async {function_name}({parameter_list}): Promise<{return_type}> {{
+    {setup}const response = await this.client.request({{
+        method: '{http_method}',
+        url: `{url}`,
+        {request_config}
+    }});{parse_statement}
+
+    if (response.status < 200 || response.status >= 300) {{{decode_error_body}
+        throw new ApiError({{ status: response.status, body: {error_body} }}{error_response_cast});
+    }}{return_statement}
+}}
+"#,
+            api_error = match error_response_type_name.as_ref() {
+                Some(type_name) => format!("ApiError<{}>", type_name),
+                None => "ApiError".to_string(),
+            },
+            parse_statement = if parses_body {
+                "\n\n    const data = parseJson(response.data);"
+            } else {
+                ""
+            },
+            decode_error_body = if reads_raw_body {
+                "\n        const errorBody = decodeErrorBody(response.data);\n"
+            } else {
+                ""
+            },
+            error_body = error_body,
+            error_response_cast = match error_response_type_name.as_ref() {
+                Some(type_name) => format!(" as {}", type_name),
+                None => String::new(),
+            },
+            return_statement = return_value
+                .map(|value| format!("\n\n    return {};", value))
+                .unwrap_or_default(),
+            function_name = self.casing_rules.to_function_name_case(route.name),
+            description = description,
+            parameter_list = parameter_list,
+            http_method = match route.method {
+                HttpMethod::Get => "get",
+                HttpMethod::Post => "post",
+                HttpMethod::Put => "put",
+                HttpMethod::Patch => "patch",
+                HttpMethod::Delete => "delete",
+            },
+            url = url,
+            setup = setup
                 .into_iter()
-                .map(|member| format!(
-                    "{}: {};",
-                    TypeScriptCasingRules.to_record_member_case(member.name),
-                    emit_type_name(member.ty),
-                ))
-                .collect::<Vec<_>>()
-                .join("\n")
-        ),
-        Type::Union { .. } => "".into(),
-        Type::Primitive(_) | Type::Array(_) | Type::Map { .. } => "".into(),
+                .map(|statement| statement + "\n    ")
+                .collect::<String>(),
+            request_config = request_config.join("\n        "),
+            return_type = return_type
+        );
+
+        (error_response_type, method)
     }
-}
 
-fn emit_sdk_resource(version: String, resource: SdkResource) -> String {
-    let type_decls: Vec<Type> = resource
-        .routes
-        .iter()
-        .flat_map(|route| vec![route.clone().payload_type.map(|ty| vec![ty])])
-        .filter_map(identity)
-        .flat_map(identity)
-        .collect();
-
-    let contents = resource
-        .routes
-        .into_iter()
-        .map(emit_route)
-        .collect::<Vec<String>>()
-        .join("\n");
+    /// Emits the statement built by `emit_statement` for each value of the parameter.
+    ///
+    /// Array parameters produce one statement per item, and parameters that may be absent or null
+    /// are skipped when they are.
+    fn emit_parameter_values(
+        &self,
+        parameter: &Parameter,
+        emit_statement: impl Fn(&str) -> String,
+    ) -> String {
+        let name = self.emit_parameter_name(&parameter.name);
+
+        let statement = match &parameter.ty {
+            Type::Array(item_type) => format!(
+                "{name}{optional_chain}forEach(item => {{ {statement} }});",
+                name = name,
+                optional_chain = if parameter.is_optional || parameter.is_nullable {
+                    "?."
+                } else {
+                    "."
+                },
+                statement = emit_statement(&self.emit_string_value(item_type, "item"))
+            ),
+            ty => emit_statement(&self.emit_string_value(ty, &name)),
+        };
+
+        match parameter.ty {
+            Type::Array(_) => statement,
+            _ if parameter.is_optional || parameter.is_nullable => format!(
+                r#"if ({name} != null) {{
+        {statement}
+    }}"#,
+                name = name,
+                statement = statement
+            ),
+            _ => statement,
+        }
+    }
+
+    /// Emits the statement built by `emit_statement` for each value of the member of the record.
+    ///
+    /// Array members produce one statement per item, and members that may be absent or null are
+    /// skipped when they are.
+    fn emit_member_values(
+        &self,
+        record: &str,
+        member: &Member,
+        emit_statement: impl Fn(&str, &Type) -> String,
+    ) -> String {
+        let value = emit_property_access(record, &member.wire_name);
 
-    contents
+        let statement = match &member.ty {
+            Type::Array(item_type) => format!(
+                "{}.forEach(item => {{ {} }});",
+                value,
+                emit_statement("item", item_type)
+            ),
+            ty => emit_statement(&value, ty),
+        };
+
+        if member.is_optional || member.is_nullable {
+            format!(
+                r#"if ({value} != null) {{
+        {statement}
+    }}"#,
+                value = value,
+                statement = statement
+            )
+        } else {
+            statement
+        }
+    }
+
+    /// Emits the string that a parameter value is sent as.
+    fn emit_string_value(&self, ty: &Type, value: &str) -> String {
+        match (ty, self.date_type) {
+            (Type::Primitive(Primitive::DateTime), TypeScriptDateType::Date) => {
+                format!("{}.toISOString()", value)
+            }
+            _ => format!("String({})", value),
+        }
+    }
+
+    fn emit_primitive(&self, primitive: Primitive) -> &'static str {
+        match primitive {
+            Primitive::String
+            | Primitive::Date
+            | Primitive::Time
+            | Primitive::Uuid
+            | Primitive::Bytes
+            | Primitive::Uri => "string",
+            Primitive::Boolean => "boolean",
+            Primitive::Integer | Primitive::Float | Primitive::Double | Primitive::Decimal => {
+                "number"
+            }
+            Primitive::Long => match self.long_type {
+                TypeScriptLongType::Number => "number",
+                TypeScriptLongType::Bigint => "bigint",
+                TypeScriptLongType::String => "string",
+            },
+            Primitive::DateTime => match self.date_type {
+                TypeScriptDateType::String => "string",
+                TypeScriptDateType::Date => "Date",
+            },
+            Primitive::Binary => "Blob",
+            Primitive::Any => "unknown",
+        }
+    }
+
+    /// Returns whether 64-bit integers are parsed by `parseJson`, rather than as numbers.
+    fn parses_long(&self) -> bool {
+        !matches!(self.long_type, TypeScriptLongType::Number)
+    }
+
+    /// Returns whether values of the primitive are converted, rather than used as they are.
+    fn converts_primitive(&self, primitive: &Primitive, conversion: Conversion) -> bool {
+        match (primitive, conversion) {
+            (Primitive::Long, Conversion::Decode) => self.parses_long(),
+            (Primitive::Long, Conversion::Encode) => {
+                matches!(self.long_type, TypeScriptLongType::String)
+            }
+            (Primitive::DateTime, Conversion::Decode) => {
+                matches!(self.date_type, TypeScriptDateType::Date)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether values of the type are converted, given the named types that are.
+    fn is_converted(&self, ty: &Type, conversion: Conversion, conversions: &Conversions) -> bool {
+        match ty {
+            Type::Primitive(primitive) => self.converts_primitive(primitive, conversion),
+            Type::Array(ty) => self.is_converted(ty, conversion, conversions),
+            Type::Tuple(item_types) => item_types
+                .iter()
+                .any(|ty| self.is_converted(ty, conversion, conversions)),
+            Type::Map { value, .. } => self.is_converted(value, conversion, conversions),
+            Type::Union { name, .. } | Type::Record { name, .. } | Type::Reference(name) => {
+                conversions.contains(name, conversion)
+            }
+            Type::Enum { .. } => false,
+        }
+    }
+
+    /// Returns the names of the named types whose values are converted.
+    ///
+    /// A union is only converted if it has a discriminator, since otherwise there is no telling
+    /// which of its cases a value is.
+    fn converted_types(
+        &self,
+        type_decls: &TypeDeclarations,
+        conversion: Conversion,
+    ) -> HashSet<String> {
+        let mut conversions = Conversions {
+            decoded: HashSet::new(),
+            encoded: HashSet::new(),
+        };
+
+        // A type is converted if any of the types it refers to are, which may only be known once
+        // the types that come after it have been visited.
+        loop {
+            let mut changed = false;
+
+            for (name, ty) in type_decls.iter() {
+                if self.type_mappings.contains(name) || conversions.contains(name, conversion) {
+                    continue;
+                }
+
+                let is_converted = match ty {
+                    Type::Record { members, .. } => members
+                        .iter()
+                        .any(|member| self.is_converted(&member.ty, conversion, &conversions)),
+                    Type::Union {
+                        discriminator: Some(_),
+                        cases,
+                        ..
+                    } => cases
+                        .iter()
+                        .any(|case| self.is_converted(&case.ty, conversion, &conversions)),
+                    _ => false,
+                };
+
+                if is_converted {
+                    match conversion {
+                        Conversion::Decode => conversions.decoded.insert(name.clone()),
+                        Conversion::Encode => conversions.encoded.insert(name.clone()),
+                    };
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        match conversion {
+            Conversion::Decode => conversions.decoded,
+            Conversion::Encode => conversions.encoded,
+        }
+    }
+
+    fn emit_conversion_function_name(&self, name: &str, conversion: Conversion) -> String {
+        let prefix = match conversion {
+            Conversion::Decode => "decode",
+            Conversion::Encode => "encode",
+        };
+
+        self.casing_rules
+            .to_function_name_case(format!("{} {}", prefix, name))
+    }
+
+    /// Emits an expression that converts the value, which must be of a type that is converted.
+    fn emit_conversion(
+        &self,
+        ty: &Type,
+        value: &str,
+        conversion: Conversion,
+        conversions: &Conversions,
+    ) -> String {
+        match ty {
+            Type::Primitive(Primitive::Long) => match (conversion, self.long_type) {
+                (Conversion::Decode, TypeScriptLongType::String) => format!("String({})", value),
+                _ => format!("BigInt({})", value),
+            },
+            Type::Primitive(Primitive::DateTime) => format!("new Date({})", value),
+            Type::Array(item_type) => format!(
+                "{}.map((item: any) => {})",
+                value,
+                self.emit_conversion(item_type, "item", conversion, conversions)
+            ),
+            Type::Tuple(item_types) => format!(
+                "[{}]",
+                item_types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item_type)| {
+                        let item = format!("{}[{}]", value, index);
+
+                        if self.is_converted(item_type, conversion, conversions) {
+                            self.emit_conversion(item_type, &item, conversion, conversions)
+                        } else {
+                            item
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Map {
+                value: value_type, ..
+            } => format!(
+                "Object.fromEntries(Object.entries({}).map(([key, entry]: [string, any]) => [key, {}]))",
+                value,
+                self.emit_conversion(value_type, "entry", conversion, conversions)
+            ),
+            Type::Union { name, .. } | Type::Record { name, .. } | Type::Reference(name) => {
+                format!(
+                    "{}({})",
+                    self.emit_conversion_function_name(name, conversion),
+                    value
+                )
+            }
+            Type::Primitive(_) | Type::Enum { .. } => value.to_owned(),
+        }
+    }
+
+    /// Emits the functions that convert values of the named type, for the conversions it needs.
+    fn emit_conversion_functions(&self, ty: &Type, conversions: &Conversions) -> String {
+        let name = match ty.name() {
+            Some(name) => name,
+            None => return String::new(),
+        };
+
+        let type_name = self.casing_rules.to_type_name_case(name.to_owned());
+
+        [Conversion::Decode, Conversion::Encode]
+            .iter()
+            .filter(|conversion| conversions.contains(name, **conversion))
+            .map(|&conversion| {
+                let body = match ty {
+                    Type::Record { members, .. } => format!(
+                        r#"return {{
+        ...value,
+        {members}
+    }};"#,
+                        members = members
+                            .iter()
+                            .filter(|member| self.is_converted(&member.ty, conversion, conversions))
+                            .map(|member| {
+                                let property = emit_property_access("value", &member.wire_name);
+                                let converted = self.emit_conversion(
+                                    &member.ty,
+                                    &property,
+                                    conversion,
+                                    conversions,
+                                );
+
+                                format!(
+                                    "{}: {},",
+                                    emit_property_key(&member.wire_name),
+                                    if member.is_optional || member.is_nullable {
+                                        format!(
+                                            "{property} == null ? {property} : {converted}",
+                                            property = property,
+                                            converted = converted
+                                        )
+                                    } else {
+                                        converted
+                                    }
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n        ")
+                    ),
+                    Type::Union {
+                        discriminator: Some(discriminator),
+                        cases,
+                        ..
+                    } => format!(
+                        r#"switch ({discriminator}) {{
+        {cases}
+        default:
+            return value;
+    }}"#,
+                        discriminator = emit_property_access("value", discriminator),
+                        cases = cases
+                            .iter()
+                            .filter(|case| self.is_converted(&case.ty, conversion, conversions))
+                            .map(|case| format!(
+                                "case '{}':\n            return {} as {};",
+                                case.name,
+                                self.emit_conversion(&case.ty, "value", conversion, conversions),
+                                type_name
+                            ))
+                            .collect::<Vec<_>>()
+                            .join("\n        ")
+                    ),
+                    _ => "return value;".to_string(),
+                };
+
+                let (input_type, output_type) = match conversion {
+                    Conversion::Decode => ("any", type_name.as_str()),
+                    Conversion::Encode => (type_name.as_str(), "any"),
+                };
+
+                format!(
+                    r#"
+
+export function {function_name}(value: {input_type}): {output_type} {{
+    {body}
+}}"#,
+                    function_name = self.emit_conversion_function_name(name, conversion),
+                    input_type = input_type,
+                    output_type = output_type,
+                    body = body
+                )
+            })
+            .collect()
+    }
+
+    /// Emits the imports of the named types, along with the functions that convert them.
+    fn emit_imports(
+        &self,
+        models_path: &str,
+        names: &[String],
+        conversions: &Conversions,
+    ) -> String {
+        names
+            .iter()
+            .map(|name| {
+                let type_name = self.casing_rules.to_type_name_case(name.clone());
+
+                let imports = std::iter::once(type_name.clone())
+                    .chain(
+                        [Conversion::Decode, Conversion::Encode]
+                            .iter()
+                            .filter(|conversion| conversions.contains(name, **conversion))
+                            .map(|conversion| {
+                                self.emit_conversion_function_name(name, *conversion)
+                            }),
+                    )
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "import {{ {imports} }} from '{path}{name}';\n",
+                    imports = imports,
+                    path = models_path,
+                    name = type_name
+                )
+            })
+            .collect()
+    }
 }
 
-fn emit_route(route: Route) -> String {
-    let return_type = route
-        .return_type
-        .as_ref()
-        .unwrap_or(&Type::Primitive(Primitive::String));
+/// Emits the module holding the servers of the API, along with the function that fills in the
+/// variables in their URLs.
+fn emit_servers(servers: &[Server]) -> String {
+    format!(
+        r#"/**
+ * A server that hosts the API, whose URL may hold variables written as `{{name}}`.
+ */
+export interface Server {{
+    url: string;
+    /**
+     * The default values of the variables.
+     */
+    variables: Record<string, string>;
+}}
 
-    let parameter_list = route
-        .all_parameters()
+/**
+ * The servers that host the API.
+ */
+export const servers: Server[] = [{servers}];
+
+/**
+ * Returns the URL of the server, with its variables replaced by the given values or otherwise
+ * their defaults.
+ */
+export function serverUrl(server: Server, variables: Record<string, string> = {{}}): string {{
+    return server.url.replace(
+        /{{([^}}]+)}}/g,
+        (match: string, name: string) => variables[name] ?? server.variables[name] ?? match,
+    );
+}}
+"#,
+        servers = servers
+            .iter()
+            .map(|server| format!("\n    {},", emit_server(server)))
+            .chain(if servers.is_empty() {
+                None
+            } else {
+                Some("\n".to_string())
+            })
+            .collect::<String>()
+    )
+}
+
+/// Emits a server as a `Server` object.
+fn emit_server(server: &Server) -> String {
+    let variables = server
+        .variables
         .iter()
-        .map(|(name, ty)| format!("{}: {}", name, emit_type_name(ty.clone())))
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    let url = route
-        .url_segments()
-        .into_iter()
-        .map(|segment| match segment {
-            UrlSegment::Parameter(param) => format!("${{{}}}", param),
-            UrlSegment::Literal(value) => value,
+        .map(|(name, variable)| {
+            format!(
+                "{}: {}",
+                emit_property_key(name),
+                emit_string_literal(&variable.default)
+            )
         })
-        .collect::<Vec<String>>()
-        .join("/");
+        .collect::<Vec<_>>();
 
-    let request_data = route.payload_type.map(|_| format!(r#"data: payload,"#));
+    // The trailing slash is left out so that paths can be appended to the URL.
+    format!(
+        "{{ url: {}, variables: {} }}",
+        emit_string_literal(server.url.trim_end_matches('/')),
+        if variables.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", variables.join(", "))
+        }
+    )
+}
+
+/// Emits the `ApiClient`, which exposes a client for each resource.
+///
+/// Clients of APIs with servers may leave out the base URL to use one of them instead.
+fn emit_client(
+    resource_clients: &[(String, String)],
+    has_security_schemes: bool,
+    default_servers: &[Server],
+    has_servers: bool,
+) -> String {
+    let (base_url_option, base_url) = if default_servers.is_empty() {
+        (
+            r#"
+    /**
+     * The base URL of the API.
+     */
+    baseUrl: string;"#,
+            "options.baseUrl",
+        )
+    } else {
+        (
+            r#"
+    /**
+     * The base URL of the API, or `undefined` to use one of the servers of the API.
+     */
+    baseUrl?: string;
+    /**
+     * The index in `servers` of the server that requests are sent to when no base URL is given,
+     * or `undefined` for the first one.
+     */
+    serverIndex?: number;"#,
+            "options.baseUrl ?? serverUrl(servers[options.serverIndex ?? 0], options.serverVariables)",
+        )
+    };
 
     format!(
-        r#"
+        r#"import axios, {{ AxiosInstance, AxiosRequestConfig, AxiosResponse }} from 'axios';
+{imports}
 /**
- * {description}
+ * Options for an `ApiClient`.
  */
-export async function {function_name}({parameter_list}): Promise<{return_type}> {{
-    const response = await axios({{
-        method: '{http_method}',
-        url: `{url}`,
-        {request_data}
-    }});
+export interface ApiClientOptions {{{base_url_option}{server_variables_option}
+    /**
+     * The axios instance that sends requests, or `undefined` to use the default instance.
+     */
+    axios?: AxiosInstance;
+    /**
+     * Headers sent with every request, unless the request sets them itself.
+     */
+    defaultHeaders?: Record<string, string>;
+    /**
+     * How long to wait for a response in milliseconds, or `undefined` to use the timeout of the
+     * axios instance.
+     */
+    timeout?: number;{credentials_option}
+}}
+
+/**
+ * A client for the API.
+ */
+export class ApiClient {{
+    {properties}
+    /**
+     * The base URL that requests are sent to.
+     */
+    readonly baseUrl: string;
+
+    constructor(readonly options: ApiClientOptions) {{
+        this.baseUrl = {base_url};
+        {initializers}
+    }}
+
+    /**
+     * Sends a request to the API with the options of the client.
+     *
+     * @internal
+     */
+    request(config: AxiosRequestConfig): Promise<AxiosResponse> {{
+        const {{ axios: instance = axios, defaultHeaders, timeout }} = this.options;
 
-    return response.data;
+        return instance.request({{
+            ...config,
+            baseURL: this.baseUrl,
+            headers: {{ ...defaultHeaders, ...config.headers }},
+            ...(timeout === undefined ? {{}} : {{ timeout }}),
+        }});
+    }}
 }}
-    "#,
-        function_name = TypeScriptCasingRules.to_function_name_case(route.name),
-        description = route.description.map(String::from).unwrap_or_default(),
-        parameter_list = parameter_list,
-        http_method = match route.method {
-            HttpMethod::Get => "get",
-            HttpMethod::Post => "post",
-            HttpMethod::Put => "put",
-            HttpMethod::Patch => "patch",
-            HttpMethod::Delete => "delete",
+"#,
+        base_url_option = base_url_option,
+        server_variables_option = if has_servers {
+            r#"
+    /**
+     * The values of the variables in the URLs of the servers, which otherwise take their
+     * defaults.
+     */
+    serverVariables?: Record<string, string>;"#
+        } else {
+            ""
         },
-        url = url,
-        request_data = request_data.unwrap_or_default(),
-        return_type = emit_type_name(return_type.to_owned())
+        base_url = base_url,
+        imports = std::iter::once(if has_security_schemes {
+            "import { CredentialsProvider } from './auth';\n".to_string()
+        } else {
+            String::new()
+        })
+        .chain(if default_servers.is_empty() {
+            None
+        } else {
+            Some("import { serverUrl, servers } from './servers';\n".to_string())
+        })
+        .chain(
+            resource_clients
+                .iter()
+                .map(|(resource_name, class_name)| format!(
+                    "import {{ {} }} from './resources/{}';\n",
+                    class_name, resource_name
+                ))
+        )
+        .collect::<String>(),
+        credentials_option = if has_security_schemes {
+            r#"
+    /**
+     * The provider of the credentials that requests are authorized with.
+     */
+    credentialsProvider?: CredentialsProvider;"#
+        } else {
+            ""
+        },
+        properties = resource_clients
+            .iter()
+            .map(|(resource_name, class_name)| format!(
+                "readonly {}: {};",
+                resource_name, class_name
+            ))
+            .collect::<Vec<_>>()
+            .join("\n    "),
+        initializers = resource_clients
+            .iter()
+            .map(|(resource_name, class_name)| format!(
+                "this.{} = new {}(this);",
+                resource_name, class_name
+            ))
+            .collect::<Vec<_>>()
+            .join("\n        ")
     )
 }
+
+fn emit_package_json(package: &PackageMetadata) -> String {
+    format!(
+        r#"{{
+  "name": {name:?},
+  "version": {version:?},
+  "main": "index.ts",
+  "dependencies": {{
+    "axios": "^1.6.0"
+  }}
+}}
+"#,
+        name = npm_package_name(&package.name),
+        version = package.version
+    )
+}
+
+/// Returns the name of the npm package, which must be lowercase and may only be scoped with a
+/// leading `@scope/`.
+fn npm_package_name(name: &str) -> String {
+    let to_npm_case = |name: &str| name.replace('.', " ").to_kebab_case();
+
+    match name.strip_prefix('@').and_then(|name| name.split_once('/')) {
+        Some((scope, name)) => format!("@{}/{}", to_npm_case(scope), to_npm_case(name)),
+        None => to_npm_case(name),
+    }
+}
+
+/// Returns the types that make up the type, which the declaration of the type refers to.
+fn child_types(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Primitive(_) | Type::Enum { .. } | Type::Reference(_) => Vec::new(),
+        Type::Array(ty) => vec![ty],
+        Type::Tuple(item_types) => item_types.iter().collect(),
+        Type::Map { key, value } => vec![key, value],
+        Type::Union { cases, .. } => cases.iter().map(|case| &case.ty).collect(),
+        Type::Record { members, .. } => members.iter().map(|member| &member.ty).collect(),
+    }
+}
+
+/// Emits the key of a property, which is the wire name of the member so that responses can be
+/// used as-is. Keys that are not valid identifiers are quoted.
+fn emit_property_key(wire_name: &str) -> String {
+    let mut chars = wire_name.chars();
+
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$');
+
+    if is_identifier {
+        wire_name.to_owned()
+    } else {
+        emit_string_literal(wire_name)
+    }
+}
+
+/// Emits a single-quoted string literal.
+fn emit_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// Returns whether the body of a successful response is read as it is rather than as JSON.
+fn reads_raw_body(route: &Route) -> bool {
+    route.return_type.is_some()
+        && matches!(
+            route.return_media_type,
+            MediaType::Text(_) | MediaType::Binary(_)
+        )
+}
+
+/// Emits an expression that reads the property of the value, using the same key as
+/// [`emit_property_key`].
+fn emit_property_access(value: &str, wire_name: &str) -> String {
+    let key = emit_property_key(wire_name);
+
+    if key.starts_with('\'') {
+        format!("{}[{}]", value, key)
+    } else {
+        format!("{}.{}", value, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    use sdkgen_core::UrlParameter;
+
+    fn member(name: &str, ty: Type, is_optional: bool) -> Member {
+        Member {
+            name: name.into(),
+            wire_name: name.into(),
+            description: None,
+            ty,
+            is_optional,
+            is_nullable: false,
+        }
+    }
+
+    fn pet() -> Type {
+        Type::Record {
+            name: "Pet".into(),
+            members: vec![
+                member("petId", Type::Primitive(Primitive::String), false),
+                member("born-at", Type::Primitive(Primitive::Date), true),
+            ],
+        }
+    }
+
+    fn get_pet() -> Route {
+        let mut route = Route {
+            name: "getPet".into(),
+            description: None,
+            method: HttpMethod::Get,
+            url: "/pets/:petId".into(),
+            group: "pets".into(),
+            version: "".into(),
+            url_parameters: vec![UrlParameter {
+                name: "petId".into(),
+                ty: Primitive::String,
+            }],
+            query_parameters: vec![Parameter {
+                name: "page-size".into(),
+                ty: Type::Primitive(Primitive::Integer),
+                is_optional: true,
+                is_nullable: false,
+            }],
+            header_parameters: Vec::new(),
+            cookie_parameters: Vec::new(),
+            payload_type: None,
+            payload_media_type: MediaType::Json,
+            return_type: Some(pet()),
+            return_media_type: MediaType::Json,
+            responses: Default::default(),
+            security: Vec::new(),
+            servers: Vec::new(),
+        };
+        route.responses.insert(StatusCode::Code(200), Some(pet()));
+
+        route
+    }
+
+    fn generate(routes: Vec<Route>, path: &str) -> String {
+        let mut type_decls = TypeDeclarations::new();
+        type_decls.register(pet());
+
+        let versions = vec![SdkVersion {
+            version: "".into(),
+            resources: vec![SdkResource {
+                resource: "pets".into(),
+                routes,
+            }],
+        }];
+
+        TypeScriptSdk::default()
+            .generate_sdk(type_decls, versions)
+            .unwrap()
+            .into_iter()
+            .find(|(file_path, _)| file_path == Path::new(path))
+            .map(|(_, contents)| contents)
+            .unwrap()
+    }
+
+    #[test]
+    fn records_keep_wire_names() {
+        let model = generate(vec![get_pet()], "models/Pet.ts");
+
+        assert!(model.contains("    petId: string;\n    'born-at'?: string;\n"));
+    }
+
+    #[test]
+    fn parameters_are_sent_with_their_wire_names() {
+        let resource = generate(vec![get_pet()], "resources/pets.ts");
+
+        assert!(resource.contains("async getPet(petId: string, pageSize?: number): Promise<Pet> {"));
+        assert!(resource.contains("params.append('page-size', String(pageSize));"));
+    }
+
+    #[test]
+    fn form_fields_are_escaped_and_do_not_shadow_parameters() {
+        let mut route = get_pet();
+        route.name = "updatePet".into();
+        route.method = HttpMethod::Post;
+        route.query_parameters[0].name = "form".into();
+        route.payload_type = Some(Type::Record {
+            name: "PetForm".into(),
+            members: vec![member("it's", Type::Primitive(Primitive::String), false)],
+        });
+        route.payload_media_type = MediaType::FormUrlEncoded;
+
+        let resource = generate(vec![route], "resources/pets.ts");
+
+        assert!(resource.contains("form.append('it\\'s', String(payload['it\\'s']));"));
+        assert!(resource.contains("params.append('form', String(form_));"));
+    }
+
+    #[test]
+    fn npm_package_names_are_normalized() {
+        assert_eq!(npm_package_name("Acme.Sdk"), "acme-sdk");
+        assert_eq!(npm_package_name("@Acme/Pet.Store"), "@acme/pet-store");
+        assert_eq!(npm_package_name("petstore"), "petstore");
+    }
+}